@@ -1,5 +1,6 @@
 import 'package:flutter/material.dart';
 import 'package:another_flushbar/flushbar.dart';
+import 'package:hbfu_alex/src/rust/api/error.dart';
 
 void showSuccessFlushbar(BuildContext context, String message) {
   Flushbar(
@@ -44,3 +45,24 @@ void showErrorFlushbar(BuildContext context, String message) {
     reverseAnimationCurve: Curves.easeIn,
   ).show(context);
 }
+
+/// 将 Rust 侧返回的 [ApiError] 转换为面向用户的提示，其他异常使用 [fallback]
+String apiErrorMessage(Object error, String fallback) {
+  if (error is! ApiError) return fallback;
+  return switch (error) {
+    ApiError_Network() => '网络连接失败，请检查网络',
+    ApiError_SessionExpired() => '登录已过期，请重新登录',
+    ApiError_BadCredentials(:final message) => message,
+    ApiError_BadCaptcha() => '验证码错误',
+    ApiError_ParseLayoutChanged() => '教务系统页面已变化，请更新应用',
+    ApiError_NotInitialized() || ApiError_NotLoggedIn() => '尚未登录，请先登录',
+    ApiError_InvalidArgument(:final message) => message,
+    ApiError_Io() => '本地文件读写失败',
+    ApiError_Internal() => fallback,
+  };
+}
+
+/// 根据 [ApiError] 的类型提示错误信息
+void showApiErrorFlushbar(BuildContext context, Object error, String fallback) {
+  showErrorFlushbar(context, apiErrorMessage(error, fallback));
+}
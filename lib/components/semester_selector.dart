@@ -2,6 +2,14 @@
 import 'package:flutter/material.dart';
 import 'package:hbfu_alex/src/rust/api/jwxt/semester.dart';
 
+/// 默认选中教务系统标记的当前学期，没有标记时选第一个
+SemesterInfo defaultSemester(List<SemesterInfo> semesters) {
+  return semesters.firstWhere(
+    (semester) => semester.isCurrent,
+    orElse: () => semesters.first,
+  );
+}
+
 class SemesterSelector extends StatelessWidget {
   final List<SemesterInfo> semesters;
   final SemesterInfo? selectedSemester;
@@ -114,6 +122,23 @@ class SemesterSelector extends StatelessWidget {
                           ),
                         ),
                       ),
+                      if (semester.isCurrent)
+                        Container(
+                          padding: const EdgeInsets.symmetric(
+                            horizontal: 6,
+                            vertical: 2,
+                          ),
+                          decoration: BoxDecoration(
+                            color: theme.primaryColor.withValues(alpha: 0.1),
+                            borderRadius: BorderRadius.circular(4),
+                          ),
+                          child: Text(
+                            '当前',
+                            style: theme.textTheme.labelSmall?.copyWith(
+                              color: theme.primaryColor,
+                            ),
+                          ),
+                        ),
                     ],
                   ),
                 ),
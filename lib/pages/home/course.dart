@@ -1,4 +1,5 @@
 import 'package:flutter/material.dart';
+import 'package:hbfu_alex/components/flush_bar.dart';
 
 import 'package:hbfu_alex/src/rust/api/jwxt/course.dart';
 import 'package:hbfu_alex/src/rust/api/jwxt/semester.dart';
@@ -17,6 +18,8 @@ class _CourseState extends State<Course> {
   SemesterInfo? _selectedSemester;
   List<CourseSchedule> _courses = [];
   bool _isLoading = false;
+  int? _selectedWeek; // 为空时显示整个学期的课程
+  int? _currentWeek;
   bool _viewByWeek = true;
 
   final ScrollController _mobileScrollController = ScrollController();
@@ -53,7 +56,7 @@ class _CourseState extends State<Course> {
   void initState() {
     super.initState();
     if (widget.semesters.isNotEmpty) {
-      _selectedSemester = widget.semesters[0];
+      _selectedSemester = defaultSemester(widget.semesters);
       _fetchCourse(_selectedSemester!);
     }
   }
@@ -65,18 +68,42 @@ class _CourseState extends State<Course> {
 
     try {
       final courses = await apiCourse(semester: semester.value);
+      final currentWeek = await _fetchCurrentWeek(semester);
       setState(() {
         _courses = courses;
+        _currentWeek = currentWeek;
+        _selectedWeek = currentWeek;
         _isLoading = false;
       });
     } catch (e) {
       setState(() {
         _courses = [];
+        _currentWeek = null;
+        _selectedWeek = null;
         _isLoading = false;
       });
+      if (mounted) {
+        showApiErrorFlushbar(context, e, '加载课表失败');
+      }
+    }
+  }
+
+  // 只有所选学期就是当前学期时才有本周的周次，开学日期未知时同样为空
+  Future<int?> _fetchCurrentWeek(SemesterInfo semester) async {
+    try {
+      final currentWeek = await apiCurrentWeek();
+      return currentWeek.semester == semester.value ? currentWeek.week : null;
+    } catch (_) {
+      return null;
     }
   }
 
+  List<CourseSchedule> get _weekCourses {
+    final week = _selectedWeek;
+    if (week == null) return _courses;
+    return _courses.where((course) => course.weekList.contains(week)).toList();
+  }
+
   Map<String, Map<String, List<CourseSchedule>>> _organizeCourses() {
     Map<String, Map<String, List<CourseSchedule>>> organized = {};
 
@@ -85,7 +112,7 @@ class _CourseState extends State<Course> {
       organized[day] = {};
     }
 
-    for (CourseSchedule course in _courses) {
+    for (CourseSchedule course in _weekCourses) {
       String dayKey = course.dayOfWeek;
 
       if (!organized.containsKey(dayKey)) {
@@ -140,6 +167,47 @@ class _CourseState extends State<Course> {
     );
   }
 
+  Widget _buildWeekSelector(ThemeData theme) {
+    final maxWeek = _courses
+        .expand((course) => course.weekList)
+        .fold<int>(0, (max, week) => week > max ? week : max);
+    if (maxWeek == 0) return const SizedBox.shrink();
+
+    return Container(
+      margin: const EdgeInsets.symmetric(horizontal: 16, vertical: 4),
+      child: Row(
+        children: [
+          Expanded(
+            child: Text(
+              '周次',
+              style: theme.textTheme.bodyMedium?.copyWith(
+                fontWeight: FontWeight.w600,
+                color: theme.colorScheme.onSurface,
+              ),
+            ),
+          ),
+          DropdownButtonHideUnderline(
+            child: DropdownButton<int?>(
+              value: _selectedWeek,
+              borderRadius: BorderRadius.circular(12),
+              onChanged: (week) => setState(() => _selectedWeek = week),
+              items: [
+                const DropdownMenuItem(value: null, child: Text('全部周次')),
+                for (var week = 1; week <= maxWeek; week++)
+                  DropdownMenuItem(
+                    value: week,
+                    child: Text(
+                      week == _currentWeek ? '第$week周（本周）' : '第$week周',
+                    ),
+                  ),
+              ],
+            ),
+          ),
+        ],
+      ),
+    );
+  }
+
   Widget _buildCourseCard(CourseSchedule course, ThemeData theme) {
     return GestureDetector(
       onTap: () => _showCourseDetail(course, theme),
@@ -989,6 +1057,7 @@ class _CourseState extends State<Course> {
     return Column(
       children: [
         _buildSemesterSelector(theme),
+        if (!_isLoading) _buildWeekSelector(theme),
         if (widget.isMobile && !_isLoading && _courses.isNotEmpty)
           _buildViewToggle(theme),
         Expanded(
@@ -1,4 +1,5 @@
 import 'package:flutter/material.dart';
+import 'package:hbfu_alex/components/flush_bar.dart';
 import 'package:hbfu_alex/src/rust/api/jwxt/dekt.dart';
 import 'package:hbfu_alex/src/rust/api/simple.dart';
 
@@ -37,6 +38,9 @@ class _DektState extends State<Dekt> {
       setState(() {
         _isLoading = false;
       });
+      if (mounted) {
+        showApiErrorFlushbar(context, e, '加载第二课堂失败');
+      }
     }
   }
 
@@ -392,7 +396,7 @@ class _DetailDialogState extends State<_DetailDialog> {
       });
     } catch (e) {
       setState(() {
-        _error = e.toString();
+        _error = apiErrorMessage(e, '加载活动详情失败');
         _isLoading = false;
       });
     }
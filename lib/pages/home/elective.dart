@@ -27,7 +27,7 @@ class _ElectiveState extends State<Elective> {
   void initState() {
     super.initState();
     if (widget.semesters.isNotEmpty) {
-      _selectedSemester = widget.semesters[0];
+      _selectedSemester = defaultSemester(widget.semesters);
       _fetchElective(_selectedSemester!);
     }
   }
@@ -55,7 +55,7 @@ class _ElectiveState extends State<Elective> {
         _isLoading = false;
       });
       if (mounted) {
-        showErrorFlushbar(context, '加载选课信息失败');
+        showApiErrorFlushbar(context, e, '加载选课信息失败');
       }
     }
   }
@@ -232,7 +232,10 @@ class _ElectiveState extends State<Elective> {
     );
   }
 
-  Widget _buildTotalCompact({required int total, required ThemeData theme}) {
+  Widget _buildTotalCompact({
+    required double total,
+    required ThemeData theme,
+  }) {
     return Container(
       padding: const EdgeInsets.symmetric(horizontal: 16, vertical: 12),
       decoration: BoxDecoration(
@@ -262,7 +265,7 @@ class _ElectiveState extends State<Elective> {
           FittedBox(
             fit: BoxFit.scaleDown,
             child: Text(
-              total.toString(),
+              _formatCredit(total),
               style: TextStyle(
                 fontSize: 26,
                 fontWeight: FontWeight.bold,
@@ -609,9 +612,16 @@ class _CourseDetailCard extends StatelessWidget {
   }
 }
 
+// 学分可能带半学分，整数学分不显示小数部分
+String _formatCredit(double credit) {
+  return credit == credit.roundToDouble()
+      ? credit.toStringAsFixed(0)
+      : credit.toString();
+}
+
 class _StatCardCompact extends StatelessWidget {
   final String label;
-  final int value;
+  final double value;
   final Color color;
   final bool isMobile;
 
@@ -647,7 +657,7 @@ class _StatCardCompact extends StatelessWidget {
           FittedBox(
             fit: BoxFit.scaleDown,
             child: Text(
-              value.toString(),
+              _formatCredit(value),
               style: TextStyle(
                 fontSize: isMobile ? 20 : 22,
                 fontWeight: FontWeight.bold,
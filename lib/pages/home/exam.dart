@@ -1,4 +1,5 @@
 import 'package:flutter/material.dart';
+import 'package:hbfu_alex/components/flush_bar.dart';
 import 'package:hbfu_alex/src/rust/api/simple.dart';
 import 'package:hbfu_alex/src/rust/api/jwxt/exam.dart';
 import 'package:hbfu_alex/src/rust/api/jwxt/semester.dart';
@@ -23,7 +24,7 @@ class _ExamState extends State<Exam> {
   void initState() {
     super.initState();
     if (widget.semesters.isNotEmpty) {
-      _selectedSemester = widget.semesters[0];
+      _selectedSemester = defaultSemester(widget.semesters);
       _fetchExam(_selectedSemester!);
     }
   }
@@ -45,6 +46,9 @@ class _ExamState extends State<Exam> {
         _exams = [];
         _isLoading = false;
       });
+      if (mounted) {
+        showApiErrorFlushbar(context, e, '加载考试安排失败');
+      }
     }
   }
 
@@ -1,4 +1,5 @@
 import 'package:flutter/material.dart';
+import 'package:hbfu_alex/components/flush_bar.dart';
 import 'package:hbfu_alex/src/rust/api/simple.dart';
 import 'package:hbfu_alex/src/rust/api/jwxt/info.dart';
 
@@ -13,6 +14,7 @@ class Info extends StatefulWidget {
 
 class _InfoState extends State<Info> with TickerProviderStateMixin {
   late Future<StudentInfo> _studentFuture;
+  late Future<StudentPhoto?> _photoFuture;
   bool _showIdNumber = false;
   late AnimationController _animationController;
   late Animation<double> _fadeAnimation;
@@ -21,6 +23,7 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
   void initState() {
     super.initState();
     _studentFuture = fetchStudentData();
+    _photoFuture = fetchStudentPhoto();
     _animationController = AnimationController(
       duration: const Duration(milliseconds: 800),
       vsync: this,
@@ -42,6 +45,38 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
     return student;
   }
 
+  // 照片加载失败时退回默认头像，不影响学籍信息的展示
+  Future<StudentPhoto?> fetchStudentPhoto() async {
+    try {
+      return await apiStudentPhoto();
+    } catch (_) {
+      return null;
+    }
+  }
+
+  Widget _buildAvatar(double iconSize, ColorScheme colorScheme) {
+    return FutureBuilder<StudentPhoto?>(
+      future: _photoFuture,
+      builder: (context, snapshot) {
+        final photo = snapshot.data;
+        if (photo == null) {
+          return Icon(
+            Icons.person,
+            size: iconSize,
+            color: colorScheme.onPrimaryContainer,
+          );
+        }
+        return ClipOval(
+          child: Image.memory(
+            photo.bytes,
+            fit: BoxFit.cover,
+            gaplessPlayback: true,
+          ),
+        );
+      },
+    );
+  }
+
   String _maskIdNumber(String idNumber) {
     if (idNumber.length >= 8) {
       return '${idNumber.substring(0, 4)}${'*' * (idNumber.length - 8)}${idNumber.substring(idNumber.length - 4)}';
@@ -49,13 +84,35 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
     return idNumber;
   }
 
+  // 教务系统未填写的字段显示为占位符
+  static const String _missing = '-';
+
+  // 后来加入学籍页的字段，只展示教务系统实际填写了的
+  List<(String, String, IconData)> _extraFields(StudentInfo student) {
+    final fields = [
+      ('出生日期', student.birthDate, Icons.cake),
+      ('民族', student.ethnicity, Icons.diversity_3),
+      ('政治面貌', student.politicalStatus, Icons.flag),
+      ('籍贯', student.hometown, Icons.home),
+      ('培养层次', student.educationLevel, Icons.workspace_premium),
+      ('学制', student.lengthOfSchooling, Icons.timelapse),
+      ('联系方式', student.contact, Icons.phone),
+      ('毕业中学', student.highSchool, Icons.history_edu),
+    ];
+    return [
+      for (final (label, value, icon) in fields)
+        if (value != null) (label, value, icon),
+    ];
+  }
+
   Widget _buildInfoRow(
     String label,
-    String value, {
+    String? value, {
     bool isSensitive = false,
     required BuildContext context,
   }) {
     final colorScheme = Theme.of(context).colorScheme;
+    final text = value ?? _missing;
 
     return Container(
       margin: const EdgeInsets.symmetric(vertical: 8.0),
@@ -85,9 +142,9 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
               children: [
                 Expanded(
                   child: Text(
-                    isSensitive && !_showIdNumber
-                        ? _maskIdNumber(value)
-                        : value,
+                    isSensitive && !_showIdNumber && value != null
+                        ? _maskIdNumber(text)
+                        : text,
                     style: TextStyle(
                       fontSize: 15,
                       fontWeight: FontWeight.w500,
@@ -146,10 +203,11 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
 
         final totalVerticalSpacing = (three - 1) * spacing;
         final rowHeight = (availableHeight - totalVerticalSpacing) / three - 42;
+        final extraFields = _extraFields(student);
 
         Widget card(
           String title,
-          String content,
+          String? content,
           IconData icon,
           Color color, {
           bool isSensitive = false,
@@ -162,7 +220,7 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
             child: _buildCompactInfoCard(
               context,
               title,
-              content,
+              content ?? _missing,
               icon,
               color,
               isSensitive: isSensitive,
@@ -210,11 +268,7 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
                                 width: 2,
                               ),
                             ),
-                            child: Icon(
-                              Icons.person,
-                              size: 28,
-                              color: colorScheme.onPrimaryContainer,
-                            ),
+                            child: _buildAvatar(28, colorScheme),
                           ),
                           const SizedBox(width: 12),
                           Expanded(
@@ -223,7 +277,7 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
                               mainAxisAlignment: MainAxisAlignment.center,
                               children: [
                                 Text(
-                                  student.name,
+                                  student.name ?? _missing,
                                   style: TextStyle(
                                     fontSize: 20,
                                     fontWeight: FontWeight.bold,
@@ -232,7 +286,7 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
                                 ),
                                 const SizedBox(height: 2),
                                 Text(
-                                  student.studentId,
+                                  student.studentId ?? _missing,
                                   style: TextStyle(
                                     fontSize: 14,
                                     color: colorScheme.onPrimaryContainer
@@ -310,6 +364,18 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
                   isSensitive: true,
                   width: lastCardWidth,
                 ),
+
+                if (extraFields.isNotEmpty) ...[
+                  SizedBox(height: spacing),
+                  Wrap(
+                    spacing: spacing,
+                    runSpacing: spacing,
+                    children: [
+                      for (final (title, content, icon) in extraFields)
+                        card(title, content, icon, colorScheme.primary),
+                    ],
+                  ),
+                ],
               ],
             ),
           ),
@@ -452,6 +518,8 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
                   isSensitive: true,
                   context: context,
                 ),
+                for (final (label, value, _) in _extraFields(student))
+                  _buildInfoRow(label, value, context: context),
               ],
             ),
           ),
@@ -489,11 +557,7 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
               borderRadius: BorderRadius.circular(35),
               border: Border.all(color: colorScheme.surface, width: 2),
             ),
-            child: Icon(
-              Icons.person,
-              size: 40,
-              color: colorScheme.onPrimaryContainer,
-            ),
+            child: _buildAvatar(40, colorScheme),
           ),
           const SizedBox(width: 16),
           Expanded(
@@ -501,7 +565,7 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
               crossAxisAlignment: CrossAxisAlignment.start,
               children: [
                 Text(
-                  student.name,
+                  student.name ?? _missing,
                   style: TextStyle(
                     fontSize: 24,
                     fontWeight: FontWeight.bold,
@@ -510,7 +574,7 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
                 ),
                 const SizedBox(height: 4),
                 Text(
-                  student.studentId,
+                  student.studentId ?? _missing,
                   style: TextStyle(
                     fontSize: 16,
                     color: colorScheme.onPrimaryContainer.withValues(
@@ -566,7 +630,7 @@ class _InfoState extends State<Info> with TickerProviderStateMixin {
                   ),
                   const SizedBox(height: 8),
                   Text(
-                    '${snapshot.error}',
+                    apiErrorMessage(snapshot.error!, '${snapshot.error}'),
                     style: TextStyle(
                       color: colorScheme.onSurface.withValues(alpha: 0.6),
                     ),
@@ -1,4 +1,5 @@
 import 'package:flutter/material.dart';
+import 'package:hbfu_alex/components/flush_bar.dart';
 import 'package:hbfu_alex/src/rust/api/simple.dart';
 import 'package:hbfu_alex/src/rust/api/jwxt/plan.dart';
 
@@ -12,7 +13,6 @@ class Plan extends StatefulWidget {
 class _PlanState extends State<Plan> with TickerProviderStateMixin {
   ExecutionPlanResponse? _planResponse;
   bool _isLoading = false;
-  final Map<String, List<ExecutionPlan>> _semesterPlans = {};
   int? _expandedSemesterIndex;
   final Map<String, int?> _expandedCourseIndex = {};
   int? _hoveredSemesterIndex;
@@ -34,25 +34,21 @@ class _PlanState extends State<Plan> with TickerProviderStateMixin {
       setState(() {
         _planResponse = plan;
         _isLoading = false;
-        _processPlanData();
       });
     } catch (e) {
       setState(() {
         _isLoading = false;
       });
+      if (mounted) {
+        showApiErrorFlushbar(context, e, '加载执行计划失败');
+      }
     }
   }
 
-  void _processPlanData() {
-    if (_planResponse == null) return;
-
-    _semesterPlans.clear();
-    for (var plan in _planResponse!.plans) {
-      if (!_semesterPlans.containsKey(plan.semester)) {
-        _semesterPlans[plan.semester] = [];
-      }
-      _semesterPlans[plan.semester]!.add(plan);
-    }
+  // 学年和学期号由 Rust 侧从学期代码中拆出，无法识别时直接显示学期代码
+  String _semesterLabel(PlanSemester group) {
+    if (group.academicYear.isEmpty) return group.semester;
+    return '${group.academicYear}学年 第${group.term}学期';
   }
 
   @override
@@ -75,31 +71,26 @@ class _PlanState extends State<Plan> with TickerProviderStateMixin {
   }
 
   Widget _buildMobileSemestersList() {
-    final sortedSemesters = List<String>.from(_planResponse!.semesters)..sort();
+    final groups = _planResponse!.groups;
 
     return ListView.builder(
       padding: const EdgeInsets.all(16),
-      itemCount: sortedSemesters.length,
+      itemCount: groups.length,
       itemBuilder: (context, index) {
-        return _buildMobileSemesterCard(index, sortedSemesters[index]);
+        return _buildMobileSemesterCard(index, groups[index]);
       },
     );
   }
 
-  Widget _buildMobileSemesterCard(int index, String semester) {
+  Widget _buildMobileSemesterCard(int index, PlanSemester group) {
     final theme = Theme.of(context);
     final cs = theme.colorScheme;
-    final plans = _semesterPlans[semester] ?? [];
+    final semester = group.semester;
+    final plans = group.plans;
     final isExpanded = _expandedSemesterIndex == index;
 
-    final totalCredits = plans.fold<double>(
-      0,
-      (sum, plan) => sum + plan.credits,
-    );
-    final totalHours = plans.fold<double>(
-      0,
-      (sum, plan) => sum + plan.totalHours,
-    );
+    final totalCredits = group.credits;
+    final totalHours = group.totalHours;
 
     return Card(
       margin: const EdgeInsets.only(bottom: 12),
@@ -126,7 +117,7 @@ class _PlanState extends State<Plan> with TickerProviderStateMixin {
                     mainAxisAlignment: MainAxisAlignment.spaceBetween,
                     children: [
                       Text(
-                        semester,
+                        _semesterLabel(group),
                         style: TextStyle(
                           fontWeight: FontWeight.bold,
                           fontSize: 18,
@@ -406,7 +397,7 @@ class _PlanState extends State<Plan> with TickerProviderStateMixin {
   Widget _buildSemestersTable() {
     final theme = Theme.of(context);
     final cs = theme.colorScheme;
-    final sortedSemesters = List<String>.from(_planResponse!.semesters)..sort();
+    final groups = _planResponse!.groups;
 
     return SingleChildScrollView(
       padding: const EdgeInsets.all(16),
@@ -491,9 +482,9 @@ class _PlanState extends State<Plan> with TickerProviderStateMixin {
             ListView.builder(
               shrinkWrap: true,
               physics: const NeverScrollableScrollPhysics(),
-              itemCount: sortedSemesters.length,
+              itemCount: groups.length,
               itemBuilder: (context, index) {
-                return _buildSemesterRow(index, sortedSemesters[index]);
+                return _buildSemesterRow(index, groups[index]);
               },
             ),
           ],
@@ -502,21 +493,16 @@ class _PlanState extends State<Plan> with TickerProviderStateMixin {
     );
   }
 
-  Widget _buildSemesterRow(int index, String semester) {
+  Widget _buildSemesterRow(int index, PlanSemester group) {
     final theme = Theme.of(context);
     final cs = theme.colorScheme;
-    final plans = _semesterPlans[semester] ?? [];
+    final semester = group.semester;
+    final plans = group.plans;
     final isExpanded = _expandedSemesterIndex == index;
     final isHovered = _hoveredSemesterIndex == index;
 
-    final totalCredits = plans.fold<double>(
-      0,
-      (sum, plan) => sum + plan.credits,
-    );
-    final totalHours = plans.fold<double>(
-      0,
-      (sum, plan) => sum + plan.totalHours,
-    );
+    final totalCredits = group.credits;
+    final totalHours = group.totalHours;
 
     final zebra = index.isOdd
         ? cs.surfaceContainerHighest.withValues(
@@ -553,7 +539,7 @@ class _PlanState extends State<Plan> with TickerProviderStateMixin {
                       Expanded(
                         flex: 3,
                         child: Text(
-                          semester,
+                          _semesterLabel(group),
                           style: TextStyle(
                             fontWeight: FontWeight.w600,
                             fontSize: 15,
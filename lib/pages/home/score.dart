@@ -1,5 +1,6 @@
 import 'package:flutter/material.dart';
 import 'package:hbfu_alex/src/rust/api/simple.dart';
+import 'package:hbfu_alex/src/rust/api/jwxt/grade.dart';
 import 'package:hbfu_alex/src/rust/api/jwxt/score.dart';
 import 'package:hbfu_alex/src/rust/api/jwxt/semester.dart';
 import 'package:hbfu_alex/components/semester_selector.dart';
@@ -20,7 +21,7 @@ class _ScoreState extends State<Score> {
   void initState() {
     super.initState();
     if (widget.semesters.isNotEmpty) {
-      _selectedSemester = widget.semesters[0];
+      _selectedSemester = defaultSemester(widget.semesters);
       _fetchScore(_selectedSemester!);
     }
   }
@@ -32,8 +33,11 @@ class _ScoreState extends State<Score> {
     });
   }
 
-  Color _getScoreColor(BuildContext context, double score) {
+  Color _getScoreColor(BuildContext context, Grade grade) {
     final theme = Theme.of(context);
+    final score = grade.value;
+    if (grade.isExempt) return theme.colorScheme.outline;
+    if (score == null) return theme.colorScheme.error;
     if (score >= 90) return Colors.green;
     if (score >= 80) return theme.primaryColor;
     if (score >= 70) return Colors.orange;
@@ -41,7 +45,11 @@ class _ScoreState extends State<Score> {
     return theme.colorScheme.error;
   }
 
-  String _getGradeText(double score) {
+  String _getGradeText(Grade grade) {
+    final score = grade.value;
+    if (grade.isAbsent) return '缺考';
+    if (grade.isExempt) return '免修';
+    if (score == null) return grade.raw;
     if (score >= 90) return '优秀';
     if (score >= 80) return '良好';
     if (score >= 70) return '中等';
@@ -49,6 +57,12 @@ class _ScoreState extends State<Score> {
     return '不及格';
   }
 
+  // 与 Rust 侧 Grade::is_passed 保持一致，免修视为通过
+  bool _isPassed(Grade grade) {
+    final score = grade.value;
+    return grade.isExempt || (score != null && score >= 60);
+  }
+
   void _showScoreDetail(ScoreInfo scoreInfo) {
     final theme = Theme.of(context);
     showDialog(
@@ -127,7 +141,7 @@ class _ScoreState extends State<Score> {
                             shape: BoxShape.circle,
                           ),
                           child: Text(
-                            scoreInfo.score.toString(),
+                            scoreInfo.score.raw,
                             style: TextStyle(
                               fontSize: 36,
                               fontWeight: FontWeight.bold,
@@ -242,7 +256,7 @@ class _ScoreState extends State<Score> {
                 borderRadius: BorderRadius.circular(16),
               ),
               child: Text(
-                scoreInfo.score.toString(),
+                scoreInfo.score.raw,
                 style: const TextStyle(
                   color: Colors.white,
                   fontSize: 16,
@@ -345,7 +359,7 @@ class _ScoreState extends State<Score> {
           ),
           child: Center(
             child: Text(
-              scoreInfo.score.toString(),
+              scoreInfo.score.raw,
               style: const TextStyle(
                 color: Colors.white,
                 fontSize: 16,
@@ -366,7 +380,7 @@ class _ScoreState extends State<Score> {
     int passedCount = 0;
     int failedCount = 0;
     for (var info in _score!.info) {
-      if (info.score >= 60) {
+      if (_isPassed(info.score)) {
         passedCount++;
       } else {
         failedCount++;
@@ -2,6 +2,7 @@ import 'package:flutter/material.dart';
 import 'package:flutter_colorpicker/flutter_colorpicker.dart';
 import 'package:hbfu_alex/components/flush_bar.dart';
 import 'package:hbfu_alex/components/theme_manager.dart';
+import 'package:hbfu_alex/src/rust/api/simple.dart';
 import 'package:provider/provider.dart';
 import 'package:shared_preferences/shared_preferences.dart';
 import 'package:flutter/services.dart';
@@ -380,6 +381,7 @@ class _SettingState extends State<Setting> {
                 ),
               );
               if (shouldLogout != true) return;
+              await _safeRun(apiLogout);
               final prefs = await SharedPreferences.getInstance();
               await prefs.clear();
               if (!mounted) return;
@@ -44,7 +44,7 @@ class _LoginState extends State<Login> {
       });
     } catch (e) {
       if (mounted) {
-        showErrorFlushbar(context, '加载验证码失败');
+        showApiErrorFlushbar(context, e, '加载验证码失败');
       }
     }
   }
@@ -114,9 +114,9 @@ class _LoginState extends State<Login> {
         '/home',
         (Route<dynamic> route) => false,
       );
-    } catch (_) {
+    } catch (e) {
       if (!mounted) return;
-      showErrorFlushbar(context, '登录失败');
+      showApiErrorFlushbar(context, e, '登录失败');
       _loadCaptcha();
       captchaController.clear();
     } finally {
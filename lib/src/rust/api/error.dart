@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `from`, `from`, `from`, `from`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `layout`, `missing_column`
+
+@freezed
+sealed class ApiError with _$ApiError implements FrbException {
+  const ApiError._();
+
+  /// 网络请求失败，`cause` 保留 reqwest 的原始错误
+  const factory ApiError.network({required String cause}) = ApiError_Network;
+  /// 教务系统会话已过期，需要重新登录
+  const factory ApiError.sessionExpired() = ApiError_SessionExpired;
+  /// 账号或密码错误
+  const factory ApiError.badCredentials({required String message}) =
+      ApiError_BadCredentials;
+  /// 验证码错误
+  const factory ApiError.badCaptcha() = ApiError_BadCaptcha;
+  /// 页面结构发生变化，`selector` 未能匹配到元素
+  const factory ApiError.parseLayoutChanged({
+    required String page,
+    required String selector,
+  }) = ApiError_ParseLayoutChanged;
+  /// 会话尚未初始化
+  const factory ApiError.notInitialized() = ApiError_NotInitialized;
+  /// 尚未登录，需要先获取验证码并登录
+  const factory ApiError.notLoggedIn() = ApiError_NotLoggedIn;
+  /// 调用参数不合法，如日期格式错误
+  const factory ApiError.invalidArgument({required String message}) =
+      ApiError_InvalidArgument;
+  /// 本地文件读写失败
+  const factory ApiError.io({required String cause}) = ApiError_Io;
+  /// 其他内部错误，`cause` 保留原始错误
+  const factory ApiError.internal({required String cause}) = ApiError_Internal;
+}
@@ -0,0 +1,551 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'error.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ApiError {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiError()';
+}
+
+
+}
+
+/// @nodoc
+class $ApiErrorCopyWith<$Res>  {
+$ApiErrorCopyWith(ApiError _, $Res Function(ApiError) __);
+}
+
+
+/// @nodoc
+
+
+class ApiError_Network extends ApiError {
+  const ApiError_Network({required this.cause}): super._();
+  
+
+ final  String cause;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline')
+$ApiError_NetworkCopyWith<ApiError_Network> get copyWith => _$ApiError_NetworkCopyWithImpl<ApiError_Network>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError_Network&&(identical(other.cause, cause) || other.cause == cause));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,cause);
+
+@override
+String toString() {
+  return 'ApiError.network(cause: $cause)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiError_NetworkCopyWith<$Res> implements $ApiErrorCopyWith<$Res> {
+  factory $ApiError_NetworkCopyWith(ApiError_Network value, $Res Function(ApiError_Network) _then) = _$ApiError_NetworkCopyWithImpl;
+@useResult
+$Res call({
+ String cause
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiError_NetworkCopyWithImpl<$Res>
+    implements $ApiError_NetworkCopyWith<$Res> {
+  _$ApiError_NetworkCopyWithImpl(this._self, this._then);
+
+  final ApiError_Network _self;
+  final $Res Function(ApiError_Network) _then;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? cause = null,}) {
+  return _then(ApiError_Network(
+cause: null == cause ? _self.cause : cause // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiError_SessionExpired extends ApiError {
+  const ApiError_SessionExpired(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError_SessionExpired);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiError.sessionExpired()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiError_BadCredentials extends ApiError {
+  const ApiError_BadCredentials({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline')
+$ApiError_BadCredentialsCopyWith<ApiError_BadCredentials> get copyWith => _$ApiError_BadCredentialsCopyWithImpl<ApiError_BadCredentials>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError_BadCredentials&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'ApiError.badCredentials(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiError_BadCredentialsCopyWith<$Res> implements $ApiErrorCopyWith<$Res> {
+  factory $ApiError_BadCredentialsCopyWith(ApiError_BadCredentials value, $Res Function(ApiError_BadCredentials) _then) = _$ApiError_BadCredentialsCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiError_BadCredentialsCopyWithImpl<$Res>
+    implements $ApiError_BadCredentialsCopyWith<$Res> {
+  _$ApiError_BadCredentialsCopyWithImpl(this._self, this._then);
+
+  final ApiError_BadCredentials _self;
+  final $Res Function(ApiError_BadCredentials) _then;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(ApiError_BadCredentials(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiError_BadCaptcha extends ApiError {
+  const ApiError_BadCaptcha(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError_BadCaptcha);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiError.badCaptcha()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiError_ParseLayoutChanged extends ApiError {
+  const ApiError_ParseLayoutChanged({required this.page, required this.selector}): super._();
+  
+
+ final  String page;
+ final  String selector;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline')
+$ApiError_ParseLayoutChangedCopyWith<ApiError_ParseLayoutChanged> get copyWith => _$ApiError_ParseLayoutChangedCopyWithImpl<ApiError_ParseLayoutChanged>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError_ParseLayoutChanged&&(identical(other.page, page) || other.page == page)&&(identical(other.selector, selector) || other.selector == selector));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,page,selector);
+
+@override
+String toString() {
+  return 'ApiError.parseLayoutChanged(page: $page, selector: $selector)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiError_ParseLayoutChangedCopyWith<$Res> implements $ApiErrorCopyWith<$Res> {
+  factory $ApiError_ParseLayoutChangedCopyWith(ApiError_ParseLayoutChanged value, $Res Function(ApiError_ParseLayoutChanged) _then) = _$ApiError_ParseLayoutChangedCopyWithImpl;
+@useResult
+$Res call({
+ String page, String selector
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiError_ParseLayoutChangedCopyWithImpl<$Res>
+    implements $ApiError_ParseLayoutChangedCopyWith<$Res> {
+  _$ApiError_ParseLayoutChangedCopyWithImpl(this._self, this._then);
+
+  final ApiError_ParseLayoutChanged _self;
+  final $Res Function(ApiError_ParseLayoutChanged) _then;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? page = null,Object? selector = null,}) {
+  return _then(ApiError_ParseLayoutChanged(
+page: null == page ? _self.page : page // ignore: cast_nullable_to_non_nullable
+as String,selector: null == selector ? _self.selector : selector // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiError_NotInitialized extends ApiError {
+  const ApiError_NotInitialized(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError_NotInitialized);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiError.notInitialized()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiError_NotLoggedIn extends ApiError {
+  const ApiError_NotLoggedIn(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError_NotLoggedIn);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiError.notLoggedIn()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiError_InvalidArgument extends ApiError {
+  const ApiError_InvalidArgument({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline')
+$ApiError_InvalidArgumentCopyWith<ApiError_InvalidArgument> get copyWith => _$ApiError_InvalidArgumentCopyWithImpl<ApiError_InvalidArgument>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError_InvalidArgument&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'ApiError.invalidArgument(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiError_InvalidArgumentCopyWith<$Res> implements $ApiErrorCopyWith<$Res> {
+  factory $ApiError_InvalidArgumentCopyWith(ApiError_InvalidArgument value, $Res Function(ApiError_InvalidArgument) _then) = _$ApiError_InvalidArgumentCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiError_InvalidArgumentCopyWithImpl<$Res>
+    implements $ApiError_InvalidArgumentCopyWith<$Res> {
+  _$ApiError_InvalidArgumentCopyWithImpl(this._self, this._then);
+
+  final ApiError_InvalidArgument _self;
+  final $Res Function(ApiError_InvalidArgument) _then;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(ApiError_InvalidArgument(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiError_Io extends ApiError {
+  const ApiError_Io({required this.cause}): super._();
+  
+
+ final  String cause;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline')
+$ApiError_IoCopyWith<ApiError_Io> get copyWith => _$ApiError_IoCopyWithImpl<ApiError_Io>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError_Io&&(identical(other.cause, cause) || other.cause == cause));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,cause);
+
+@override
+String toString() {
+  return 'ApiError.io(cause: $cause)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiError_IoCopyWith<$Res> implements $ApiErrorCopyWith<$Res> {
+  factory $ApiError_IoCopyWith(ApiError_Io value, $Res Function(ApiError_Io) _then) = _$ApiError_IoCopyWithImpl;
+@useResult
+$Res call({
+ String cause
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiError_IoCopyWithImpl<$Res>
+    implements $ApiError_IoCopyWith<$Res> {
+  _$ApiError_IoCopyWithImpl(this._self, this._then);
+
+  final ApiError_Io _self;
+  final $Res Function(ApiError_Io) _then;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? cause = null,}) {
+  return _then(ApiError_Io(
+cause: null == cause ? _self.cause : cause // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiError_Internal extends ApiError {
+  const ApiError_Internal({required this.cause}): super._();
+  
+
+ final  String cause;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline')
+$ApiError_InternalCopyWith<ApiError_Internal> get copyWith => _$ApiError_InternalCopyWithImpl<ApiError_Internal>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiError_Internal&&(identical(other.cause, cause) || other.cause == cause));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,cause);
+
+@override
+String toString() {
+  return 'ApiError.internal(cause: $cause)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiError_InternalCopyWith<$Res> implements $ApiErrorCopyWith<$Res> {
+  factory $ApiError_InternalCopyWith(ApiError_Internal value, $Res Function(ApiError_Internal) _then) = _$ApiError_InternalCopyWithImpl;
+@useResult
+$Res call({
+ String cause
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiError_InternalCopyWithImpl<$Res>
+    implements $ApiError_InternalCopyWith<$Res> {
+  _$ApiError_InternalCopyWithImpl(this._self, this._then);
+
+  final ApiError_Internal _self;
+  final $Res Function(ApiError_Internal) _then;
+
+/// Create a copy of ApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? cause = null,}) {
+  return _then(ApiError_Internal(
+cause: null == cause ? _self.cause : cause // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `course_times`, `escape_text`, `format_local`, `parse_time`, `push_folded`, `stable_hash`, `weekday_offset`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `IcsEvent`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `beijing_now`, `course_events`, `exam_events`, `parse_date`, `write_calendar`
+
+/// 一个大节的上下课时间，`section` 对应 `CourseSchedule.section`
+class SectionTime {
+  final BigInt section;
+  final String start;
+  final String end;
+
+  const SectionTime({
+    required this.section,
+    required this.start,
+    required this.end,
+  });
+
+  @override
+  int get hashCode => section.hashCode ^ start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SectionTime &&
+          runtimeType == other.runtimeType &&
+          section == other.section &&
+          start == other.start &&
+          end == other.end;
+}
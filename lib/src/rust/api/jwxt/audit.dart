@@ -0,0 +1,151 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'grade.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'score.dart';
+
+// These functions are ignored because they are not marked as `pub`: `course_status`, `progress`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `degree_audit`
+
+/// 培养方案中的一门课程及其完成情况
+class AuditCourse {
+  final String courseCode;
+  final String courseName;
+  final String semester;
+  final String courseType;
+  final double credits;
+  final CourseStatus status;
+  final Grade? bestScore;
+  final int attempts;
+
+  const AuditCourse({
+    required this.courseCode,
+    required this.courseName,
+    required this.semester,
+    required this.courseType,
+    required this.credits,
+    required this.status,
+    this.bestScore,
+    required this.attempts,
+  });
+
+  @override
+  int get hashCode =>
+      courseCode.hashCode ^
+      courseName.hashCode ^
+      semester.hashCode ^
+      courseType.hashCode ^
+      credits.hashCode ^
+      status.hashCode ^
+      bestScore.hashCode ^
+      attempts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AuditCourse &&
+          runtimeType == other.runtimeType &&
+          courseCode == other.courseCode &&
+          courseName == other.courseName &&
+          semester == other.semester &&
+          courseType == other.courseType &&
+          credits == other.credits &&
+          status == other.status &&
+          bestScore == other.bestScore &&
+          attempts == other.attempts;
+}
+
+enum CourseStatus {
+  /// 已取得学分，免修视为通过
+  passed,
+  /// 有成绩但未通过
+  failed,
+  /// 本学期开设，尚未出成绩
+  inProgress,
+  /// 尚未修读
+  notTaken,
+}
+
+/// 一组课程的学分完成情况，`key` 为课程属性或开课学期
+class CreditProgress {
+  final String key;
+  final double earned;
+  final double required_;
+  final int passedCount;
+  final int courseCount;
+
+  const CreditProgress({
+    required this.key,
+    required this.earned,
+    required this.required_,
+    required this.passedCount,
+    required this.courseCount,
+  });
+
+  @override
+  int get hashCode =>
+      key.hashCode ^
+      earned.hashCode ^
+      required_.hashCode ^
+      passedCount.hashCode ^
+      courseCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CreditProgress &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          earned == other.earned &&
+          required_ == other.required_ &&
+          passedCount == other.passedCount &&
+          courseCount == other.courseCount;
+}
+
+class DegreeAudit {
+  final List<AuditCourse> courses;
+  final List<CreditProgress> byCourseType;
+  final List<CreditProgress> bySemester;
+  final double earned;
+  final double required_;
+  final List<ScoreInfo> outsidePlan;
+  final double outsidePlanEarned;
+
+  const DegreeAudit({
+    required this.courses,
+    required this.byCourseType,
+    required this.bySemester,
+    required this.earned,
+    required this.required_,
+    required this.outsidePlan,
+    required this.outsidePlanEarned,
+  });
+
+  @override
+  int get hashCode =>
+      courses.hashCode ^
+      byCourseType.hashCode ^
+      bySemester.hashCode ^
+      earned.hashCode ^
+      required_.hashCode ^
+      outsidePlan.hashCode ^
+      outsidePlanEarned.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DegreeAudit &&
+          runtimeType == other.runtimeType &&
+          courses == other.courses &&
+          byCourseType == other.byCourseType &&
+          bySemester == other.bySemester &&
+          earned == other.earned &&
+          required_ == other.required_ &&
+          outsidePlan == other.outsidePlan &&
+          outsidePlanEarned == other.outsidePlanEarned;
+}
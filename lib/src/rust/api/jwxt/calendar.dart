@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `calendar_day`, `format_date`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `holiday_dates`
+
+/// 解析校历页面。学期取下拉框中选中的一项；每个日期单元格的 title 为 "2025年04月04日 清明节"，
+/// 日期后的文字为标注，title 中没有标注时取单元格中日期数字以外的文字
+Future<SchoolCalendar> parseSchoolCalendar({required String html}) =>
+    RustLib.instance.api.crateApiJwxtCalendarParseSchoolCalendar(html: html);
+
+/// 校历中有标注的一天
+class CalendarDay {
+  final String date;
+  final int weekday;
+  final CalendarDayKind kind;
+  final String note;
+
+  const CalendarDay({
+    required this.date,
+    required this.weekday,
+    required this.kind,
+    required this.note,
+  });
+
+  @override
+  int get hashCode =>
+      date.hashCode ^ weekday.hashCode ^ kind.hashCode ^ note.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalendarDay &&
+          runtimeType == other.runtimeType &&
+          date == other.date &&
+          weekday == other.weekday &&
+          kind == other.kind &&
+          note == other.note;
+}
+
+enum CalendarDayKind {
+  /// 放假，不上课
+  holiday,
+  /// 调休上课的周末
+  workday,
+}
+
+class SchoolCalendar {
+  final String semester;
+  final String startDate;
+  final List<SchoolWeek> weeks;
+
+  const SchoolCalendar({
+    required this.semester,
+    required this.startDate,
+    required this.weeks,
+  });
+
+  @override
+  int get hashCode => semester.hashCode ^ startDate.hashCode ^ weeks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SchoolCalendar &&
+          runtimeType == other.runtimeType &&
+          semester == other.semester &&
+          startDate == other.startDate &&
+          weeks == other.weeks;
+}
+
+class SchoolWeek {
+  final int week;
+  final String monday;
+  final List<CalendarDay> holidays;
+  final List<CalendarDay> swapDays;
+
+  const SchoolWeek({
+    required this.week,
+    required this.monday,
+    required this.holidays,
+    required this.swapDays,
+  });
+
+  @override
+  int get hashCode =>
+      week.hashCode ^ monday.hashCode ^ holidays.hashCode ^ swapDays.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SchoolWeek &&
+          runtimeType == other.runtimeType &&
+          week == other.week &&
+          monday == other.monday &&
+          holidays == other.holidays &&
+          swapDays == other.swapDays;
+}
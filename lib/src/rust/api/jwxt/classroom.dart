@@ -0,0 +1,98 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `select_options`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+Future<ClassroomFilters> parseClassroomFilters({required String html}) =>
+    RustLib.instance.api.crateApiJwxtClassroomParseClassroomFilters(html: html);
+
+/// 解析教室使用情况，返回 `sections` 中各节都空闲的教室。
+/// 表格中有 "第1节" 这样的节次列时，单元格有内容表示该节已被占用；
+/// 没有节次列时表格只列出空闲教室，全部返回。有节次列但缺少所查的节次时返回
+/// `ApiError::InvalidArgument`
+Future<List<FreeClassroom>> parseFreeClassrooms({
+  required String html,
+  required List<int> sections,
+}) => RustLib.instance.api.crateApiJwxtClassroomParseFreeClassrooms(
+  html: html,
+  sections: sections,
+);
+
+class ClassroomFilters {
+  final List<FilterOption> campuses;
+  final List<FilterOption> buildings;
+
+  const ClassroomFilters({required this.campuses, required this.buildings});
+
+  @override
+  int get hashCode => campuses.hashCode ^ buildings.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ClassroomFilters &&
+          runtimeType == other.runtimeType &&
+          campuses == other.campuses &&
+          buildings == other.buildings;
+}
+
+/// 下拉框中的一个选项
+class FilterOption {
+  final String key;
+  final String value;
+
+  const FilterOption({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FilterOption &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
+
+class FreeClassroom {
+  final String name;
+  final String campus;
+  final String building;
+  final int capacity;
+  final String roomType;
+
+  const FreeClassroom({
+    required this.name,
+    required this.campus,
+    required this.building,
+    required this.capacity,
+    required this.roomType,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      campus.hashCode ^
+      building.hashCode ^
+      capacity.hashCode ^
+      roomType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FreeClassroom &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          campus == other.campus &&
+          building == other.building &&
+          capacity == other.capacity &&
+          roomType == other.roomType;
+}
@@ -4,9 +4,16 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `extract_duration`, `extract_text`, `extract_time_ranges`
+// These functions are ignored because they are not marked as `pub`: `extract_duration`, `extract_text`, `extract_time_ranges`, `extract_weeks`, `split_cell_courses`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `is_active_in_week`
+
+/// 解析周次文字，支持范围、列表与单双周，如 "4-17周"、"2,4,6周"、"1-8,10-16(单周)"。
+/// 只有最后一段带单双周标记时，视为对整个列表生效
+Future<Uint8List> parseWeeks({required String text}) =>
+    RustLib.instance.api.crateApiJwxtCourseParseWeeks(text: text);
 
 Future<List<CourseSchedule>> parseCourseSchedule({required String html}) =>
     RustLib.instance.api.crateApiJwxtCourseParseCourseSchedule(html: html);
@@ -19,6 +26,7 @@ class CourseSchedule {
   final String dayOfWeek;
   final BigInt section;
   final String weeks;
+  final Uint8List weekList;
   final String classroom;
   final String duration;
 
@@ -30,6 +38,7 @@ class CourseSchedule {
     required this.dayOfWeek,
     required this.section,
     required this.weeks,
+    required this.weekList,
     required this.classroom,
     required this.duration,
   });
@@ -43,6 +52,7 @@ class CourseSchedule {
       dayOfWeek.hashCode ^
       section.hashCode ^
       weeks.hashCode ^
+      weekList.hashCode ^
       classroom.hashCode ^
       duration.hashCode;
 
@@ -58,6 +68,7 @@ class CourseSchedule {
           dayOfWeek == other.dayOfWeek &&
           section == other.section &&
           weeks == other.weeks &&
+          weekList == other.weekList &&
           classroom == other.classroom &&
           duration == other.duration;
 }
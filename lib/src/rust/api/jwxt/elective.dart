@@ -4,6 +4,7 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 Future<ElectiveResponse> parseElective({required String html}) =>
@@ -11,11 +12,11 @@ Future<ElectiveResponse> parseElective({required String html}) =>
 
 class CreditInfo {
   final String category;
-  final int required_;
-  final int limited;
-  final int elective;
-  final int public;
-  final int total;
+  final double required_;
+  final double limited;
+  final double elective;
+  final double public;
+  final double total;
 
   const CreditInfo({
     required this.category,
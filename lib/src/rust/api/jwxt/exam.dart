@@ -4,35 +4,92 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `exam_countdown`, `parse_exam_time`, `time_span`
 
+/// 课程名称与考试时间为必需列，其余列缺失时留空
 Future<List<ExamSchedule>> parseExam({required String html}) =>
     RustLib.instance.api.crateApiJwxtExamParseExam(html: html);
 
+/// 一场考试的倒计时，以及与其他考试的冲突情况
+class ExamCountdown {
+  final ExamSchedule exam;
+  final PlatformInt64? remainingSeconds;
+  final bool isFinished;
+  final Uint32List overlapsWith;
+  final Uint32List sameDayWith;
+
+  const ExamCountdown({
+    required this.exam,
+    this.remainingSeconds,
+    required this.isFinished,
+    required this.overlapsWith,
+    required this.sameDayWith,
+  });
+
+  @override
+  int get hashCode =>
+      exam.hashCode ^
+      remainingSeconds.hashCode ^
+      isFinished.hashCode ^
+      overlapsWith.hashCode ^
+      sameDayWith.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExamCountdown &&
+          runtimeType == other.runtimeType &&
+          exam == other.exam &&
+          remainingSeconds == other.remainingSeconds &&
+          isFinished == other.isFinished &&
+          overlapsWith == other.overlapsWith &&
+          sameDayWith == other.sameDayWith;
+}
+
 class ExamSchedule {
   final int id;
+  final String campus;
   final String courseCode;
   final String courseName;
   final String examTime;
+  final String? startTime;
+  final String? endTime;
   final String examLocation;
+  final String seatNumber;
+  final String examMethod;
+  final String remark;
 
   const ExamSchedule({
     required this.id,
+    required this.campus,
     required this.courseCode,
     required this.courseName,
     required this.examTime,
+    this.startTime,
+    this.endTime,
     required this.examLocation,
+    required this.seatNumber,
+    required this.examMethod,
+    required this.remark,
   });
 
   @override
   int get hashCode =>
       id.hashCode ^
+      campus.hashCode ^
       courseCode.hashCode ^
       courseName.hashCode ^
       examTime.hashCode ^
-      examLocation.hashCode;
+      startTime.hashCode ^
+      endTime.hashCode ^
+      examLocation.hashCode ^
+      seatNumber.hashCode ^
+      examMethod.hashCode ^
+      remark.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -40,8 +97,14 @@ class ExamSchedule {
       other is ExamSchedule &&
           runtimeType == other.runtimeType &&
           id == other.id &&
+          campus == other.campus &&
           courseCode == other.courseCode &&
           courseName == other.courseName &&
           examTime == other.examTime &&
-          examLocation == other.examLocation;
+          startTime == other.startTime &&
+          endTime == other.endTime &&
+          examLocation == other.examLocation &&
+          seatNumber == other.seatNumber &&
+          examMethod == other.examMethod &&
+          remark == other.remark;
 }
@@ -0,0 +1,120 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `matches`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `compute_gpa`, `gpa_report`, `grade_point`
+
+/// 按课程性质、课程属性筛选参与计算的课程，列表为空时不限制
+class GpaFilter {
+  final List<String> courseNatures;
+  final List<String> courseAttrs;
+
+  const GpaFilter({required this.courseNatures, required this.courseAttrs});
+
+  static Future<GpaFilter> default_() =>
+      RustLib.instance.api.crateApiJwxtGpaGpaFilterDefault();
+
+  @override
+  int get hashCode => courseNatures.hashCode ^ courseAttrs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GpaFilter &&
+          runtimeType == other.runtimeType &&
+          courseNatures == other.courseNatures &&
+          courseAttrs == other.courseAttrs;
+}
+
+class GpaReport {
+  final GpaScheme scheme;
+  final GpaResult overall;
+  final List<SemesterGpa> semesters;
+
+  const GpaReport({
+    required this.scheme,
+    required this.overall,
+    required this.semesters,
+  });
+
+  @override
+  int get hashCode => scheme.hashCode ^ overall.hashCode ^ semesters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GpaReport &&
+          runtimeType == other.runtimeType &&
+          scheme == other.scheme &&
+          overall == other.overall &&
+          semesters == other.semesters;
+}
+
+class GpaResult {
+  final double? gpa;
+  final double? averageScore;
+  final double credits;
+  final int courseCount;
+
+  const GpaResult({
+    this.gpa,
+    this.averageScore,
+    required this.credits,
+    required this.courseCount,
+  });
+
+  @override
+  int get hashCode =>
+      gpa.hashCode ^
+      averageScore.hashCode ^
+      credits.hashCode ^
+      courseCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GpaResult &&
+          runtimeType == other.runtimeType &&
+          gpa == other.gpa &&
+          averageScore == other.averageScore &&
+          credits == other.credits &&
+          courseCount == other.courseCount;
+}
+
+/// 绩点算法，等级制成绩先按 `GradeScale` 换算成百分制再计算
+enum GpaScheme {
+  /// 教务系统成绩单中每门课程的绩点
+  school,
+  /// 标准 4.0：90/80/70/60 分别对应 4/3/2/1
+  standard4,
+  /// 改进 4.0：85/70/60 分别对应 4/3/2
+  improved4,
+  /// 北大 4.0：十档，90 分以上 4.0，60 分 1.0
+  pku4,
+  /// WES：85/75/60 分别对应 4/3/2
+  wes,
+}
+
+class SemesterGpa {
+  final String term;
+  final GpaResult result;
+
+  const SemesterGpa({required this.term, required this.result});
+
+  @override
+  int get hashCode => term.hashCode ^ result.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SemesterGpa &&
+          runtimeType == other.runtimeType &&
+          term == other.term &&
+          result == other.result;
+}
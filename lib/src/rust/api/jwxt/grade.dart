@@ -0,0 +1,98 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `value_of`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `is_counted`, `is_passed`, `parse`, `with_exam_nature`
+
+/// 一门课程的成绩，保留教务系统显示的原始文字
+class Grade {
+  final String raw;
+  final double? value;
+  final bool isAbsent;
+  final bool isExempt;
+  final bool isRetake;
+  final bool isMakeup;
+
+  const Grade({
+    required this.raw,
+    this.value,
+    required this.isAbsent,
+    required this.isExempt,
+    required this.isRetake,
+    required this.isMakeup,
+  });
+
+  @override
+  int get hashCode =>
+      raw.hashCode ^
+      value.hashCode ^
+      isAbsent.hashCode ^
+      isExempt.hashCode ^
+      isRetake.hashCode ^
+      isMakeup.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Grade &&
+          runtimeType == other.runtimeType &&
+          raw == other.raw &&
+          value == other.value &&
+          isAbsent == other.isAbsent &&
+          isExempt == other.isExempt &&
+          isRetake == other.isRetake &&
+          isMakeup == other.isMakeup;
+}
+
+/// 等级制成绩换算成百分制的分值，可由用户按本校规定调整
+class GradeScale {
+  final double excellent;
+  final double good;
+  final double medium;
+  final double pass;
+  final double fail;
+  final double qualified;
+  final double unqualified;
+
+  const GradeScale({
+    required this.excellent,
+    required this.good,
+    required this.medium,
+    required this.pass,
+    required this.fail,
+    required this.qualified,
+    required this.unqualified,
+  });
+
+  static Future<GradeScale> default_() =>
+      RustLib.instance.api.crateApiJwxtGradeGradeScaleDefault();
+
+  @override
+  int get hashCode =>
+      excellent.hashCode ^
+      good.hashCode ^
+      medium.hashCode ^
+      pass.hashCode ^
+      fail.hashCode ^
+      qualified.hashCode ^
+      unqualified.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GradeScale &&
+          runtimeType == other.runtimeType &&
+          excellent == other.excellent &&
+          good == other.good &&
+          medium == other.medium &&
+          pass == other.pass &&
+          fail == other.fail &&
+          qualified == other.qualified &&
+          unqualified == other.unqualified;
+}
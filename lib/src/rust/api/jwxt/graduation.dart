@@ -0,0 +1,99 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `new`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `graduation_check`
+
+/// 取出学分文字中的数字，如 "1.5"、"2分"，无法识别时为 0
+Future<double> parseCredit({required String text}) =>
+    RustLib.instance.api.crateApiJwxtGraduationParseCredit(text: text);
+
+/// 一项学分要求的完成情况
+class CreditBucket {
+  final CreditSource source;
+  final String name;
+  final double earned;
+  final double required_;
+  final double missing;
+  final bool passed;
+
+  const CreditBucket({
+    required this.source,
+    required this.name,
+    required this.earned,
+    required this.required_,
+    required this.missing,
+    required this.passed,
+  });
+
+  @override
+  int get hashCode =>
+      source.hashCode ^
+      name.hashCode ^
+      earned.hashCode ^
+      required_.hashCode ^
+      missing.hashCode ^
+      passed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CreditBucket &&
+          runtimeType == other.runtimeType &&
+          source == other.source &&
+          name == other.name &&
+          earned == other.earned &&
+          required_ == other.required_ &&
+          missing == other.missing &&
+          passed == other.passed;
+}
+
+enum CreditSource {
+  /// 课程学分
+  course,
+  /// 第二课堂学分
+  secondClassroom,
+}
+
+/// 第二课堂某一类别需要的学分，教务系统页面上没有给出，由用户按本校规定设置
+class DektRequirement {
+  final String category;
+  final double credits;
+
+  const DektRequirement({required this.category, required this.credits});
+
+  @override
+  int get hashCode => category.hashCode ^ credits.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DektRequirement &&
+          runtimeType == other.runtimeType &&
+          category == other.category &&
+          credits == other.credits;
+}
+
+class GraduationReport {
+  final List<CreditBucket> buckets;
+  final bool passed;
+
+  const GraduationReport({required this.buckets, required this.passed});
+
+  @override
+  int get hashCode => buckets.hashCode ^ passed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GraduationReport &&
+          runtimeType == other.runtimeType &&
+          buckets == other.buckets &&
+          passed == other.passed;
+}
@@ -4,34 +4,58 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
+// These functions are ignored because they are not marked as `pub`: `collect_fields`, `is_label`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `new`
 
 Future<StudentInfo> parseStudentInfo({required String html}) =>
     RustLib.instance.api.crateApiJwxtInfoParseStudentInfo(html: html);
 
+/// 学籍卡片中照片的地址，通常为 "/jsxsd/grxx/xszpLoad"
+Future<String> parsePhotoSrc({required String html}) =>
+    RustLib.instance.api.crateApiJwxtInfoParsePhotoSrc(html: html);
+
+/// 学籍卡片中的个人信息，卡片上没有的项为 None
 class StudentInfo {
-  final String name;
-  final String gender;
-  final String studentId;
-  final String department;
-  final String major;
-  final String className;
-  final String admissionDate;
-  final String admissionNumber;
-  final String idNumber;
+  final String? name;
+  final String? gender;
+  final String? studentId;
+  final String? department;
+  final String? major;
+  final String? className;
+  final String? admissionDate;
+  final String? admissionNumber;
+  final String? idNumber;
+  final String? birthDate;
+  final String? ethnicity;
+  final String? politicalStatus;
+  final String? hometown;
+  final String? educationLevel;
+  final String? lengthOfSchooling;
+  final String? contact;
+  final String? highSchool;
 
   const StudentInfo({
-    required this.name,
-    required this.gender,
-    required this.studentId,
-    required this.department,
-    required this.major,
-    required this.className,
-    required this.admissionDate,
-    required this.admissionNumber,
-    required this.idNumber,
+    this.name,
+    this.gender,
+    this.studentId,
+    this.department,
+    this.major,
+    this.className,
+    this.admissionDate,
+    this.admissionNumber,
+    this.idNumber,
+    this.birthDate,
+    this.ethnicity,
+    this.politicalStatus,
+    this.hometown,
+    this.educationLevel,
+    this.lengthOfSchooling,
+    this.contact,
+    this.highSchool,
   });
 
   @override
@@ -44,7 +68,15 @@ class StudentInfo {
       className.hashCode ^
       admissionDate.hashCode ^
       admissionNumber.hashCode ^
-      idNumber.hashCode;
+      idNumber.hashCode ^
+      birthDate.hashCode ^
+      ethnicity.hashCode ^
+      politicalStatus.hashCode ^
+      hometown.hashCode ^
+      educationLevel.hashCode ^
+      lengthOfSchooling.hashCode ^
+      contact.hashCode ^
+      highSchool.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -59,5 +91,32 @@ class StudentInfo {
           className == other.className &&
           admissionDate == other.admissionDate &&
           admissionNumber == other.admissionNumber &&
-          idNumber == other.idNumber;
+          idNumber == other.idNumber &&
+          birthDate == other.birthDate &&
+          ethnicity == other.ethnicity &&
+          politicalStatus == other.politicalStatus &&
+          hometown == other.hometown &&
+          educationLevel == other.educationLevel &&
+          lengthOfSchooling == other.lengthOfSchooling &&
+          contact == other.contact &&
+          highSchool == other.highSchool;
+}
+
+/// 学籍卡片上的学生照片
+class StudentPhoto {
+  final Uint8List bytes;
+  final String mimeType;
+
+  const StudentPhoto({required this.bytes, required this.mimeType});
+
+  @override
+  int get hashCode => bytes.hashCode ^ mimeType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StudentPhoto &&
+          runtimeType == other.runtimeType &&
+          bytes == other.bytes &&
+          mimeType == other.mimeType;
 }
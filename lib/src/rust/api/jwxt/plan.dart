@@ -4,14 +4,19 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
+
 Future<ExecutionPlanResponse> parsePlan({required String html}) =>
     RustLib.instance.api.crateApiJwxtPlanParsePlan(html: html);
 
 class ExecutionPlan {
   final int id;
   final String semester;
+  final String academicYear;
+  final int term;
   final String courseCode;
   final String courseName;
   final String department;
@@ -24,6 +29,8 @@ class ExecutionPlan {
   const ExecutionPlan({
     required this.id,
     required this.semester,
+    required this.academicYear,
+    required this.term,
     required this.courseCode,
     required this.courseName,
     required this.department,
@@ -38,6 +45,8 @@ class ExecutionPlan {
   int get hashCode =>
       id.hashCode ^
       semester.hashCode ^
+      academicYear.hashCode ^
+      term.hashCode ^
       courseCode.hashCode ^
       courseName.hashCode ^
       department.hashCode ^
@@ -54,6 +63,8 @@ class ExecutionPlan {
           runtimeType == other.runtimeType &&
           id == other.id &&
           semester == other.semester &&
+          academicYear == other.academicYear &&
+          term == other.term &&
           courseCode == other.courseCode &&
           courseName == other.courseName &&
           department == other.department &&
@@ -67,11 +78,16 @@ class ExecutionPlan {
 class ExecutionPlanResponse {
   final List<ExecutionPlan> plans;
   final List<String> semesters;
+  final List<PlanSemester> groups;
 
-  const ExecutionPlanResponse({required this.plans, required this.semesters});
+  const ExecutionPlanResponse({
+    required this.plans,
+    required this.semesters,
+    required this.groups,
+  });
 
   @override
-  int get hashCode => plans.hashCode ^ semesters.hashCode;
+  int get hashCode => plans.hashCode ^ semesters.hashCode ^ groups.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -79,5 +95,46 @@ class ExecutionPlanResponse {
       other is ExecutionPlanResponse &&
           runtimeType == other.runtimeType &&
           plans == other.plans &&
-          semesters == other.semesters;
+          semesters == other.semesters &&
+          groups == other.groups;
+}
+
+/// 一个学期的培养方案课程及学分、学时合计
+class PlanSemester {
+  final String semester;
+  final String academicYear;
+  final int term;
+  final double credits;
+  final double totalHours;
+  final List<ExecutionPlan> plans;
+
+  const PlanSemester({
+    required this.semester,
+    required this.academicYear,
+    required this.term,
+    required this.credits,
+    required this.totalHours,
+    required this.plans,
+  });
+
+  @override
+  int get hashCode =>
+      semester.hashCode ^
+      academicYear.hashCode ^
+      term.hashCode ^
+      credits.hashCode ^
+      totalHours.hashCode ^
+      plans.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlanSemester &&
+          runtimeType == other.runtimeType &&
+          semester == other.semester &&
+          academicYear == other.academicYear &&
+          term == other.term &&
+          credits == other.credits &&
+          totalHours == other.totalHours &&
+          plans == other.plans;
 }
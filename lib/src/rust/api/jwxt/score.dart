@@ -4,23 +4,23 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../error.dart';
+import 'grade.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-Future<List<ScoreInfo>> parseScore({required String html}) =>
-    RustLib.instance.api.crateApiJwxtScoreParseScore(html: html);
+// These functions are ignored because they are not marked as `pub`: `credit_weighted`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `effective_scores`, `gpa_average`, `parse_score_all`, `parse_score`, `weighted_average`
 
 Future<ScoreSummary> parseScoreSummary({required String html}) =>
     RustLib.instance.api.crateApiJwxtScoreParseScoreSummary(html: html);
 
-Future<ScoreTotal> parseScoreAll({required String html}) =>
-    RustLib.instance.api.crateApiJwxtScoreParseScoreAll(html: html);
-
 class ScoreInfo {
   final int id;
   final String term;
   final String courseId;
   final String courseName;
-  final double score;
+  final Grade score;
   final double credit;
   final double hours;
   final double gpa;
@@ -77,11 +77,18 @@ class ScoreInfo {
 class ScoreSummary {
   final double creditTotal;
   final double gpaAverage;
+  /// 按课程成绩计算的学分加权平均分，没有可计入的成绩时为 None
+  final double? averageScore;
 
-  const ScoreSummary({required this.creditTotal, required this.gpaAverage});
+  const ScoreSummary({
+    required this.creditTotal,
+    required this.gpaAverage,
+    this.averageScore,
+  });
 
   @override
-  int get hashCode => creditTotal.hashCode ^ gpaAverage.hashCode;
+  int get hashCode =>
+      creditTotal.hashCode ^ gpaAverage.hashCode ^ averageScore.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -89,7 +96,8 @@ class ScoreSummary {
       other is ScoreSummary &&
           runtimeType == other.runtimeType &&
           creditTotal == other.creditTotal &&
-          gpaAverage == other.gpaAverage;
+          gpaAverage == other.gpaAverage &&
+          averageScore == other.averageScore;
 }
 
 class ScoreTotal {
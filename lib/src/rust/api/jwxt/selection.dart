@@ -0,0 +1,183 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `alert_message`, `classify`, `json_parse`, `json_text`, `list_path`, `oper_path`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+Future<List<SelectionRound>> parseSelectionRounds({required String html}) =>
+    RustLib.instance.api.crateApiJwxtSelectionParseSelectionRounds(html: html);
+
+/// 进入选课轮次（xsxk_index）的结果。未开放时页面只有一个 alert 提示
+Future<SelectionOutcome> parseEnterResult({required String html}) =>
+    RustLib.instance.api.crateApiJwxtSelectionParseEnterResult(html: html);
+
+/// 解析课程列表接口返回的 JSON（DataTables 格式，课程在 `aaData` 中）。
+/// 没有进入选课轮次或选课已结束时接口返回 HTML 页面，此时返回 `ParseLayoutChanged`
+Future<List<SelectableCourse>> parseSelectionCourses({
+  required String json,
+  required SelectionCategory category,
+}) => RustLib.instance.api.crateApiJwxtSelectionParseSelectionCourses(
+  json: json,
+  category: category,
+);
+
+/// 解析选课、退课接口的结果 `{"success": true, "message": "..."}`。
+/// 不在选课时间内时接口可能直接返回带 alert 的 HTML 页面
+Future<SelectionOutcome> parseSelectionOutcome({required String text}) =>
+    RustLib.instance.api.crateApiJwxtSelectionParseSelectionOutcome(text: text);
+
+/// 可选的教学班
+class SelectableCourse {
+  final String id;
+  final SelectionCategory category;
+  final String courseCode;
+  final String courseName;
+  final double credits;
+  final String teacher;
+  final String time;
+  final String location;
+  final String campus;
+  final int capacity;
+  final int selected;
+  final int remaining;
+  final String conflict;
+
+  const SelectableCourse({
+    required this.id,
+    required this.category,
+    required this.courseCode,
+    required this.courseName,
+    required this.credits,
+    required this.teacher,
+    required this.time,
+    required this.location,
+    required this.campus,
+    required this.capacity,
+    required this.selected,
+    required this.remaining,
+    required this.conflict,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      category.hashCode ^
+      courseCode.hashCode ^
+      courseName.hashCode ^
+      credits.hashCode ^
+      teacher.hashCode ^
+      time.hashCode ^
+      location.hashCode ^
+      campus.hashCode ^
+      capacity.hashCode ^
+      selected.hashCode ^
+      remaining.hashCode ^
+      conflict.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SelectableCourse &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          category == other.category &&
+          courseCode == other.courseCode &&
+          courseName == other.courseName &&
+          credits == other.credits &&
+          teacher == other.teacher &&
+          time == other.time &&
+          location == other.location &&
+          campus == other.campus &&
+          capacity == other.capacity &&
+          selected == other.selected &&
+          remaining == other.remaining &&
+          conflict == other.conflict;
+}
+
+/// 可选课程的类别，对应选课页面的各个标签页
+enum SelectionCategory {
+  /// 公选课
+  publicElective,
+  /// 专业选修课
+  majorElective,
+  /// 跨专业选课（培养方案外）
+  crossMajor,
+}
+
+/// 进入选课、选课、退课的结果，`message` 为教务系统返回的原始提示
+class SelectionOutcome {
+  final SelectionResult result;
+  final String message;
+
+  const SelectionOutcome({required this.result, required this.message});
+
+  @override
+  int get hashCode => result.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SelectionOutcome &&
+          runtimeType == other.runtimeType &&
+          result == other.result &&
+          message == other.message;
+}
+
+enum SelectionResult {
+  /// 选课或退课成功
+  success,
+  /// 与已选课程上课时间冲突
+  timeConflict,
+  /// 教学班人数已满
+  full,
+  /// 不在选课时间内或选课未开放
+  outsideWindow,
+  /// 其他原因失败，见 `message`
+  failed,
+}
+
+/// 一个选课轮次
+class SelectionRound {
+  final String id;
+  final String semester;
+  final String name;
+  final String startTime;
+  final String endTime;
+  final bool canEnter;
+
+  const SelectionRound({
+    required this.id,
+    required this.semester,
+    required this.name,
+    required this.startTime,
+    required this.endTime,
+    required this.canEnter,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      semester.hashCode ^
+      name.hashCode ^
+      startTime.hashCode ^
+      endTime.hashCode ^
+      canEnter.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SelectionRound &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          semester == other.semester &&
+          name == other.name &&
+          startTime == other.startTime &&
+          endTime == other.endTime &&
+          canEnter == other.canEnter;
+}
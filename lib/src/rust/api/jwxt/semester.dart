@@ -4,8 +4,18 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `semester_info`, `week_monday`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `current_week`, `mark_current`, `teaching_week`
+
+/// 拆分学期代码 "2023-2024-1" 为学年 "2023-2024" 与学期序号 1，格式不符时为 None
+Future<(String, int)?> splitSemester({required String semester}) =>
+    RustLib.instance.api.crateApiJwxtSemesterSplitSemester(semester: semester);
+
+/// 解析学期下拉框，按时间倒序保留最近 10 个学期，选中的选项记为当前学期
 Future<List<SemesterInfo>> parseSemester({
   required String html,
   required bool isAll,
@@ -14,14 +24,68 @@ Future<List<SemesterInfo>> parseSemester({
   isAll: isAll,
 );
 
+/// 解析校历页面（jxzl_query）：选中的学期及其第一周的周一
+Future<SemesterStart> parseSemesterStart({required String html}) =>
+    RustLib.instance.api.crateApiJwxtSemesterParseSemesterStart(html: html);
+
+/// 今天所在的教学周
+class CurrentWeek {
+  final String semester;
+  final String startDate;
+  final int week;
+  final int weekday;
+  final bool isOverridden;
+
+  const CurrentWeek({
+    required this.semester,
+    required this.startDate,
+    required this.week,
+    required this.weekday,
+    required this.isOverridden,
+  });
+
+  @override
+  int get hashCode =>
+      semester.hashCode ^
+      startDate.hashCode ^
+      week.hashCode ^
+      weekday.hashCode ^
+      isOverridden.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CurrentWeek &&
+          runtimeType == other.runtimeType &&
+          semester == other.semester &&
+          startDate == other.startDate &&
+          week == other.week &&
+          weekday == other.weekday &&
+          isOverridden == other.isOverridden;
+}
+
 class SemesterInfo {
   final String key;
   final String value;
+  final String academicYear;
+  final int term;
+  final bool isCurrent;
 
-  const SemesterInfo({required this.key, required this.value});
+  const SemesterInfo({
+    required this.key,
+    required this.value,
+    required this.academicYear,
+    required this.term,
+    required this.isCurrent,
+  });
 
   @override
-  int get hashCode => key.hashCode ^ value.hashCode;
+  int get hashCode =>
+      key.hashCode ^
+      value.hashCode ^
+      academicYear.hashCode ^
+      term.hashCode ^
+      isCurrent.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -29,5 +93,27 @@ class SemesterInfo {
       other is SemesterInfo &&
           runtimeType == other.runtimeType &&
           key == other.key &&
-          value == other.value;
+          value == other.value &&
+          academicYear == other.academicYear &&
+          term == other.term &&
+          isCurrent == other.isCurrent;
+}
+
+/// 学期的第一天，取自校历页面或由用户设置
+class SemesterStart {
+  final String semester;
+  final String startDate;
+
+  const SemesterStart({required this.semester, required this.startDate});
+
+  @override
+  int get hashCode => semester.hashCode ^ startDate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SemesterStart &&
+          runtimeType == other.runtimeType &&
+          semester == other.semester &&
+          startDate == other.startDate;
 }
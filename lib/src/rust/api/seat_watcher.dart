@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'jwxt/selection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'seat_watcher.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_fatal`, `next_delay`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `watch_seats`
+
+class SeatWatchConfig {
+  final SelectionCategory category;
+  final List<String> courseIds;
+  final int intervalMs;
+  final int jitterMs;
+  final int? maxRounds;
+
+  const SeatWatchConfig({
+    required this.category,
+    required this.courseIds,
+    required this.intervalMs,
+    required this.jitterMs,
+    this.maxRounds,
+  });
+
+  @override
+  int get hashCode =>
+      category.hashCode ^
+      courseIds.hashCode ^
+      intervalMs.hashCode ^
+      jitterMs.hashCode ^
+      maxRounds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SeatWatchConfig &&
+          runtimeType == other.runtimeType &&
+          category == other.category &&
+          courseIds == other.courseIds &&
+          intervalMs == other.intervalMs &&
+          jitterMs == other.jitterMs &&
+          maxRounds == other.maxRounds;
+}
+
+@freezed
+sealed class SeatWatchEvent with _$SeatWatchEvent {
+  const SeatWatchEvent._();
+
+  /// 完成一轮查询，`courses` 为仍在等待的教学班的最新名额
+  const factory SeatWatchEvent.polled({
+    required int round,
+    required List<SelectableCourse> courses,
+  }) = SeatWatchEvent_Polled;
+  /// 出现空位后尝试选课的结果
+  const factory SeatWatchEvent.attempted({
+    required SelectableCourse course,
+    required SelectionOutcome outcome,
+  }) = SeatWatchEvent_Attempted;
+  /// 查询或选课出错，会话失效、页面结构变化以外的错误之后仍会继续查询
+  const factory SeatWatchEvent.error({required String message}) =
+      SeatWatchEvent_Error;
+  /// 监视结束，`pending` 为没有选上的教学班
+  const factory SeatWatchEvent.finished({
+    required List<String> selected,
+    required List<String> pending,
+  }) = SeatWatchEvent_Finished;
+}
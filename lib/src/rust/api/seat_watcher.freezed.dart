@@ -0,0 +1,327 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'seat_watcher.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$SeatWatchEvent {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SeatWatchEvent);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SeatWatchEvent()';
+}
+
+
+}
+
+/// @nodoc
+class $SeatWatchEventCopyWith<$Res>  {
+$SeatWatchEventCopyWith(SeatWatchEvent _, $Res Function(SeatWatchEvent) __);
+}
+
+
+/// @nodoc
+
+
+class SeatWatchEvent_Polled extends SeatWatchEvent {
+  const SeatWatchEvent_Polled({required this.round, required final  List<SelectableCourse> courses}): _courses = courses,super._();
+  
+
+ final  int round;
+ final  List<SelectableCourse> _courses;
+ List<SelectableCourse> get courses {
+  if (_courses is EqualUnmodifiableListView) return _courses;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_courses);
+}
+
+
+/// Create a copy of SeatWatchEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline')
+$SeatWatchEvent_PolledCopyWith<SeatWatchEvent_Polled> get copyWith => _$SeatWatchEvent_PolledCopyWithImpl<SeatWatchEvent_Polled>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SeatWatchEvent_Polled&&(identical(other.round, round) || other.round == round)&&const DeepCollectionEquality().equals(other._courses, _courses));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,round,const DeepCollectionEquality().hash(_courses));
+
+@override
+String toString() {
+  return 'SeatWatchEvent.polled(round: $round, courses: $courses)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SeatWatchEvent_PolledCopyWith<$Res> implements $SeatWatchEventCopyWith<$Res> {
+  factory $SeatWatchEvent_PolledCopyWith(SeatWatchEvent_Polled value, $Res Function(SeatWatchEvent_Polled) _then) = _$SeatWatchEvent_PolledCopyWithImpl;
+@useResult
+$Res call({
+ int round, List<SelectableCourse> courses
+});
+
+
+
+
+}
+/// @nodoc
+class _$SeatWatchEvent_PolledCopyWithImpl<$Res>
+    implements $SeatWatchEvent_PolledCopyWith<$Res> {
+  _$SeatWatchEvent_PolledCopyWithImpl(this._self, this._then);
+
+  final SeatWatchEvent_Polled _self;
+  final $Res Function(SeatWatchEvent_Polled) _then;
+
+/// Create a copy of SeatWatchEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? round = null,Object? courses = null,}) {
+  return _then(SeatWatchEvent_Polled(
+round: null == round ? _self.round : round // ignore: cast_nullable_to_non_nullable
+as int,courses: null == courses ? _self._courses : courses // ignore: cast_nullable_to_non_nullable
+as List<SelectableCourse>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SeatWatchEvent_Attempted extends SeatWatchEvent {
+  const SeatWatchEvent_Attempted({required this.course, required this.outcome}): super._();
+  
+
+ final  SelectableCourse course;
+ final  SelectionOutcome outcome;
+
+/// Create a copy of SeatWatchEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline')
+$SeatWatchEvent_AttemptedCopyWith<SeatWatchEvent_Attempted> get copyWith => _$SeatWatchEvent_AttemptedCopyWithImpl<SeatWatchEvent_Attempted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SeatWatchEvent_Attempted&&(identical(other.course, course) || other.course == course)&&(identical(other.outcome, outcome) || other.outcome == outcome));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,course,outcome);
+
+@override
+String toString() {
+  return 'SeatWatchEvent.attempted(course: $course, outcome: $outcome)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SeatWatchEvent_AttemptedCopyWith<$Res> implements $SeatWatchEventCopyWith<$Res> {
+  factory $SeatWatchEvent_AttemptedCopyWith(SeatWatchEvent_Attempted value, $Res Function(SeatWatchEvent_Attempted) _then) = _$SeatWatchEvent_AttemptedCopyWithImpl;
+@useResult
+$Res call({
+ SelectableCourse course, SelectionOutcome outcome
+});
+
+
+
+
+}
+/// @nodoc
+class _$SeatWatchEvent_AttemptedCopyWithImpl<$Res>
+    implements $SeatWatchEvent_AttemptedCopyWith<$Res> {
+  _$SeatWatchEvent_AttemptedCopyWithImpl(this._self, this._then);
+
+  final SeatWatchEvent_Attempted _self;
+  final $Res Function(SeatWatchEvent_Attempted) _then;
+
+/// Create a copy of SeatWatchEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? course = null,Object? outcome = null,}) {
+  return _then(SeatWatchEvent_Attempted(
+course: null == course ? _self.course : course // ignore: cast_nullable_to_non_nullable
+as SelectableCourse,outcome: null == outcome ? _self.outcome : outcome // ignore: cast_nullable_to_non_nullable
+as SelectionOutcome,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SeatWatchEvent_Error extends SeatWatchEvent {
+  const SeatWatchEvent_Error({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of SeatWatchEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline')
+$SeatWatchEvent_ErrorCopyWith<SeatWatchEvent_Error> get copyWith => _$SeatWatchEvent_ErrorCopyWithImpl<SeatWatchEvent_Error>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SeatWatchEvent_Error&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'SeatWatchEvent.error(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SeatWatchEvent_ErrorCopyWith<$Res> implements $SeatWatchEventCopyWith<$Res> {
+  factory $SeatWatchEvent_ErrorCopyWith(SeatWatchEvent_Error value, $Res Function(SeatWatchEvent_Error) _then) = _$SeatWatchEvent_ErrorCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$SeatWatchEvent_ErrorCopyWithImpl<$Res>
+    implements $SeatWatchEvent_ErrorCopyWith<$Res> {
+  _$SeatWatchEvent_ErrorCopyWithImpl(this._self, this._then);
+
+  final SeatWatchEvent_Error _self;
+  final $Res Function(SeatWatchEvent_Error) _then;
+
+/// Create a copy of SeatWatchEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(SeatWatchEvent_Error(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SeatWatchEvent_Finished extends SeatWatchEvent {
+  const SeatWatchEvent_Finished({required final  List<String> selected, required final  List<String> pending}): _selected = selected,_pending = pending,super._();
+  
+
+ final  List<String> _selected;
+ List<String> get selected {
+  if (_selected is EqualUnmodifiableListView) return _selected;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_selected);
+}
+
+ final  List<String> _pending;
+ List<String> get pending {
+  if (_pending is EqualUnmodifiableListView) return _pending;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_pending);
+}
+
+
+/// Create a copy of SeatWatchEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline')
+$SeatWatchEvent_FinishedCopyWith<SeatWatchEvent_Finished> get copyWith => _$SeatWatchEvent_FinishedCopyWithImpl<SeatWatchEvent_Finished>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SeatWatchEvent_Finished&&const DeepCollectionEquality().equals(other._selected, _selected)&&const DeepCollectionEquality().equals(other._pending, _pending));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_selected),const DeepCollectionEquality().hash(_pending));
+
+@override
+String toString() {
+  return 'SeatWatchEvent.finished(selected: $selected, pending: $pending)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SeatWatchEvent_FinishedCopyWith<$Res> implements $SeatWatchEventCopyWith<$Res> {
+  factory $SeatWatchEvent_FinishedCopyWith(SeatWatchEvent_Finished value, $Res Function(SeatWatchEvent_Finished) _then) = _$SeatWatchEvent_FinishedCopyWithImpl;
+@useResult
+$Res call({
+ List<String> selected, List<String> pending
+});
+
+
+
+
+}
+/// @nodoc
+class _$SeatWatchEvent_FinishedCopyWithImpl<$Res>
+    implements $SeatWatchEvent_FinishedCopyWith<$Res> {
+  _$SeatWatchEvent_FinishedCopyWithImpl(this._self, this._then);
+
+  final SeatWatchEvent_Finished _self;
+  final $Res Function(SeatWatchEvent_Finished) _then;
+
+/// Create a copy of SeatWatchEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? selected = null,Object? pending = null,}) {
+  return _then(SeatWatchEvent_Finished(
+selected: null == selected ? _self._selected : selected // ignore: cast_nullable_to_non_nullable
+as List<String>,pending: null == pending ? _self._pending : pending // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -5,10 +5,12 @@
 
 import '../frb_generated.dart';
 import '../lib.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `login_jwxt`, `login_vpn`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
+// These functions are ignored because they are not marked as `pub`: `build`, `cas_url`, `credentials`, `detect_login_page`, `fetch_page`, `get_bytes`, `get_page_with_query`, `get_page`, `jwxt_url`, `login_jwxt`, `login_vpn`, `post_page`, `relogin_with_credentials`, `relogin`, `set_login_state`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Credentials`, `LoginPage`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>>
 abstract class HttpSession implements RustOpaqueInterface {
@@ -29,11 +31,81 @@ abstract class HttpSession implements RustOpaqueInterface {
     required String captcha,
   });
 
+  static Future<HttpSession> default_() =>
+      RustLib.instance.api.crateApiSessionHttpSessionDefault();
+
   Future<Uint8List> getCaptcha();
 
   Future<String> getFlowExecutionKey();
 
+  /// 访问教务系统主页，判断服务端会话是否仍然有效
+  Future<bool> isAlive();
+
+  /// 从 `save_cookies` 写出的文件重建会话
+  static Future<HttpSession> loadCookies({
+    required String path,
+    required Endpoints endpoints,
+  }) => RustLib.instance.api.crateApiSessionHttpSessionLoadCookies(
+    path: path,
+    endpoints: endpoints,
+  );
+
+  Future<LoginState> loginState();
+
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
   static Future<HttpSession> newInstance() =>
       RustLib.instance.api.crateApiSessionHttpSessionNew();
+
+  /// 保存全部 Cookie，JSESSIONID 等会话 Cookie 没有过期时间，也需要一并写出
+  Future<void> saveCookies({required String path});
+
+  /// 保存账号密码，会话过期时用于免验证码重新登录
+  Future<void> setCredentials({
+    required String username,
+    required String vpnPassword,
+    required String oaPassword,
+  });
+
+  static Future<HttpSession> withEndpoints({required Endpoints endpoints}) =>
+      RustLib.instance.api.crateApiSessionHttpSessionWithEndpoints(
+        endpoints: endpoints,
+      );
+}
+
+/// 服务地址，默认经由学校 WebVPN 访问，也可以指向校内直连地址或本地模拟服务
+class Endpoints {
+  /// VPN 统一认证地址
+  final String cas;
+  /// 教务系统地址
+  final String jwxt;
+
+  const Endpoints({required this.cas, required this.jwxt});
+
+  static Future<Endpoints> default_() =>
+      RustLib.instance.api.crateApiSessionEndpointsDefault();
+
+  @override
+  int get hashCode => cas.hashCode ^ jwxt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Endpoints &&
+          runtimeType == other.runtimeType &&
+          cas == other.cas &&
+          jwxt == other.jwxt;
+}
+
+/// 登录状态机：NoSession → CaptchaFetched → VpnAuthenticated → JwxtAuthenticated → Expired
+enum LoginState {
+  /// 尚未创建会话
+  noSession,
+  /// 已获取验证码，等待登录
+  captchaFetched,
+  /// VPN 登录成功，教务系统尚未登录
+  vpnAuthenticated,
+  /// 教务系统登录成功
+  jwxtAuthenticated,
+  /// 会话已过期且无法自动重新登录
+  expired,
 }
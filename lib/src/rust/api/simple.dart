@@ -4,15 +4,49 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
+import 'ics.dart';
+import 'jwxt/audit.dart';
+import 'jwxt/calendar.dart';
+import 'jwxt/classroom.dart';
 import 'jwxt/course.dart';
 import 'jwxt/dekt.dart';
 import 'jwxt/elective.dart';
 import 'jwxt/exam.dart';
+import 'jwxt/gpa.dart';
+import 'jwxt/grade.dart';
+import 'jwxt/graduation.dart';
 import 'jwxt/info.dart';
 import 'jwxt/plan.dart';
 import 'jwxt/score.dart';
+import 'jwxt/selection.dart';
 import 'jwxt/semester.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'seat_watcher.dart';
+import 'session.dart';
+
+// These functions are ignored because they are not marked as `pub`: `existing_session`, `fetch_scores`, `fetch_selection_courses`, `lock_poisoned`, `logged_in_session`, `new_session`, `replace_session`, `session_slot`, `submit_selection`
+
+/// 设置 VPN 与教务系统地址，之后创建的会话使用新地址
+void apiSetEndpoints({required String cas, required String jwxt}) =>
+    RustLib.instance.api.crateApiSimpleApiSetEndpoints(cas: cas, jwxt: jwxt);
+
+/// 设置等级制成绩（优秀、良好等）换算成百分制的分值，之后查询的成绩按新分值计算
+void apiSetGradeScale({required GradeScale scale}) =>
+    RustLib.instance.api.crateApiSimpleApiSetGradeScale(scale: scale);
+
+/// 设置第二课堂各类别需要的学分，用于 `api_graduation_check`
+void apiSetDektRequirements({required List<DektRequirement> requirements}) =>
+    RustLib.instance.api.crateApiSimpleApiSetDektRequirements(
+      requirements: requirements,
+    );
+
+/// 手动设置学期的开学日期（YYYY-MM-DD），用于计算教学周；为 None 时恢复从校历获取
+void apiSetSemesterStart({SemesterStart? start}) =>
+    RustLib.instance.api.crateApiSimpleApiSetSemesterStart(start: start);
+
+Future<LoginState> apiLoginState() =>
+    RustLib.instance.api.crateApiSimpleApiLoginState();
 
 Future<String> apiLogin({
   required String username,
@@ -26,29 +60,180 @@ Future<String> apiLogin({
   captcha: captcha,
 );
 
+Future<void> apiLogout() => RustLib.instance.api.crateApiSimpleApiLogout();
+
+Future<void> apiSaveSession({required String path}) =>
+    RustLib.instance.api.crateApiSimpleApiSaveSession(path: path);
+
+/// 从 Cookie 文件恢复会话，服务端会话仍有效时返回 true，此时无需再调用 `api_login`。
+/// 会话已过期时仍会保留 Cookie，配合 `api_set_credentials` 可以免验证码重新登录
+Future<bool> apiRestoreSession({required String path}) =>
+    RustLib.instance.api.crateApiSimpleApiRestoreSession(path: path);
+
+Future<void> apiSetCredentials({
+  required String username,
+  required String vpnPassword,
+  required String oaPassword,
+}) => RustLib.instance.api.crateApiSimpleApiSetCredentials(
+  username: username,
+  vpnPassword: vpnPassword,
+  oaPassword: oaPassword,
+);
+
+Future<bool> apiSessionAlive() =>
+    RustLib.instance.api.crateApiSimpleApiSessionAlive();
+
 Future<StudentInfo> apiStudentInfo() =>
     RustLib.instance.api.crateApiSimpleApiStudentInfo();
 
+/// 下载学籍卡片上的照片
+Future<StudentPhoto> apiStudentPhoto() =>
+    RustLib.instance.api.crateApiSimpleApiStudentPhoto();
+
 Future<List<SemesterInfo>> apiSemester({required bool isAll}) =>
     RustLib.instance.api.crateApiSimpleApiSemester(isAll: isAll);
 
+/// 今天是当前学期的第几教学周。优先使用 `api_set_semester_start` 设置的开学日期，
+/// 未设置时从校历页面获取
+Future<CurrentWeek> apiCurrentWeek() =>
+    RustLib.instance.api.crateApiSimpleApiCurrentWeek();
+
 Future<ScoreTotal> apiScore({required String semester}) =>
     RustLib.instance.api.crateApiSimpleApiScore(semester: semester);
 
+/// 按指定算法计算总体与每学期的绩点，`semester` 为空时统计全部学期
+Future<GpaReport> apiGpa({
+  required String semester,
+  required GpaScheme scheme,
+  required GpaFilter filter,
+}) => RustLib.instance.api.crateApiSimpleApiGpa(
+  semester: semester,
+  scheme: scheme,
+  filter: filter,
+);
+
 Future<List<CourseSchedule>> apiCourse({required String semester}) =>
     RustLib.instance.api.crateApiSimpleApiCourse(semester: semester);
 
+/// 只返回第 `week` 周有课的课程
+Future<List<CourseSchedule>> apiCourseForWeek({
+  required String semester,
+  required int week,
+}) => RustLib.instance.api.crateApiSimpleApiCourseForWeek(
+  semester: semester,
+  week: week,
+);
+
+/// 导出课表为 iCalendar 文本，`first_monday` 为第一周星期一 "YYYY-MM-DD"，
+/// `section_times` 为空或缺少某节时使用课表中的时间范围。UID 由学期、课程与周次确定，
+/// 重复导入会更新原有日程而不是重复添加；校历中放假的日期不生成日程
+Future<String> apiExportCourseIcs({
+  required String semester,
+  required String firstMonday,
+  required List<SectionTime> sectionTimes,
+}) => RustLib.instance.api.crateApiSimpleApiExportCourseIcs(
+  semester: semester,
+  firstMonday: firstMonday,
+  sectionTimes: sectionTimes,
+);
+
+/// 教学周历，包含每周的星期一及节假日、调休安排，`semester` 为空时为当前学期
+Future<SchoolCalendar> apiSchoolCalendar({required String semester}) =>
+    RustLib.instance.api.crateApiSimpleApiSchoolCalendar(semester: semester);
+
 Future<List<ExamSchedule>> apiExam({required String semester}) =>
     RustLib.instance.api.crateApiSimpleApiExam(semester: semester);
 
+/// 导出考试安排为 iCalendar 文本，`alarm_minutes` 为提前提醒的分钟数，
+/// 为空时默认提前一天和一小时提醒
+Future<String> apiExportExamIcs({
+  required String semester,
+  required List<int> alarmMinutes,
+}) => RustLib.instance.api.crateApiSimpleApiExportExamIcs(
+  semester: semester,
+  alarmMinutes: alarmMinutes,
+);
+
+/// 考试倒计时，按距开考时间排序，并标出时间重叠或同一天的考试
+Future<List<ExamCountdown>> apiExamCountdown({required String semester}) =>
+    RustLib.instance.api.crateApiSimpleApiExamCountdown(semester: semester);
+
 Future<ElectiveResponse> apiElective({required String semester}) =>
     RustLib.instance.api.crateApiSimpleApiElective(semester: semester);
 
 Future<ExecutionPlanResponse> apiPlan() =>
     RustLib.instance.api.crateApiSimpleApiPlan();
 
+/// 对照培养方案与全部成绩，统计各课程的完成情况与已获学分。
+/// `current_semester` 为当前学期，该学期尚未出成绩的课程记为修读中
+Future<DegreeAudit> apiDegreeAudit({required String currentSemester}) =>
+    RustLib.instance.api.crateApiSimpleApiDegreeAudit(
+      currentSemester: currentSemester,
+    );
+
 Future<DEKT> apiDekt() => RustLib.instance.api.crateApiSimpleApiDekt();
 
+/// 检查毕业学分要求，同时获取学分要求表、成绩单与第二课堂学分
+Future<GraduationReport> apiGraduationCheck() =>
+    RustLib.instance.api.crateApiSimpleApiGraduationCheck();
+
+/// 空闲教室查询可选的校区与教学楼
+Future<ClassroomFilters> apiClassroomFilters() =>
+    RustLib.instance.api.crateApiSimpleApiClassroomFilters();
+
+/// 查询第 `week` 周星期 `day`（1 为周一）`sections` 各节都空闲的教室。
+/// `campus`、`building` 为 `api_classroom_filters` 中校区、教学楼的 value，为空时不限
+Future<List<FreeClassroom>> apiFreeClassrooms({
+  required String semester,
+  required int week,
+  required int day,
+  required List<int> sections,
+  required String campus,
+  required String building,
+}) => RustLib.instance.api.crateApiSimpleApiFreeClassrooms(
+  semester: semester,
+  week: week,
+  day: day,
+  sections: sections,
+  campus: campus,
+  building: building,
+);
+
+/// 当前学期的选课轮次
+Future<List<SelectionRound>> apiSelectionRounds() =>
+    RustLib.instance.api.crateApiSimpleApiSelectionRounds();
+
+/// 进入选课轮次，之后才能查询可选课程和选课。`round_id` 为 `SelectionRound::id`
+Future<SelectionOutcome> apiEnterSelection({required String roundId}) =>
+    RustLib.instance.api.crateApiSimpleApiEnterSelection(roundId: roundId);
+
+/// 某一类别的可选教学班，`keyword` 按课程名称筛选，为空时返回全部
+Future<List<SelectableCourse>> apiSelectionCourses({
+  required SelectionCategory category,
+  required String keyword,
+}) => RustLib.instance.api.crateApiSimpleApiSelectionCourses(
+  category: category,
+  keyword: keyword,
+);
+
+/// 选课，`course_id` 为 `SelectableCourse::id`
+Future<SelectionOutcome> apiSelectCourse({
+  required SelectionCategory category,
+  required String courseId,
+}) => RustLib.instance.api.crateApiSimpleApiSelectCourse(
+  category: category,
+  courseId: courseId,
+);
+
+/// 退课，`course_id` 为已选教学班的 jx0404id
+Future<SelectionOutcome> apiDropCourse({required String courseId}) =>
+    RustLib.instance.api.crateApiSimpleApiDropCourse(courseId: courseId);
+
+/// 监视已满的教学班，出现空位时自动选课，进度与结果通过 `sink` 推送。
+/// 间隔不小于 3 秒；Dart 端取消订阅后在下一次推送时停止
+Stream<SeatWatchEvent> apiWatchSeats({required SeatWatchConfig config}) =>
+    RustLib.instance.api.crateApiSimpleApiWatchSeats(config: config);
+
 Future<DEKTDetail> apiDektDetail({required String id}) =>
     RustLib.instance.api.crateApiSimpleApiDektDetail(id: id);
 
@@ -5,14 +5,24 @@
 
 import 'api/aescbc.dart';
 import 'api/conwork.dart';
+import 'api/error.dart';
+import 'api/ics.dart';
+import 'api/jwxt/audit.dart';
+import 'api/jwxt/calendar.dart';
+import 'api/jwxt/classroom.dart';
 import 'api/jwxt/course.dart';
 import 'api/jwxt/dekt.dart';
 import 'api/jwxt/elective.dart';
 import 'api/jwxt/exam.dart';
+import 'api/jwxt/gpa.dart';
+import 'api/jwxt/grade.dart';
+import 'api/jwxt/graduation.dart';
 import 'api/jwxt/info.dart';
 import 'api/jwxt/plan.dart';
 import 'api/jwxt/score.dart';
+import 'api/jwxt/selection.dart';
 import 'api/jwxt/semester.dart';
+import 'api/seat_watcher.dart';
 import 'api/session.dart';
 import 'api/simple.dart';
 import 'dart:async';
@@ -78,7 +88,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1761466720;
+  int get rustContentHash => 52187821;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -119,6 +129,8 @@ abstract class RustLibApi extends BaseApi {
     required String captcha,
   });
 
+  Future<HttpSession> crateApiSessionHttpSessionDefault();
+
   Future<Uint8List> crateApiSessionHttpSessionGetCaptcha({
     required HttpSession that,
   });
@@ -127,26 +139,106 @@ abstract class RustLibApi extends BaseApi {
     required HttpSession that,
   });
 
+  Future<bool> crateApiSessionHttpSessionIsAlive({required HttpSession that});
+
+  Future<HttpSession> crateApiSessionHttpSessionLoadCookies({
+    required String path,
+    required Endpoints endpoints,
+  });
+
+  Future<LoginState> crateApiSessionHttpSessionLoginState({
+    required HttpSession that,
+  });
+
   Future<HttpSession> crateApiSessionHttpSessionNew();
 
+  Future<void> crateApiSessionHttpSessionSaveCookies({
+    required HttpSession that,
+    required String path,
+  });
+
+  Future<void> crateApiSessionHttpSessionSetCredentials({
+    required HttpSession that,
+    required String username,
+    required String vpnPassword,
+    required String oaPassword,
+  });
+
+  Future<HttpSession> crateApiSessionHttpSessionWithEndpoints({
+    required Endpoints endpoints,
+  });
+
   Future<String> crateApiAescbcAesCbcEncrypt({required String plainText});
 
+  Future<ClassroomFilters> crateApiSimpleApiClassroomFilters();
+
   Future<List<CourseSchedule>> crateApiSimpleApiCourse({
     required String semester,
   });
 
+  Future<List<CourseSchedule>> crateApiSimpleApiCourseForWeek({
+    required String semester,
+    required int week,
+  });
+
+  Future<CurrentWeek> crateApiSimpleApiCurrentWeek();
+
+  Future<DegreeAudit> crateApiSimpleApiDegreeAudit({
+    required String currentSemester,
+  });
+
   Future<DEKT> crateApiSimpleApiDekt();
 
   Future<DEKTDetail> crateApiSimpleApiDektDetail({required String id});
 
+  Future<SelectionOutcome> crateApiSimpleApiDropCourse({
+    required String courseId,
+  });
+
   Future<ElectiveResponse> crateApiSimpleApiElective({
     required String semester,
   });
 
+  Future<SelectionOutcome> crateApiSimpleApiEnterSelection({
+    required String roundId,
+  });
+
   Future<List<ExamSchedule>> crateApiSimpleApiExam({required String semester});
 
+  Future<List<ExamCountdown>> crateApiSimpleApiExamCountdown({
+    required String semester,
+  });
+
+  Future<String> crateApiSimpleApiExportCourseIcs({
+    required String semester,
+    required String firstMonday,
+    required List<SectionTime> sectionTimes,
+  });
+
+  Future<String> crateApiSimpleApiExportExamIcs({
+    required String semester,
+    required List<int> alarmMinutes,
+  });
+
+  Future<List<FreeClassroom>> crateApiSimpleApiFreeClassrooms({
+    required String semester,
+    required int week,
+    required int day,
+    required List<int> sections,
+    required String campus,
+    required String building,
+  });
+
   Future<Uint8List> crateApiSimpleApiGetCaptcha();
 
+  Future<GpaReport> crateApiSimpleApiGpa({
+    required String semester,
+    required GpaScheme scheme,
+    required GpaFilter filter,
+  });
+
+  Future<GraduationReport> crateApiSimpleApiGraduationCheck();
+
   Future<String> crateApiSimpleApiLogin({
     required String username,
     required String vpnPassword,
@@ -154,22 +246,85 @@ abstract class RustLibApi extends BaseApi {
     required String captcha,
   });
 
+  Future<LoginState> crateApiSimpleApiLoginState();
+
+  Future<void> crateApiSimpleApiLogout();
+
   Future<ExecutionPlanResponse> crateApiSimpleApiPlan();
 
+  Future<bool> crateApiSimpleApiRestoreSession({required String path});
+
+  Future<void> crateApiSimpleApiSaveSession({required String path});
+
+  Future<SchoolCalendar> crateApiSimpleApiSchoolCalendar({
+    required String semester,
+  });
+
   Future<ScoreTotal> crateApiSimpleApiScore({required String semester});
 
+  Future<SelectionOutcome> crateApiSimpleApiSelectCourse({
+    required SelectionCategory category,
+    required String courseId,
+  });
+
+  Future<List<SelectableCourse>> crateApiSimpleApiSelectionCourses({
+    required SelectionCategory category,
+    required String keyword,
+  });
+
+  Future<List<SelectionRound>> crateApiSimpleApiSelectionRounds();
+
   Future<List<SemesterInfo>> crateApiSimpleApiSemester({required bool isAll});
 
+  Future<bool> crateApiSimpleApiSessionAlive();
+
+  Future<void> crateApiSimpleApiSetCredentials({
+    required String username,
+    required String vpnPassword,
+    required String oaPassword,
+  });
+
+  void crateApiSimpleApiSetDektRequirements({
+    required List<DektRequirement> requirements,
+  });
+
+  void crateApiSimpleApiSetEndpoints({
+    required String cas,
+    required String jwxt,
+  });
+
+  void crateApiSimpleApiSetGradeScale({required GradeScale scale});
+
+  void crateApiSimpleApiSetSemesterStart({SemesterStart? start});
+
   Future<StudentInfo> crateApiSimpleApiStudentInfo();
 
+  Future<StudentPhoto> crateApiSimpleApiStudentPhoto();
+
+  Stream<SeatWatchEvent> crateApiSimpleApiWatchSeats({
+    required SeatWatchConfig config,
+  });
+
   Future<String> crateApiConworkEncodeInp({required String input});
 
+  Future<Endpoints> crateApiSessionEndpointsDefault();
+
+  Future<GpaFilter> crateApiJwxtGpaGpaFilterDefault();
+
+  Future<GradeScale> crateApiJwxtGradeGradeScaleDefault();
+
   Future<void> crateApiSimpleInitApp();
 
+  Future<ClassroomFilters> crateApiJwxtClassroomParseClassroomFilters({
+    required String html,
+  });
+
   Future<List<CourseSchedule>> crateApiJwxtCourseParseCourseSchedule({
     required String html,
   });
 
+  Future<double> crateApiJwxtGraduationParseCredit({required String text});
+
   Future<DEKT> crateApiJwxtDektParseDekt({required String html});
 
   Future<DEKTDetail> crateApiJwxtDektParseDektDetail({required String html});
@@ -184,27 +339,61 @@ abstract class RustLibApi extends BaseApi {
     required String html,
   });
 
+  Future<SelectionOutcome> crateApiJwxtSelectionParseEnterResult({
+    required String html,
+  });
+
   Future<List<ExamSchedule>> crateApiJwxtExamParseExam({required String html});
 
-  Future<ExecutionPlanResponse> crateApiJwxtPlanParsePlan({
+  Future<List<FreeClassroom>> crateApiJwxtClassroomParseFreeClassrooms({
     required String html,
+    required List<int> sections,
   });
 
-  Future<List<ScoreInfo>> crateApiJwxtScoreParseScore({required String html});
+  Future<String> crateApiJwxtInfoParsePhotoSrc({required String html});
+
+  Future<ExecutionPlanResponse> crateApiJwxtPlanParsePlan({
+    required String html,
+  });
 
-  Future<ScoreTotal> crateApiJwxtScoreParseScoreAll({required String html});
+  Future<SchoolCalendar> crateApiJwxtCalendarParseSchoolCalendar({
+    required String html,
+  });
 
   Future<ScoreSummary> crateApiJwxtScoreParseScoreSummary({
     required String html,
   });
 
+  Future<List<SelectableCourse>> crateApiJwxtSelectionParseSelectionCourses({
+    required String json,
+    required SelectionCategory category,
+  });
+
+  Future<SelectionOutcome> crateApiJwxtSelectionParseSelectionOutcome({
+    required String text,
+  });
+
+  Future<List<SelectionRound>> crateApiJwxtSelectionParseSelectionRounds({
+    required String html,
+  });
+
   Future<List<SemesterInfo>> crateApiJwxtSemesterParseSemester({
     required String html,
     required bool isAll,
   });
 
+  Future<SemesterStart> crateApiJwxtSemesterParseSemesterStart({
+    required String html,
+  });
+
   Future<StudentInfo> crateApiJwxtInfoParseStudentInfo({required String html});
 
+  Future<Uint8List> crateApiJwxtCourseParseWeeks({required String text});
+
+  Future<(String, int)?> crateApiJwxtSemesterSplitSemester({
+    required String semester,
+  });
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_ArcCookieStoreMutex;
 
@@ -266,7 +455,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
         ),
         constMeta: kCrateApiSessionHttpSessionAccessJwxtConstMeta,
         argValues: [that],
@@ -450,7 +639,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
         ),
         constMeta: kCrateApiSessionHttpSessionCompleteLoginConstMeta,
         argValues: [that, username, vpnPassword, oaPassword, captcha],
@@ -465,6 +654,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "username", "vpnPassword", "oaPassword", "captcha"],
       );
 
+  @override
+  Future<HttpSession> crateApiSessionHttpSessionDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionHttpSessionDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionHttpSessionDefaultConstMeta =>
+      const TaskConstMeta(debugName: "HttpSession_default", argNames: []);
+
   @override
   Future<Uint8List> crateApiSessionHttpSessionGetCaptcha({
     required HttpSession that,
@@ -480,13 +697,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 8,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
         ),
         constMeta: kCrateApiSessionHttpSessionGetCaptchaConstMeta,
         argValues: [that],
@@ -516,13 +733,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 9,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
         ),
         constMeta: kCrateApiSessionHttpSessionGetFlowExecutionKeyConstMeta,
         argValues: [that],
@@ -538,97 +755,113 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<HttpSession> crateApiSessionHttpSessionNew() {
+  Future<bool> crateApiSessionHttpSessionIsAlive({required HttpSession that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 10,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiSessionHttpSessionNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSessionHttpSessionIsAliveConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSessionHttpSessionNewConstMeta =>
-      const TaskConstMeta(debugName: "HttpSession_new", argNames: []);
+  TaskConstMeta get kCrateApiSessionHttpSessionIsAliveConstMeta =>
+      const TaskConstMeta(
+        debugName: "HttpSession_is_alive",
+        argNames: ["that"],
+      );
 
   @override
-  Future<String> crateApiAescbcAesCbcEncrypt({required String plainText}) {
+  Future<HttpSession> crateApiSessionHttpSessionLoadCookies({
+    required String path,
+    required Endpoints endpoints,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(plainText, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_endpoints(endpoints, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 11,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiAescbcAesCbcEncryptConstMeta,
-        argValues: [plainText],
+        constMeta: kCrateApiSessionHttpSessionLoadCookiesConstMeta,
+        argValues: [path, endpoints],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAescbcAesCbcEncryptConstMeta =>
+  TaskConstMeta get kCrateApiSessionHttpSessionLoadCookiesConstMeta =>
       const TaskConstMeta(
-        debugName: "aes_cbc_encrypt",
-        argNames: ["plainText"],
+        debugName: "HttpSession_load_cookies",
+        argNames: ["path", "endpoints"],
       );
 
   @override
-  Future<List<CourseSchedule>> crateApiSimpleApiCourse({
-    required String semester,
+  Future<LoginState> crateApiSessionHttpSessionLoginState({
+    required HttpSession that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(semester, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 12,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_course_schedule,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_login_state,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleApiCourseConstMeta,
-        argValues: [semester],
+        constMeta: kCrateApiSessionHttpSessionLoginStateConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleApiCourseConstMeta =>
-      const TaskConstMeta(debugName: "api_course", argNames: ["semester"]);
+  TaskConstMeta get kCrateApiSessionHttpSessionLoginStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "HttpSession_login_state",
+        argNames: ["that"],
+      );
 
   @override
-  Future<DEKT> crateApiSimpleApiDekt() {
+  Future<HttpSession> crateApiSessionHttpSessionNew() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -636,152 +869,146 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 13,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_dekt,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleApiDektConstMeta,
+        constMeta: kCrateApiSessionHttpSessionNewConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleApiDektConstMeta =>
-      const TaskConstMeta(debugName: "api_dekt", argNames: []);
+  TaskConstMeta get kCrateApiSessionHttpSessionNewConstMeta =>
+      const TaskConstMeta(debugName: "HttpSession_new", argNames: []);
 
   @override
-  Future<DEKTDetail> crateApiSimpleApiDektDetail({required String id}) {
+  Future<void> crateApiSessionHttpSessionSaveCookies({
+    required HttpSession that,
+    required String path,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(id, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+            that,
+            serializer,
+          );
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 14,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_dekt_detail,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiSimpleApiDektDetailConstMeta,
-        argValues: [id],
+        constMeta: kCrateApiSessionHttpSessionSaveCookiesConstMeta,
+        argValues: [that, path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleApiDektDetailConstMeta =>
-      const TaskConstMeta(debugName: "api_dekt_detail", argNames: ["id"]);
+  TaskConstMeta get kCrateApiSessionHttpSessionSaveCookiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "HttpSession_save_cookies",
+        argNames: ["that", "path"],
+      );
 
   @override
-  Future<ElectiveResponse> crateApiSimpleApiElective({
-    required String semester,
+  Future<void> crateApiSessionHttpSessionSetCredentials({
+    required HttpSession that,
+    required String username,
+    required String vpnPassword,
+    required String oaPassword,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(semester, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+            that,
+            serializer,
+          );
+          sse_encode_String(username, serializer);
+          sse_encode_String(vpnPassword, serializer);
+          sse_encode_String(oaPassword, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 15,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_elective_response,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleApiElectiveConstMeta,
-        argValues: [semester],
+        constMeta: kCrateApiSessionHttpSessionSetCredentialsConstMeta,
+        argValues: [that, username, vpnPassword, oaPassword],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleApiElectiveConstMeta =>
-      const TaskConstMeta(debugName: "api_elective", argNames: ["semester"]);
+  TaskConstMeta get kCrateApiSessionHttpSessionSetCredentialsConstMeta =>
+      const TaskConstMeta(
+        debugName: "HttpSession_set_credentials",
+        argNames: ["that", "username", "vpnPassword", "oaPassword"],
+      );
 
   @override
-  Future<List<ExamSchedule>> crateApiSimpleApiExam({required String semester}) {
+  Future<HttpSession> crateApiSessionHttpSessionWithEndpoints({
+    required Endpoints endpoints,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(semester, serializer);
+          sse_encode_box_autoadd_endpoints(endpoints, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 16,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_exam_schedule,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleApiExamConstMeta,
-        argValues: [semester],
+        constMeta: kCrateApiSessionHttpSessionWithEndpointsConstMeta,
+        argValues: [endpoints],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleApiExamConstMeta =>
-      const TaskConstMeta(debugName: "api_exam", argNames: ["semester"]);
-
-  @override
-  Future<Uint8List> crateApiSimpleApiGetCaptcha() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 16,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSimpleApiGetCaptchaConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleApiGetCaptchaConstMeta =>
-      const TaskConstMeta(debugName: "api_get_captcha", argNames: []);
+  TaskConstMeta get kCrateApiSessionHttpSessionWithEndpointsConstMeta =>
+      const TaskConstMeta(
+        debugName: "HttpSession_with_endpoints",
+        argNames: ["endpoints"],
+      );
 
   @override
-  Future<String> crateApiSimpleApiLogin({
-    required String username,
-    required String vpnPassword,
-    required String oaPassword,
-    required String captcha,
-  }) {
+  Future<String> crateApiAescbcAesCbcEncrypt({required String plainText}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(username, serializer);
-          sse_encode_String(vpnPassword, serializer);
-          sse_encode_String(oaPassword, serializer);
-          sse_encode_String(captcha, serializer);
+          sse_encode_String(plainText, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -791,22 +1018,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError,
         ),
-        constMeta: kCrateApiSimpleApiLoginConstMeta,
-        argValues: [username, vpnPassword, oaPassword, captcha],
+        constMeta: kCrateApiAescbcAesCbcEncryptConstMeta,
+        argValues: [plainText],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleApiLoginConstMeta => const TaskConstMeta(
-    debugName: "api_login",
-    argNames: ["username", "vpnPassword", "oaPassword", "captcha"],
-  );
+  TaskConstMeta get kCrateApiAescbcAesCbcEncryptConstMeta =>
+      const TaskConstMeta(
+        debugName: "aes_cbc_encrypt",
+        argNames: ["plainText"],
+      );
 
   @override
-  Future<ExecutionPlanResponse> crateApiSimpleApiPlan() {
+  Future<ClassroomFilters> crateApiSimpleApiClassroomFilters() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -819,21 +1048,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_execution_plan_response,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_classroom_filters,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiSimpleApiPlanConstMeta,
+        constMeta: kCrateApiSimpleApiClassroomFiltersConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleApiPlanConstMeta =>
-      const TaskConstMeta(debugName: "api_plan", argNames: []);
+  TaskConstMeta get kCrateApiSimpleApiClassroomFiltersConstMeta =>
+      const TaskConstMeta(debugName: "api_classroom_filters", argNames: []);
 
   @override
-  Future<ScoreTotal> crateApiSimpleApiScore({required String semester}) {
+  Future<List<CourseSchedule>> crateApiSimpleApiCourse({
+    required String semester,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -847,26 +1078,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_score_total,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_course_schedule,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiSimpleApiScoreConstMeta,
+        constMeta: kCrateApiSimpleApiCourseConstMeta,
         argValues: [semester],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleApiScoreConstMeta =>
-      const TaskConstMeta(debugName: "api_score", argNames: ["semester"]);
+  TaskConstMeta get kCrateApiSimpleApiCourseConstMeta =>
+      const TaskConstMeta(debugName: "api_course", argNames: ["semester"]);
 
   @override
-  Future<List<SemesterInfo>> crateApiSimpleApiSemester({required bool isAll}) {
+  Future<List<CourseSchedule>> crateApiSimpleApiCourseForWeek({
+    required String semester,
+    required int week,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_bool(isAll, serializer);
+          sse_encode_String(semester, serializer);
+          sse_encode_u_8(week, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -875,21 +1110,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_semester_info,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_course_schedule,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiSimpleApiSemesterConstMeta,
-        argValues: [isAll],
+        constMeta: kCrateApiSimpleApiCourseForWeekConstMeta,
+        argValues: [semester, week],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleApiSemesterConstMeta =>
-      const TaskConstMeta(debugName: "api_semester", argNames: ["isAll"]);
+  TaskConstMeta get kCrateApiSimpleApiCourseForWeekConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_course_for_week",
+        argNames: ["semester", "week"],
+      );
 
   @override
-  Future<StudentInfo> crateApiSimpleApiStudentInfo() {
+  Future<CurrentWeek> crateApiSimpleApiCurrentWeek() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -902,26 +1140,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_student_info,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_current_week,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiSimpleApiStudentInfoConstMeta,
+        constMeta: kCrateApiSimpleApiCurrentWeekConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleApiStudentInfoConstMeta =>
-      const TaskConstMeta(debugName: "api_student_info", argNames: []);
+  TaskConstMeta get kCrateApiSimpleApiCurrentWeekConstMeta =>
+      const TaskConstMeta(debugName: "api_current_week", argNames: []);
 
   @override
-  Future<String> crateApiConworkEncodeInp({required String input}) {
+  Future<DegreeAudit> crateApiSimpleApiDegreeAudit({
+    required String currentSemester,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(input, serializer);
+          sse_encode_String(currentSemester, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -930,21 +1170,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_degree_audit,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiConworkEncodeInpConstMeta,
-        argValues: [input],
+        constMeta: kCrateApiSimpleApiDegreeAuditConstMeta,
+        argValues: [currentSemester],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiConworkEncodeInpConstMeta =>
-      const TaskConstMeta(debugName: "encode_inp", argNames: ["input"]);
+  TaskConstMeta get kCrateApiSimpleApiDegreeAuditConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_degree_audit",
+        argNames: ["currentSemester"],
+      );
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<DEKT> crateApiSimpleApiDekt() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -957,28 +1200,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_dekt,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
+        constMeta: kCrateApiSimpleApiDektConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiSimpleApiDektConstMeta =>
+      const TaskConstMeta(debugName: "api_dekt", argNames: []);
 
   @override
-  Future<List<CourseSchedule>> crateApiJwxtCourseParseCourseSchedule({
-    required String html,
-  }) {
+  Future<DEKTDetail> crateApiSimpleApiDektDetail({required String id}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(id, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -987,29 +1228,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_course_schedule,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_dekt_detail,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtCourseParseCourseScheduleConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiDektDetailConstMeta,
+        argValues: [id],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtCourseParseCourseScheduleConstMeta =>
-      const TaskConstMeta(
-        debugName: "parse_course_schedule",
-        argNames: ["html"],
-      );
+  TaskConstMeta get kCrateApiSimpleApiDektDetailConstMeta =>
+      const TaskConstMeta(debugName: "api_dekt_detail", argNames: ["id"]);
 
   @override
-  Future<DEKT> crateApiJwxtDektParseDekt({required String html}) {
+  Future<SelectionOutcome> crateApiSimpleApiDropCourse({
+    required String courseId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(courseId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1018,26 +1258,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_dekt,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_selection_outcome,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtDektParseDektConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiDropCourseConstMeta,
+        argValues: [courseId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtDektParseDektConstMeta =>
-      const TaskConstMeta(debugName: "parse_dekt", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiDropCourseConstMeta =>
+      const TaskConstMeta(debugName: "api_drop_course", argNames: ["courseId"]);
 
   @override
-  Future<DEKTDetail> crateApiJwxtDektParseDektDetail({required String html}) {
+  Future<ElectiveResponse> crateApiSimpleApiElective({
+    required String semester,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(semester, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1046,26 +1288,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_dekt_detail,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_elective_response,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtDektParseDektDetailConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiElectiveConstMeta,
+        argValues: [semester],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtDektParseDektDetailConstMeta =>
-      const TaskConstMeta(debugName: "parse_dekt_detail", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiElectiveConstMeta =>
+      const TaskConstMeta(debugName: "api_elective", argNames: ["semester"]);
 
   @override
-  Future<List<DEKTList>> crateApiJwxtDektParseDektList({required String html}) {
+  Future<SelectionOutcome> crateApiSimpleApiEnterSelection({
+    required String roundId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(roundId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1074,28 +1318,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_dekt_list,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_selection_outcome,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtDektParseDektListConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiEnterSelectionConstMeta,
+        argValues: [roundId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtDektParseDektListConstMeta =>
-      const TaskConstMeta(debugName: "parse_dekt_list", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiEnterSelectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_enter_selection",
+        argNames: ["roundId"],
+      );
 
   @override
-  Future<List<DEKTTotal>> crateApiJwxtDektParseDektTotal({
-    required String html,
-  }) {
+  Future<List<ExamSchedule>> crateApiSimpleApiExam({required String semester}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(semester, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1104,28 +1349,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_dekt_total,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_exam_schedule,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtDektParseDektTotalConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiExamConstMeta,
+        argValues: [semester],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtDektParseDektTotalConstMeta =>
-      const TaskConstMeta(debugName: "parse_dekt_total", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiExamConstMeta =>
+      const TaskConstMeta(debugName: "api_exam", argNames: ["semester"]);
 
   @override
-  Future<ElectiveResponse> crateApiJwxtElectiveParseElective({
-    required String html,
+  Future<List<ExamCountdown>> crateApiSimpleApiExamCountdown({
+    required String semester,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(semester, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1134,26 +1379,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_elective_response,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_exam_countdown,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtElectiveParseElectiveConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiExamCountdownConstMeta,
+        argValues: [semester],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtElectiveParseElectiveConstMeta =>
-      const TaskConstMeta(debugName: "parse_elective", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiExamCountdownConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_exam_countdown",
+        argNames: ["semester"],
+      );
 
   @override
-  Future<List<ExamSchedule>> crateApiJwxtExamParseExam({required String html}) {
+  Future<String> crateApiSimpleApiExportCourseIcs({
+    required String semester,
+    required String firstMonday,
+    required List<SectionTime> sectionTimes,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(semester, serializer);
+          sse_encode_String(firstMonday, serializer);
+          sse_encode_list_section_time(sectionTimes, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1162,28 +1416,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_exam_schedule,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtExamParseExamConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiExportCourseIcsConstMeta,
+        argValues: [semester, firstMonday, sectionTimes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtExamParseExamConstMeta =>
-      const TaskConstMeta(debugName: "parse_exam", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiExportCourseIcsConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_export_course_ics",
+        argNames: ["semester", "firstMonday", "sectionTimes"],
+      );
 
   @override
-  Future<ExecutionPlanResponse> crateApiJwxtPlanParsePlan({
-    required String html,
+  Future<String> crateApiSimpleApiExportExamIcs({
+    required String semester,
+    required List<int> alarmMinutes,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(semester, serializer);
+          sse_encode_list_prim_u_32_loose(alarmMinutes, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1192,26 +1451,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_execution_plan_response,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtPlanParsePlanConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiExportExamIcsConstMeta,
+        argValues: [semester, alarmMinutes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtPlanParsePlanConstMeta =>
-      const TaskConstMeta(debugName: "parse_plan", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiExportExamIcsConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_export_exam_ics",
+        argNames: ["semester", "alarmMinutes"],
+      );
 
   @override
-  Future<List<ScoreInfo>> crateApiJwxtScoreParseScore({required String html}) {
+  Future<List<FreeClassroom>> crateApiSimpleApiFreeClassrooms({
+    required String semester,
+    required int week,
+    required int day,
+    required List<int> sections,
+    required String campus,
+    required String building,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(semester, serializer);
+          sse_encode_u_32(week, serializer);
+          sse_encode_u_32(day, serializer);
+          sse_encode_list_prim_u_32_loose(sections, serializer);
+          sse_encode_String(campus, serializer);
+          sse_encode_String(building, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1220,26 +1494,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_score_info,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_free_classroom,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtScoreParseScoreConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiFreeClassroomsConstMeta,
+        argValues: [semester, week, day, sections, campus, building],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtScoreParseScoreConstMeta =>
-      const TaskConstMeta(debugName: "parse_score", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiFreeClassroomsConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_free_classrooms",
+        argNames: ["semester", "week", "day", "sections", "campus", "building"],
+      );
 
   @override
-  Future<ScoreTotal> crateApiJwxtScoreParseScoreAll({required String html}) {
+  Future<Uint8List> crateApiSimpleApiGetCaptcha() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1248,28 +1524,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_score_total,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtScoreParseScoreAllConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiGetCaptchaConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtScoreParseScoreAllConstMeta =>
-      const TaskConstMeta(debugName: "parse_score_all", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiGetCaptchaConstMeta =>
+      const TaskConstMeta(debugName: "api_get_captcha", argNames: []);
 
   @override
-  Future<ScoreSummary> crateApiJwxtScoreParseScoreSummary({
-    required String html,
+  Future<GpaReport> crateApiSimpleApiGpa({
+    required String semester,
+    required GpaScheme scheme,
+    required GpaFilter filter,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(semester, serializer);
+          sse_encode_gpa_scheme(scheme, serializer);
+          sse_encode_box_autoadd_gpa_filter(filter, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1278,30 +1558,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_score_summary,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_gpa_report,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtScoreParseScoreSummaryConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiGpaConstMeta,
+        argValues: [semester, scheme, filter],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtScoreParseScoreSummaryConstMeta =>
-      const TaskConstMeta(debugName: "parse_score_summary", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiGpaConstMeta => const TaskConstMeta(
+    debugName: "api_gpa",
+    argNames: ["semester", "scheme", "filter"],
+  );
 
   @override
-  Future<List<SemesterInfo>> crateApiJwxtSemesterParseSemester({
-    required String html,
-    required bool isAll,
-  }) {
+  Future<GraduationReport> crateApiSimpleApiGraduationCheck() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
-          sse_encode_bool(isAll, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1310,29 +1587,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_semester_info,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_graduation_report,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtSemesterParseSemesterConstMeta,
-        argValues: [html, isAll],
+        constMeta: kCrateApiSimpleApiGraduationCheckConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtSemesterParseSemesterConstMeta =>
-      const TaskConstMeta(
-        debugName: "parse_semester",
-        argNames: ["html", "isAll"],
-      );
+  TaskConstMeta get kCrateApiSimpleApiGraduationCheckConstMeta =>
+      const TaskConstMeta(debugName: "api_graduation_check", argNames: []);
 
   @override
-  Future<StudentInfo> crateApiJwxtInfoParseStudentInfo({required String html}) {
+  Future<String> crateApiSimpleApiLogin({
+    required String username,
+    required String vpnPassword,
+    required String oaPassword,
+    required String captcha,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(html, serializer);
+          sse_encode_String(username, serializer);
+          sse_encode_String(vpnPassword, serializer);
+          sse_encode_String(oaPassword, serializer);
+          sse_encode_String(captcha, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1341,1519 +1623,5389 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_student_info,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
         ),
-        constMeta: kCrateApiJwxtInfoParseStudentInfoConstMeta,
-        argValues: [html],
+        constMeta: kCrateApiSimpleApiLoginConstMeta,
+        argValues: [username, vpnPassword, oaPassword, captcha],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiJwxtInfoParseStudentInfoConstMeta =>
-      const TaskConstMeta(debugName: "parse_student_info", argNames: ["html"]);
+  TaskConstMeta get kCrateApiSimpleApiLoginConstMeta => const TaskConstMeta(
+    debugName: "api_login",
+    argNames: ["username", "vpnPassword", "oaPassword", "captcha"],
+  );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_ArcCookieStoreMutex => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex;
+  @override
+  Future<LoginState> crateApiSimpleApiLoginState() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_login_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleApiLoginStateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_ArcCookieStoreMutex => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex;
+  TaskConstMeta get kCrateApiSimpleApiLoginStateConstMeta =>
+      const TaskConstMeta(debugName: "api_login_state", argNames: []);
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Client => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient;
+  @override
+  Future<void> crateApiSimpleApiLogout() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiLogoutConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Client => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient;
+  TaskConstMeta get kCrateApiSimpleApiLogoutConstMeta =>
+      const TaskConstMeta(debugName: "api_logout", argNames: []);
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_CryptoError => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError;
+  @override
+  Future<ExecutionPlanResponse> crateApiSimpleApiPlan() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_execution_plan_response,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiPlanConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_CryptoError => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError;
+  TaskConstMeta get kCrateApiSimpleApiPlanConstMeta =>
+      const TaskConstMeta(debugName: "api_plan", argNames: []);
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_HttpSession => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession;
+  @override
+  Future<bool> crateApiSimpleApiRestoreSession({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiRestoreSessionConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_HttpSession => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession;
+  TaskConstMeta get kCrateApiSimpleApiRestoreSessionConstMeta =>
+      const TaskConstMeta(debugName: "api_restore_session", argNames: ["path"]);
 
-  @protected
-  ArcCookieStoreMutex
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcCookieStoreMutexImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  @override
+  Future<void> crateApiSimpleApiSaveSession({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSaveSessionConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  Client
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  TaskConstMeta get kCrateApiSimpleApiSaveSessionConstMeta =>
+      const TaskConstMeta(debugName: "api_save_session", argNames: ["path"]);
+
+  @override
+  Future<SchoolCalendar> crateApiSimpleApiSchoolCalendar({
+    required String semester,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(semester, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_school_calendar,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSchoolCalendarConstMeta,
+        argValues: [semester],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  CryptoError
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CryptoErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  TaskConstMeta get kCrateApiSimpleApiSchoolCalendarConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_school_calendar",
+        argNames: ["semester"],
+      );
+
+  @override
+  Future<ScoreTotal> crateApiSimpleApiScore({required String semester}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(semester, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_score_total,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiScoreConstMeta,
+        argValues: [semester],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiScoreConstMeta =>
+      const TaskConstMeta(debugName: "api_score", argNames: ["semester"]);
+
+  @override
+  Future<SelectionOutcome> crateApiSimpleApiSelectCourse({
+    required SelectionCategory category,
+    required String courseId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_selection_category(category, serializer);
+          sse_encode_String(courseId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_selection_outcome,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSelectCourseConstMeta,
+        argValues: [category, courseId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiSelectCourseConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_select_course",
+        argNames: ["category", "courseId"],
+      );
+
+  @override
+  Future<List<SelectableCourse>> crateApiSimpleApiSelectionCourses({
+    required SelectionCategory category,
+    required String keyword,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_selection_category(category, serializer);
+          sse_encode_String(keyword, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_selectable_course,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSelectionCoursesConstMeta,
+        argValues: [category, keyword],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiSelectionCoursesConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_selection_courses",
+        argNames: ["category", "keyword"],
+      );
+
+  @override
+  Future<List<SelectionRound>> crateApiSimpleApiSelectionRounds() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_selection_round,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSelectionRoundsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiSelectionRoundsConstMeta =>
+      const TaskConstMeta(debugName: "api_selection_rounds", argNames: []);
+
+  @override
+  Future<List<SemesterInfo>> crateApiSimpleApiSemester({required bool isAll}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(isAll, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_semester_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSemesterConstMeta,
+        argValues: [isAll],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiSemesterConstMeta =>
+      const TaskConstMeta(debugName: "api_semester", argNames: ["isAll"]);
+
+  @override
+  Future<bool> crateApiSimpleApiSessionAlive() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSessionAliveConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiSessionAliveConstMeta =>
+      const TaskConstMeta(debugName: "api_session_alive", argNames: []);
+
+  @override
+  Future<void> crateApiSimpleApiSetCredentials({
+    required String username,
+    required String vpnPassword,
+    required String oaPassword,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(username, serializer);
+          sse_encode_String(vpnPassword, serializer);
+          sse_encode_String(oaPassword, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSetCredentialsConstMeta,
+        argValues: [username, vpnPassword, oaPassword],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiSetCredentialsConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_set_credentials",
+        argNames: ["username", "vpnPassword", "oaPassword"],
+      );
+
+  @override
+  void crateApiSimpleApiSetDektRequirements({
+    required List<DektRequirement> requirements,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_dekt_requirement(requirements, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSetDektRequirementsConstMeta,
+        argValues: [requirements],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiSetDektRequirementsConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_set_dekt_requirements",
+        argNames: ["requirements"],
+      );
+
+  @override
+  void crateApiSimpleApiSetEndpoints({
+    required String cas,
+    required String jwxt,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cas, serializer);
+          sse_encode_String(jwxt, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSetEndpointsConstMeta,
+        argValues: [cas, jwxt],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiSetEndpointsConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_set_endpoints",
+        argNames: ["cas", "jwxt"],
+      );
+
+  @override
+  void crateApiSimpleApiSetGradeScale({required GradeScale scale}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_grade_scale(scale, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSetGradeScaleConstMeta,
+        argValues: [scale],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiSetGradeScaleConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_set_grade_scale",
+        argNames: ["scale"],
+      );
+
+  @override
+  void crateApiSimpleApiSetSemesterStart({SemesterStart? start}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_semester_start(start, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiSetSemesterStartConstMeta,
+        argValues: [start],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiSetSemesterStartConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_set_semester_start",
+        argNames: ["start"],
+      );
+
+  @override
+  Future<StudentInfo> crateApiSimpleApiStudentInfo() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_student_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiStudentInfoConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiStudentInfoConstMeta =>
+      const TaskConstMeta(debugName: "api_student_info", argNames: []);
+
+  @override
+  Future<StudentPhoto> crateApiSimpleApiStudentPhoto() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_student_photo,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSimpleApiStudentPhotoConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiStudentPhotoConstMeta =>
+      const TaskConstMeta(debugName: "api_student_photo", argNames: []);
+
+  @override
+  Stream<SeatWatchEvent> crateApiSimpleApiWatchSeats({
+    required SeatWatchConfig config,
+  }) {
+    final sink = RustStreamSink<SeatWatchEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_seat_watch_event_Sse(sink, serializer);
+            sse_encode_box_autoadd_seat_watch_config(config, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 56,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiSimpleApiWatchSeatsConstMeta,
+          argValues: [sink, config],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSimpleApiWatchSeatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "api_watch_seats",
+        argNames: ["sink", "config"],
+      );
+
+  @override
+  Future<String> crateApiConworkEncodeInp({required String input}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(input, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiConworkEncodeInpConstMeta,
+        argValues: [input],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiConworkEncodeInpConstMeta =>
+      const TaskConstMeta(debugName: "encode_inp", argNames: ["input"]);
+
+  @override
+  Future<Endpoints> crateApiSessionEndpointsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_endpoints,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionEndpointsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionEndpointsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "endpoints_default", argNames: []);
+
+  @override
+  Future<GpaFilter> crateApiJwxtGpaGpaFilterDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_gpa_filter,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiJwxtGpaGpaFilterDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtGpaGpaFilterDefaultConstMeta =>
+      const TaskConstMeta(debugName: "gpa_filter_default", argNames: []);
+
+  @override
+  Future<GradeScale> crateApiJwxtGradeGradeScaleDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_grade_scale,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiJwxtGradeGradeScaleDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtGradeGradeScaleDefaultConstMeta =>
+      const TaskConstMeta(debugName: "grade_scale_default", argNames: []);
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<ClassroomFilters> crateApiJwxtClassroomParseClassroomFilters({
+    required String html,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_classroom_filters,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtClassroomParseClassroomFiltersConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtClassroomParseClassroomFiltersConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_classroom_filters",
+        argNames: ["html"],
+      );
+
+  @override
+  Future<List<CourseSchedule>> crateApiJwxtCourseParseCourseSchedule({
+    required String html,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_course_schedule,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtCourseParseCourseScheduleConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtCourseParseCourseScheduleConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_course_schedule",
+        argNames: ["html"],
+      );
+
+  @override
+  Future<double> crateApiJwxtGraduationParseCredit({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiJwxtGraduationParseCreditConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtGraduationParseCreditConstMeta =>
+      const TaskConstMeta(debugName: "parse_credit", argNames: ["text"]);
+
+  @override
+  Future<DEKT> crateApiJwxtDektParseDekt({required String html}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dekt,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtDektParseDektConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtDektParseDektConstMeta =>
+      const TaskConstMeta(debugName: "parse_dekt", argNames: ["html"]);
+
+  @override
+  Future<DEKTDetail> crateApiJwxtDektParseDektDetail({required String html}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dekt_detail,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtDektParseDektDetailConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtDektParseDektDetailConstMeta =>
+      const TaskConstMeta(debugName: "parse_dekt_detail", argNames: ["html"]);
+
+  @override
+  Future<List<DEKTList>> crateApiJwxtDektParseDektList({required String html}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_dekt_list,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtDektParseDektListConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtDektParseDektListConstMeta =>
+      const TaskConstMeta(debugName: "parse_dekt_list", argNames: ["html"]);
+
+  @override
+  Future<List<DEKTTotal>> crateApiJwxtDektParseDektTotal({
+    required String html,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_dekt_total,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtDektParseDektTotalConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtDektParseDektTotalConstMeta =>
+      const TaskConstMeta(debugName: "parse_dekt_total", argNames: ["html"]);
+
+  @override
+  Future<ElectiveResponse> crateApiJwxtElectiveParseElective({
+    required String html,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_elective_response,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtElectiveParseElectiveConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtElectiveParseElectiveConstMeta =>
+      const TaskConstMeta(debugName: "parse_elective", argNames: ["html"]);
+
+  @override
+  Future<SelectionOutcome> crateApiJwxtSelectionParseEnterResult({
+    required String html,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_selection_outcome,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiJwxtSelectionParseEnterResultConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtSelectionParseEnterResultConstMeta =>
+      const TaskConstMeta(debugName: "parse_enter_result", argNames: ["html"]);
+
+  @override
+  Future<List<ExamSchedule>> crateApiJwxtExamParseExam({required String html}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_exam_schedule,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtExamParseExamConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtExamParseExamConstMeta =>
+      const TaskConstMeta(debugName: "parse_exam", argNames: ["html"]);
+
+  @override
+  Future<List<FreeClassroom>> crateApiJwxtClassroomParseFreeClassrooms({
+    required String html,
+    required List<int> sections,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          sse_encode_list_prim_u_32_loose(sections, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_free_classroom,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtClassroomParseFreeClassroomsConstMeta,
+        argValues: [html, sections],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtClassroomParseFreeClassroomsConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_free_classrooms",
+        argNames: ["html", "sections"],
+      );
+
+  @override
+  Future<String> crateApiJwxtInfoParsePhotoSrc({required String html}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtInfoParsePhotoSrcConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtInfoParsePhotoSrcConstMeta =>
+      const TaskConstMeta(debugName: "parse_photo_src", argNames: ["html"]);
+
+  @override
+  Future<ExecutionPlanResponse> crateApiJwxtPlanParsePlan({
+    required String html,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_execution_plan_response,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtPlanParsePlanConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtPlanParsePlanConstMeta =>
+      const TaskConstMeta(debugName: "parse_plan", argNames: ["html"]);
+
+  @override
+  Future<SchoolCalendar> crateApiJwxtCalendarParseSchoolCalendar({
+    required String html,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_school_calendar,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtCalendarParseSchoolCalendarConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtCalendarParseSchoolCalendarConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_school_calendar",
+        argNames: ["html"],
+      );
+
+  @override
+  Future<ScoreSummary> crateApiJwxtScoreParseScoreSummary({
+    required String html,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_score_summary,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtScoreParseScoreSummaryConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtScoreParseScoreSummaryConstMeta =>
+      const TaskConstMeta(debugName: "parse_score_summary", argNames: ["html"]);
+
+  @override
+  Future<List<SelectableCourse>> crateApiJwxtSelectionParseSelectionCourses({
+    required String json,
+    required SelectionCategory category,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(json, serializer);
+          sse_encode_selection_category(category, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_selectable_course,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtSelectionParseSelectionCoursesConstMeta,
+        argValues: [json, category],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtSelectionParseSelectionCoursesConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_selection_courses",
+        argNames: ["json", "category"],
+      );
+
+  @override
+  Future<SelectionOutcome> crateApiJwxtSelectionParseSelectionOutcome({
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_selection_outcome,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtSelectionParseSelectionOutcomeConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtSelectionParseSelectionOutcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_selection_outcome",
+        argNames: ["text"],
+      );
+
+  @override
+  Future<List<SelectionRound>> crateApiJwxtSelectionParseSelectionRounds({
+    required String html,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_selection_round,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtSelectionParseSelectionRoundsConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtSelectionParseSelectionRoundsConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_selection_rounds",
+        argNames: ["html"],
+      );
+
+  @override
+  Future<List<SemesterInfo>> crateApiJwxtSemesterParseSemester({
+    required String html,
+    required bool isAll,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          sse_encode_bool(isAll, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_semester_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtSemesterParseSemesterConstMeta,
+        argValues: [html, isAll],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtSemesterParseSemesterConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_semester",
+        argNames: ["html", "isAll"],
+      );
+
+  @override
+  Future<SemesterStart> crateApiJwxtSemesterParseSemesterStart({
+    required String html,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_semester_start,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtSemesterParseSemesterStartConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtSemesterParseSemesterStartConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_semester_start",
+        argNames: ["html"],
+      );
+
+  @override
+  Future<StudentInfo> crateApiJwxtInfoParseStudentInfo({required String html}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_student_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiJwxtInfoParseStudentInfoConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtInfoParseStudentInfoConstMeta =>
+      const TaskConstMeta(debugName: "parse_student_info", argNames: ["html"]);
+
+  @override
+  Future<Uint8List> crateApiJwxtCourseParseWeeks({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiJwxtCourseParseWeeksConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtCourseParseWeeksConstMeta =>
+      const TaskConstMeta(debugName: "parse_weeks", argNames: ["text"]);
+
+  @override
+  Future<(String, int)?> crateApiJwxtSemesterSplitSemester({
+    required String semester,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(semester, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_record_string_u_8,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiJwxtSemesterSplitSemesterConstMeta,
+        argValues: [semester],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJwxtSemesterSplitSemesterConstMeta =>
+      const TaskConstMeta(debugName: "split_semester", argNames: ["semester"]);
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ArcCookieStoreMutex => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ArcCookieStoreMutex => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Client => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Client => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_CryptoError => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_CryptoError => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_HttpSession => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_HttpSession => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  ArcCookieStoreMutex
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ArcCookieStoreMutexImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Client
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  CryptoError
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CryptoErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  HttpSession
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HttpSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  HttpSession
+  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HttpSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  HttpSession
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HttpSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  ArcCookieStoreMutex
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ArcCookieStoreMutexImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Client
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  CryptoError
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CryptoErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  HttpSession
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HttpSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<SeatWatchEvent> dco_decode_StreamSink_seat_watch_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  ApiError dco_decode_api_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ApiError_Network(cause: dco_decode_String(raw[1]));
+      case 1:
+        return ApiError_SessionExpired();
+      case 2:
+        return ApiError_BadCredentials(message: dco_decode_String(raw[1]));
+      case 3:
+        return ApiError_BadCaptcha();
+      case 4:
+        return ApiError_ParseLayoutChanged(
+          page: dco_decode_String(raw[1]),
+          selector: dco_decode_String(raw[2]),
+        );
+      case 5:
+        return ApiError_NotInitialized();
+      case 6:
+        return ApiError_NotLoggedIn();
+      case 7:
+        return ApiError_InvalidArgument(message: dco_decode_String(raw[1]));
+      case 8:
+        return ApiError_Io(cause: dco_decode_String(raw[1]));
+      case 9:
+        return ApiError_Internal(cause: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  AuditCourse dco_decode_audit_course(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return AuditCourse(
+      courseCode: dco_decode_String(arr[0]),
+      courseName: dco_decode_String(arr[1]),
+      semester: dco_decode_String(arr[2]),
+      courseType: dco_decode_String(arr[3]),
+      credits: dco_decode_f_32(arr[4]),
+      status: dco_decode_course_status(arr[5]),
+      bestScore: dco_decode_opt_box_autoadd_grade(arr[6]),
+      attempts: dco_decode_u_32(arr[7]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  Endpoints dco_decode_box_autoadd_endpoints(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_endpoints(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  GpaFilter dco_decode_box_autoadd_gpa_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_gpa_filter(raw);
+  }
+
+  @protected
+  Grade dco_decode_box_autoadd_grade(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_grade(raw);
+  }
+
+  @protected
+  GradeScale dco_decode_box_autoadd_grade_scale(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_grade_scale(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  (String, int) dco_decode_box_autoadd_record_string_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as (String, int);
+  }
+
+  @protected
+  SeatWatchConfig dco_decode_box_autoadd_seat_watch_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_seat_watch_config(raw);
+  }
+
+  @protected
+  SelectableCourse dco_decode_box_autoadd_selectable_course(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_selectable_course(raw);
+  }
+
+  @protected
+  SelectionOutcome dco_decode_box_autoadd_selection_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_selection_outcome(raw);
+  }
+
+  @protected
+  SemesterStart dco_decode_box_autoadd_semester_start(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_semester_start(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  CalendarDay dco_decode_calendar_day(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CalendarDay(
+      date: dco_decode_String(arr[0]),
+      weekday: dco_decode_u_32(arr[1]),
+      kind: dco_decode_calendar_day_kind(arr[2]),
+      note: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  CalendarDayKind dco_decode_calendar_day_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CalendarDayKind.values[raw as int];
+  }
+
+  @protected
+  ClassroomFilters dco_decode_classroom_filters(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ClassroomFilters(
+      campuses: dco_decode_list_filter_option(arr[0]),
+      buildings: dco_decode_list_filter_option(arr[1]),
+    );
+  }
+
+  @protected
+  CourseSchedule dco_decode_course_schedule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return CourseSchedule(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      teacher: dco_decode_String(arr[2]),
+      timeRange: dco_decode_String(arr[3]),
+      dayOfWeek: dco_decode_String(arr[4]),
+      section: dco_decode_usize(arr[5]),
+      weeks: dco_decode_String(arr[6]),
+      weekList: dco_decode_list_prim_u_8_strict(arr[7]),
+      classroom: dco_decode_String(arr[8]),
+      duration: dco_decode_String(arr[9]),
+    );
+  }
+
+  @protected
+  CourseStatus dco_decode_course_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CourseStatus.values[raw as int];
+  }
+
+  @protected
+  CreditBucket dco_decode_credit_bucket(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CreditBucket(
+      source: dco_decode_credit_source(arr[0]),
+      name: dco_decode_String(arr[1]),
+      earned: dco_decode_f_32(arr[2]),
+      required_: dco_decode_f_32(arr[3]),
+      missing: dco_decode_f_32(arr[4]),
+      passed: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  CreditInfo dco_decode_credit_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CreditInfo(
+      category: dco_decode_String(arr[0]),
+      required_: dco_decode_f_32(arr[1]),
+      limited: dco_decode_f_32(arr[2]),
+      elective: dco_decode_f_32(arr[3]),
+      public: dco_decode_f_32(arr[4]),
+      total: dco_decode_f_32(arr[5]),
+    );
+  }
+
+  @protected
+  CreditProgress dco_decode_credit_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CreditProgress(
+      key: dco_decode_String(arr[0]),
+      earned: dco_decode_f_32(arr[1]),
+      required_: dco_decode_f_32(arr[2]),
+      passedCount: dco_decode_u_32(arr[3]),
+      courseCount: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  CreditSource dco_decode_credit_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CreditSource.values[raw as int];
+  }
+
+  @protected
+  CurrentWeek dco_decode_current_week(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CurrentWeek(
+      semester: dco_decode_String(arr[0]),
+      startDate: dco_decode_String(arr[1]),
+      week: dco_decode_i_32(arr[2]),
+      weekday: dco_decode_u_32(arr[3]),
+      isOverridden: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  DegreeAudit dco_decode_degree_audit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return DegreeAudit(
+      courses: dco_decode_list_audit_course(arr[0]),
+      byCourseType: dco_decode_list_credit_progress(arr[1]),
+      bySemester: dco_decode_list_credit_progress(arr[2]),
+      earned: dco_decode_f_32(arr[3]),
+      required_: dco_decode_f_32(arr[4]),
+      outsidePlan: dco_decode_list_score_info(arr[5]),
+      outsidePlanEarned: dco_decode_f_32(arr[6]),
+    );
+  }
+
+  @protected
+  DEKT dco_decode_dekt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DEKT(
+      list: dco_decode_list_dekt_list(arr[0]),
+      total: dco_decode_list_dekt_total(arr[1]),
+    );
+  }
+
+  @protected
+  DEKTDetail dco_decode_dekt_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return DEKTDetail(field0: dco_decode_Map_String_String_None(arr[0]));
+  }
+
+  @protected
+  DEKTList dco_decode_dekt_list(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return DEKTList(
+      id: dco_decode_String(arr[0]),
+      semester: dco_decode_String(arr[1]),
+      category: dco_decode_String(arr[2]),
+      subCategory: dco_decode_String(arr[3]),
+      activityName: dco_decode_String(arr[4]),
+      credit: dco_decode_String(arr[5]),
+      operationId: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  DektRequirement dco_decode_dekt_requirement(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DektRequirement(
+      category: dco_decode_String(arr[0]),
+      credits: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  DEKTTotal dco_decode_dekt_total(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DEKTTotal(
+      category: dco_decode_String(arr[0]),
+      totalCredit: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ElectiveResponse dco_decode_elective_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ElectiveResponse(
+      credits: dco_decode_list_credit_info(arr[0]),
+      courses: dco_decode_list_xqxkch_info(arr[1]),
+    );
+  }
+
+  @protected
+  Endpoints dco_decode_endpoints(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Endpoints(
+      cas: dco_decode_String(arr[0]),
+      jwxt: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ExamCountdown dco_decode_exam_countdown(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ExamCountdown(
+      exam: dco_decode_exam_schedule(arr[0]),
+      remainingSeconds: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      isFinished: dco_decode_bool(arr[2]),
+      overlapsWith: dco_decode_list_prim_u_32_strict(arr[3]),
+      sameDayWith: dco_decode_list_prim_u_32_strict(arr[4]),
+    );
+  }
+
+  @protected
+  ExamSchedule dco_decode_exam_schedule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return ExamSchedule(
+      id: dco_decode_u_32(arr[0]),
+      campus: dco_decode_String(arr[1]),
+      courseCode: dco_decode_String(arr[2]),
+      courseName: dco_decode_String(arr[3]),
+      examTime: dco_decode_String(arr[4]),
+      startTime: dco_decode_opt_String(arr[5]),
+      endTime: dco_decode_opt_String(arr[6]),
+      examLocation: dco_decode_String(arr[7]),
+      seatNumber: dco_decode_String(arr[8]),
+      examMethod: dco_decode_String(arr[9]),
+      remark: dco_decode_String(arr[10]),
+    );
+  }
+
+  @protected
+  ExecutionPlan dco_decode_execution_plan(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return ExecutionPlan(
+      id: dco_decode_u_32(arr[0]),
+      semester: dco_decode_String(arr[1]),
+      academicYear: dco_decode_String(arr[2]),
+      term: dco_decode_u_8(arr[3]),
+      courseCode: dco_decode_String(arr[4]),
+      courseName: dco_decode_String(arr[5]),
+      department: dco_decode_String(arr[6]),
+      credits: dco_decode_f_32(arr[7]),
+      totalHours: dco_decode_f_32(arr[8]),
+      assessmentMethod: dco_decode_String(arr[9]),
+      courseType: dco_decode_String(arr[10]),
+      isExam: dco_decode_String(arr[11]),
+    );
+  }
+
+  @protected
+  ExecutionPlanResponse dco_decode_execution_plan_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ExecutionPlanResponse(
+      plans: dco_decode_list_execution_plan(arr[0]),
+      semesters: dco_decode_list_String(arr[1]),
+      groups: dco_decode_list_plan_semester(arr[2]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FilterOption dco_decode_filter_option(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FilterOption(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  FreeClassroom dco_decode_free_classroom(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return FreeClassroom(
+      name: dco_decode_String(arr[0]),
+      campus: dco_decode_String(arr[1]),
+      building: dco_decode_String(arr[2]),
+      capacity: dco_decode_u_32(arr[3]),
+      roomType: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  GpaFilter dco_decode_gpa_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GpaFilter(
+      courseNatures: dco_decode_list_String(arr[0]),
+      courseAttrs: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  GpaReport dco_decode_gpa_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GpaReport(
+      scheme: dco_decode_gpa_scheme(arr[0]),
+      overall: dco_decode_gpa_result(arr[1]),
+      semesters: dco_decode_list_semester_gpa(arr[2]),
+    );
+  }
+
+  @protected
+  GpaResult dco_decode_gpa_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return GpaResult(
+      gpa: dco_decode_opt_box_autoadd_f_32(arr[0]),
+      averageScore: dco_decode_opt_box_autoadd_f_32(arr[1]),
+      credits: dco_decode_f_32(arr[2]),
+      courseCount: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  GpaScheme dco_decode_gpa_scheme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GpaScheme.values[raw as int];
+  }
+
+  @protected
+  Grade dco_decode_grade(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return Grade(
+      raw: dco_decode_String(arr[0]),
+      value: dco_decode_opt_box_autoadd_f_32(arr[1]),
+      isAbsent: dco_decode_bool(arr[2]),
+      isExempt: dco_decode_bool(arr[3]),
+      isRetake: dco_decode_bool(arr[4]),
+      isMakeup: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  GradeScale dco_decode_grade_scale(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return GradeScale(
+      excellent: dco_decode_f_32(arr[0]),
+      good: dco_decode_f_32(arr[1]),
+      medium: dco_decode_f_32(arr[2]),
+      pass: dco_decode_f_32(arr[3]),
+      fail: dco_decode_f_32(arr[4]),
+      qualified: dco_decode_f_32(arr[5]),
+      unqualified: dco_decode_f_32(arr[6]),
+    );
+  }
+
+  @protected
+  GraduationReport dco_decode_graduation_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GraduationReport(
+      buckets: dco_decode_list_credit_bucket(arr[0]),
+      passed: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AuditCourse> dco_decode_list_audit_course(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_audit_course).toList();
+  }
+
+  @protected
+  List<CalendarDay> dco_decode_list_calendar_day(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_calendar_day).toList();
+  }
+
+  @protected
+  List<CourseSchedule> dco_decode_list_course_schedule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_course_schedule).toList();
+  }
+
+  @protected
+  List<CreditBucket> dco_decode_list_credit_bucket(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_credit_bucket).toList();
+  }
+
+  @protected
+  List<CreditInfo> dco_decode_list_credit_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_credit_info).toList();
+  }
+
+  @protected
+  List<CreditProgress> dco_decode_list_credit_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_credit_progress).toList();
+  }
+
+  @protected
+  List<DEKTList> dco_decode_list_dekt_list(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_dekt_list).toList();
+  }
+
+  @protected
+  List<DektRequirement> dco_decode_list_dekt_requirement(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_dekt_requirement).toList();
+  }
+
+  @protected
+  List<DEKTTotal> dco_decode_list_dekt_total(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_dekt_total).toList();
+  }
+
+  @protected
+  List<ExamCountdown> dco_decode_list_exam_countdown(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_exam_countdown).toList();
+  }
+
+  @protected
+  List<ExamSchedule> dco_decode_list_exam_schedule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_exam_schedule).toList();
+  }
+
+  @protected
+  List<ExecutionPlan> dco_decode_list_execution_plan(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_execution_plan).toList();
+  }
+
+  @protected
+  List<FilterOption> dco_decode_list_filter_option(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_filter_option).toList();
+  }
+
+  @protected
+  List<FreeClassroom> dco_decode_list_free_classroom(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_free_classroom).toList();
+  }
+
+  @protected
+  List<PlanSemester> dco_decode_list_plan_semester(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_plan_semester).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<SchoolWeek> dco_decode_list_school_week(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_school_week).toList();
+  }
+
+  @protected
+  List<ScoreInfo> dco_decode_list_score_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_score_info).toList();
+  }
+
+  @protected
+  List<SectionTime> dco_decode_list_section_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_section_time).toList();
+  }
+
+  @protected
+  List<SelectableCourse> dco_decode_list_selectable_course(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_selectable_course).toList();
+  }
+
+  @protected
+  List<SelectionRound> dco_decode_list_selection_round(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_selection_round).toList();
+  }
+
+  @protected
+  List<SemesterGpa> dco_decode_list_semester_gpa(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_semester_gpa).toList();
+  }
+
+  @protected
+  List<SemesterInfo> dco_decode_list_semester_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_semester_info).toList();
+  }
+
+  @protected
+  List<XqxkchInfo> dco_decode_list_xqxkch_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_xqxkch_info).toList();
+  }
+
+  @protected
+  LoginState dco_decode_login_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LoginState.values[raw as int];
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  Grade? dco_decode_opt_box_autoadd_grade(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_grade(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  (String, int)? dco_decode_opt_box_autoadd_record_string_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_record_string_u_8(raw);
+  }
+
+  @protected
+  SemesterStart? dco_decode_opt_box_autoadd_semester_start(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_semester_start(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  PlanSemester dco_decode_plan_semester(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PlanSemester(
+      semester: dco_decode_String(arr[0]),
+      academicYear: dco_decode_String(arr[1]),
+      term: dco_decode_u_8(arr[2]),
+      credits: dco_decode_f_32(arr[3]),
+      totalHours: dco_decode_f_32(arr[4]),
+      plans: dco_decode_list_execution_plan(arr[5]),
+    );
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  (String, int) dco_decode_record_string_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_u_8(arr[1]));
+  }
+
+  @protected
+  SchoolCalendar dco_decode_school_calendar(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SchoolCalendar(
+      semester: dco_decode_String(arr[0]),
+      startDate: dco_decode_String(arr[1]),
+      weeks: dco_decode_list_school_week(arr[2]),
+    );
+  }
+
+  @protected
+  SchoolWeek dco_decode_school_week(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SchoolWeek(
+      week: dco_decode_u_32(arr[0]),
+      monday: dco_decode_String(arr[1]),
+      holidays: dco_decode_list_calendar_day(arr[2]),
+      swapDays: dco_decode_list_calendar_day(arr[3]),
+    );
+  }
+
+  @protected
+  ScoreInfo dco_decode_score_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return ScoreInfo(
+      id: dco_decode_u_32(arr[0]),
+      term: dco_decode_String(arr[1]),
+      courseId: dco_decode_String(arr[2]),
+      courseName: dco_decode_String(arr[3]),
+      score: dco_decode_grade(arr[4]),
+      credit: dco_decode_f_32(arr[5]),
+      hours: dco_decode_f_32(arr[6]),
+      gpa: dco_decode_f_32(arr[7]),
+      examType: dco_decode_String(arr[8]),
+      courseAttr: dco_decode_String(arr[9]),
+      courseNature: dco_decode_String(arr[10]),
+    );
+  }
+
+  @protected
+  ScoreSummary dco_decode_score_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ScoreSummary(
+      creditTotal: dco_decode_f_32(arr[0]),
+      gpaAverage: dco_decode_f_32(arr[1]),
+      averageScore: dco_decode_opt_box_autoadd_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  ScoreTotal dco_decode_score_total(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ScoreTotal(
+      info: dco_decode_list_score_info(arr[0]),
+      summary: dco_decode_score_summary(arr[1]),
+    );
+  }
+
+  @protected
+  SeatWatchConfig dco_decode_seat_watch_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SeatWatchConfig(
+      category: dco_decode_selection_category(arr[0]),
+      courseIds: dco_decode_list_String(arr[1]),
+      intervalMs: dco_decode_u_32(arr[2]),
+      jitterMs: dco_decode_u_32(arr[3]),
+      maxRounds: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  SeatWatchEvent dco_decode_seat_watch_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SeatWatchEvent_Polled(
+          round: dco_decode_u_32(raw[1]),
+          courses: dco_decode_list_selectable_course(raw[2]),
+        );
+      case 1:
+        return SeatWatchEvent_Attempted(
+          course: dco_decode_box_autoadd_selectable_course(raw[1]),
+          outcome: dco_decode_box_autoadd_selection_outcome(raw[2]),
+        );
+      case 2:
+        return SeatWatchEvent_Error(message: dco_decode_String(raw[1]));
+      case 3:
+        return SeatWatchEvent_Finished(
+          selected: dco_decode_list_String(raw[1]),
+          pending: dco_decode_list_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SectionTime dco_decode_section_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SectionTime(
+      section: dco_decode_usize(arr[0]),
+      start: dco_decode_String(arr[1]),
+      end: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  SelectableCourse dco_decode_selectable_course(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return SelectableCourse(
+      id: dco_decode_String(arr[0]),
+      category: dco_decode_selection_category(arr[1]),
+      courseCode: dco_decode_String(arr[2]),
+      courseName: dco_decode_String(arr[3]),
+      credits: dco_decode_f_32(arr[4]),
+      teacher: dco_decode_String(arr[5]),
+      time: dco_decode_String(arr[6]),
+      location: dco_decode_String(arr[7]),
+      campus: dco_decode_String(arr[8]),
+      capacity: dco_decode_u_32(arr[9]),
+      selected: dco_decode_u_32(arr[10]),
+      remaining: dco_decode_u_32(arr[11]),
+      conflict: dco_decode_String(arr[12]),
+    );
+  }
+
+  @protected
+  SelectionCategory dco_decode_selection_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SelectionCategory.values[raw as int];
+  }
+
+  @protected
+  SelectionOutcome dco_decode_selection_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SelectionOutcome(
+      result: dco_decode_selection_result(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  SelectionResult dco_decode_selection_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SelectionResult.values[raw as int];
+  }
+
+  @protected
+  SelectionRound dco_decode_selection_round(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SelectionRound(
+      id: dco_decode_String(arr[0]),
+      semester: dco_decode_String(arr[1]),
+      name: dco_decode_String(arr[2]),
+      startTime: dco_decode_String(arr[3]),
+      endTime: dco_decode_String(arr[4]),
+      canEnter: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  SemesterGpa dco_decode_semester_gpa(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SemesterGpa(
+      term: dco_decode_String(arr[0]),
+      result: dco_decode_gpa_result(arr[1]),
+    );
+  }
+
+  @protected
+  SemesterInfo dco_decode_semester_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SemesterInfo(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+      academicYear: dco_decode_String(arr[2]),
+      term: dco_decode_u_8(arr[3]),
+      isCurrent: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  SemesterStart dco_decode_semester_start(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SemesterStart(
+      semester: dco_decode_String(arr[0]),
+      startDate: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  StudentInfo dco_decode_student_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17)
+      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return StudentInfo(
+      name: dco_decode_opt_String(arr[0]),
+      gender: dco_decode_opt_String(arr[1]),
+      studentId: dco_decode_opt_String(arr[2]),
+      department: dco_decode_opt_String(arr[3]),
+      major: dco_decode_opt_String(arr[4]),
+      className: dco_decode_opt_String(arr[5]),
+      admissionDate: dco_decode_opt_String(arr[6]),
+      admissionNumber: dco_decode_opt_String(arr[7]),
+      idNumber: dco_decode_opt_String(arr[8]),
+      birthDate: dco_decode_opt_String(arr[9]),
+      ethnicity: dco_decode_opt_String(arr[10]),
+      politicalStatus: dco_decode_opt_String(arr[11]),
+      hometown: dco_decode_opt_String(arr[12]),
+      educationLevel: dco_decode_opt_String(arr[13]),
+      lengthOfSchooling: dco_decode_opt_String(arr[14]),
+      contact: dco_decode_opt_String(arr[15]),
+      highSchool: dco_decode_opt_String(arr[16]),
+    );
+  }
+
+  @protected
+  StudentPhoto dco_decode_student_photo(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return StudentPhoto(
+      bytes: dco_decode_list_prim_u_8_strict(arr[0]),
+      mimeType: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  XqxkchInfo dco_decode_xqxkch_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return XqxkchInfo(
+      courseId: dco_decode_String(arr[0]),
+      courseName: dco_decode_String(arr[1]),
+      department: dco_decode_String(arr[2]),
+      hours: dco_decode_i_32(arr[3]),
+      credits: dco_decode_f_32(arr[4]),
+      courseAttribute: dco_decode_String(arr[5]),
+      selectionType: dco_decode_String(arr[6]),
+      selected: dco_decode_String(arr[7]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  ArcCookieStoreMutex
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ArcCookieStoreMutexImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Client
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  CryptoError
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CryptoErrorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  HttpSession
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return HttpSessionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  HttpSession
+  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return HttpSessionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  HttpSession
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return HttpSessionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  ArcCookieStoreMutex
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ArcCookieStoreMutexImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Client
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  CryptoError
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CryptoErrorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  HttpSession
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return HttpSessionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<SeatWatchEvent> sse_decode_StreamSink_seat_watch_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  ApiError sse_decode_api_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_cause = sse_decode_String(deserializer);
+        return ApiError_Network(cause: var_cause);
+      case 1:
+        return ApiError_SessionExpired();
+      case 2:
+        var var_message = sse_decode_String(deserializer);
+        return ApiError_BadCredentials(message: var_message);
+      case 3:
+        return ApiError_BadCaptcha();
+      case 4:
+        var var_page = sse_decode_String(deserializer);
+        var var_selector = sse_decode_String(deserializer);
+        return ApiError_ParseLayoutChanged(
+          page: var_page,
+          selector: var_selector,
+        );
+      case 5:
+        return ApiError_NotInitialized();
+      case 6:
+        return ApiError_NotLoggedIn();
+      case 7:
+        var var_message = sse_decode_String(deserializer);
+        return ApiError_InvalidArgument(message: var_message);
+      case 8:
+        var var_cause = sse_decode_String(deserializer);
+        return ApiError_Io(cause: var_cause);
+      case 9:
+        var var_cause = sse_decode_String(deserializer);
+        return ApiError_Internal(cause: var_cause);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  AuditCourse sse_decode_audit_course(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_courseCode = sse_decode_String(deserializer);
+    var var_courseName = sse_decode_String(deserializer);
+    var var_semester = sse_decode_String(deserializer);
+    var var_courseType = sse_decode_String(deserializer);
+    var var_credits = sse_decode_f_32(deserializer);
+    var var_status = sse_decode_course_status(deserializer);
+    var var_bestScore = sse_decode_opt_box_autoadd_grade(deserializer);
+    var var_attempts = sse_decode_u_32(deserializer);
+    return AuditCourse(
+      courseCode: var_courseCode,
+      courseName: var_courseName,
+      semester: var_semester,
+      courseType: var_courseType,
+      credits: var_credits,
+      status: var_status,
+      bestScore: var_bestScore,
+      attempts: var_attempts,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  Endpoints sse_decode_box_autoadd_endpoints(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_endpoints(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  GpaFilter sse_decode_box_autoadd_gpa_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_gpa_filter(deserializer));
+  }
+
+  @protected
+  Grade sse_decode_box_autoadd_grade(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_grade(deserializer));
+  }
+
+  @protected
+  GradeScale sse_decode_box_autoadd_grade_scale(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_grade_scale(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  (String, int) sse_decode_box_autoadd_record_string_u_8(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_record_string_u_8(deserializer));
+  }
+
+  @protected
+  SeatWatchConfig sse_decode_box_autoadd_seat_watch_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_seat_watch_config(deserializer));
+  }
+
+  @protected
+  SelectableCourse sse_decode_box_autoadd_selectable_course(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_selectable_course(deserializer));
+  }
+
+  @protected
+  SelectionOutcome sse_decode_box_autoadd_selection_outcome(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_selection_outcome(deserializer));
+  }
+
+  @protected
+  SemesterStart sse_decode_box_autoadd_semester_start(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_semester_start(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  CalendarDay sse_decode_calendar_day(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_date = sse_decode_String(deserializer);
+    var var_weekday = sse_decode_u_32(deserializer);
+    var var_kind = sse_decode_calendar_day_kind(deserializer);
+    var var_note = sse_decode_String(deserializer);
+    return CalendarDay(
+      date: var_date,
+      weekday: var_weekday,
+      kind: var_kind,
+      note: var_note,
+    );
+  }
+
+  @protected
+  CalendarDayKind sse_decode_calendar_day_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CalendarDayKind.values[inner];
+  }
+
+  @protected
+  ClassroomFilters sse_decode_classroom_filters(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_campuses = sse_decode_list_filter_option(deserializer);
+    var var_buildings = sse_decode_list_filter_option(deserializer);
+    return ClassroomFilters(campuses: var_campuses, buildings: var_buildings);
+  }
+
+  @protected
+  CourseSchedule sse_decode_course_schedule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_teacher = sse_decode_String(deserializer);
+    var var_timeRange = sse_decode_String(deserializer);
+    var var_dayOfWeek = sse_decode_String(deserializer);
+    var var_section = sse_decode_usize(deserializer);
+    var var_weeks = sse_decode_String(deserializer);
+    var var_weekList = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_classroom = sse_decode_String(deserializer);
+    var var_duration = sse_decode_String(deserializer);
+    return CourseSchedule(
+      id: var_id,
+      name: var_name,
+      teacher: var_teacher,
+      timeRange: var_timeRange,
+      dayOfWeek: var_dayOfWeek,
+      section: var_section,
+      weeks: var_weeks,
+      weekList: var_weekList,
+      classroom: var_classroom,
+      duration: var_duration,
+    );
+  }
+
+  @protected
+  CourseStatus sse_decode_course_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CourseStatus.values[inner];
+  }
+
+  @protected
+  CreditBucket sse_decode_credit_bucket(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_source = sse_decode_credit_source(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_earned = sse_decode_f_32(deserializer);
+    var var_required_ = sse_decode_f_32(deserializer);
+    var var_missing = sse_decode_f_32(deserializer);
+    var var_passed = sse_decode_bool(deserializer);
+    return CreditBucket(
+      source: var_source,
+      name: var_name,
+      earned: var_earned,
+      required_: var_required_,
+      missing: var_missing,
+      passed: var_passed,
+    );
+  }
+
+  @protected
+  CreditInfo sse_decode_credit_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_category = sse_decode_String(deserializer);
+    var var_required_ = sse_decode_f_32(deserializer);
+    var var_limited = sse_decode_f_32(deserializer);
+    var var_elective = sse_decode_f_32(deserializer);
+    var var_public = sse_decode_f_32(deserializer);
+    var var_total = sse_decode_f_32(deserializer);
+    return CreditInfo(
+      category: var_category,
+      required_: var_required_,
+      limited: var_limited,
+      elective: var_elective,
+      public: var_public,
+      total: var_total,
+    );
+  }
+
+  @protected
+  CreditProgress sse_decode_credit_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_earned = sse_decode_f_32(deserializer);
+    var var_required_ = sse_decode_f_32(deserializer);
+    var var_passedCount = sse_decode_u_32(deserializer);
+    var var_courseCount = sse_decode_u_32(deserializer);
+    return CreditProgress(
+      key: var_key,
+      earned: var_earned,
+      required_: var_required_,
+      passedCount: var_passedCount,
+      courseCount: var_courseCount,
+    );
+  }
+
+  @protected
+  CreditSource sse_decode_credit_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CreditSource.values[inner];
+  }
+
+  @protected
+  CurrentWeek sse_decode_current_week(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_semester = sse_decode_String(deserializer);
+    var var_startDate = sse_decode_String(deserializer);
+    var var_week = sse_decode_i_32(deserializer);
+    var var_weekday = sse_decode_u_32(deserializer);
+    var var_isOverridden = sse_decode_bool(deserializer);
+    return CurrentWeek(
+      semester: var_semester,
+      startDate: var_startDate,
+      week: var_week,
+      weekday: var_weekday,
+      isOverridden: var_isOverridden,
+    );
+  }
+
+  @protected
+  DegreeAudit sse_decode_degree_audit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_courses = sse_decode_list_audit_course(deserializer);
+    var var_byCourseType = sse_decode_list_credit_progress(deserializer);
+    var var_bySemester = sse_decode_list_credit_progress(deserializer);
+    var var_earned = sse_decode_f_32(deserializer);
+    var var_required_ = sse_decode_f_32(deserializer);
+    var var_outsidePlan = sse_decode_list_score_info(deserializer);
+    var var_outsidePlanEarned = sse_decode_f_32(deserializer);
+    return DegreeAudit(
+      courses: var_courses,
+      byCourseType: var_byCourseType,
+      bySemester: var_bySemester,
+      earned: var_earned,
+      required_: var_required_,
+      outsidePlan: var_outsidePlan,
+      outsidePlanEarned: var_outsidePlanEarned,
+    );
+  }
+
+  @protected
+  DEKT sse_decode_dekt(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_list = sse_decode_list_dekt_list(deserializer);
+    var var_total = sse_decode_list_dekt_total(deserializer);
+    return DEKT(list: var_list, total: var_total);
+  }
+
+  @protected
+  DEKTDetail sse_decode_dekt_detail(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_Map_String_String_None(deserializer);
+    return DEKTDetail(field0: var_field0);
+  }
+
+  @protected
+  DEKTList sse_decode_dekt_list(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_semester = sse_decode_String(deserializer);
+    var var_category = sse_decode_String(deserializer);
+    var var_subCategory = sse_decode_String(deserializer);
+    var var_activityName = sse_decode_String(deserializer);
+    var var_credit = sse_decode_String(deserializer);
+    var var_operationId = sse_decode_String(deserializer);
+    return DEKTList(
+      id: var_id,
+      semester: var_semester,
+      category: var_category,
+      subCategory: var_subCategory,
+      activityName: var_activityName,
+      credit: var_credit,
+      operationId: var_operationId,
+    );
+  }
+
+  @protected
+  DektRequirement sse_decode_dekt_requirement(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_category = sse_decode_String(deserializer);
+    var var_credits = sse_decode_f_32(deserializer);
+    return DektRequirement(category: var_category, credits: var_credits);
+  }
+
+  @protected
+  DEKTTotal sse_decode_dekt_total(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_category = sse_decode_String(deserializer);
+    var var_totalCredit = sse_decode_String(deserializer);
+    return DEKTTotal(category: var_category, totalCredit: var_totalCredit);
+  }
+
+  @protected
+  ElectiveResponse sse_decode_elective_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_credits = sse_decode_list_credit_info(deserializer);
+    var var_courses = sse_decode_list_xqxkch_info(deserializer);
+    return ElectiveResponse(credits: var_credits, courses: var_courses);
+  }
+
+  @protected
+  Endpoints sse_decode_endpoints(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cas = sse_decode_String(deserializer);
+    var var_jwxt = sse_decode_String(deserializer);
+    return Endpoints(cas: var_cas, jwxt: var_jwxt);
+  }
+
+  @protected
+  ExamCountdown sse_decode_exam_countdown(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_exam = sse_decode_exam_schedule(deserializer);
+    var var_remainingSeconds = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_isFinished = sse_decode_bool(deserializer);
+    var var_overlapsWith = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_sameDayWith = sse_decode_list_prim_u_32_strict(deserializer);
+    return ExamCountdown(
+      exam: var_exam,
+      remainingSeconds: var_remainingSeconds,
+      isFinished: var_isFinished,
+      overlapsWith: var_overlapsWith,
+      sameDayWith: var_sameDayWith,
+    );
+  }
+
+  @protected
+  ExamSchedule sse_decode_exam_schedule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_campus = sse_decode_String(deserializer);
+    var var_courseCode = sse_decode_String(deserializer);
+    var var_courseName = sse_decode_String(deserializer);
+    var var_examTime = sse_decode_String(deserializer);
+    var var_startTime = sse_decode_opt_String(deserializer);
+    var var_endTime = sse_decode_opt_String(deserializer);
+    var var_examLocation = sse_decode_String(deserializer);
+    var var_seatNumber = sse_decode_String(deserializer);
+    var var_examMethod = sse_decode_String(deserializer);
+    var var_remark = sse_decode_String(deserializer);
+    return ExamSchedule(
+      id: var_id,
+      campus: var_campus,
+      courseCode: var_courseCode,
+      courseName: var_courseName,
+      examTime: var_examTime,
+      startTime: var_startTime,
+      endTime: var_endTime,
+      examLocation: var_examLocation,
+      seatNumber: var_seatNumber,
+      examMethod: var_examMethod,
+      remark: var_remark,
+    );
+  }
+
+  @protected
+  ExecutionPlan sse_decode_execution_plan(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_semester = sse_decode_String(deserializer);
+    var var_academicYear = sse_decode_String(deserializer);
+    var var_term = sse_decode_u_8(deserializer);
+    var var_courseCode = sse_decode_String(deserializer);
+    var var_courseName = sse_decode_String(deserializer);
+    var var_department = sse_decode_String(deserializer);
+    var var_credits = sse_decode_f_32(deserializer);
+    var var_totalHours = sse_decode_f_32(deserializer);
+    var var_assessmentMethod = sse_decode_String(deserializer);
+    var var_courseType = sse_decode_String(deserializer);
+    var var_isExam = sse_decode_String(deserializer);
+    return ExecutionPlan(
+      id: var_id,
+      semester: var_semester,
+      academicYear: var_academicYear,
+      term: var_term,
+      courseCode: var_courseCode,
+      courseName: var_courseName,
+      department: var_department,
+      credits: var_credits,
+      totalHours: var_totalHours,
+      assessmentMethod: var_assessmentMethod,
+      courseType: var_courseType,
+      isExam: var_isExam,
+    );
+  }
+
+  @protected
+  ExecutionPlanResponse sse_decode_execution_plan_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_plans = sse_decode_list_execution_plan(deserializer);
+    var var_semesters = sse_decode_list_String(deserializer);
+    var var_groups = sse_decode_list_plan_semester(deserializer);
+    return ExecutionPlanResponse(
+      plans: var_plans,
+      semesters: var_semesters,
+      groups: var_groups,
+    );
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  FilterOption sse_decode_filter_option(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return FilterOption(key: var_key, value: var_value);
+  }
+
+  @protected
+  FreeClassroom sse_decode_free_classroom(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_campus = sse_decode_String(deserializer);
+    var var_building = sse_decode_String(deserializer);
+    var var_capacity = sse_decode_u_32(deserializer);
+    var var_roomType = sse_decode_String(deserializer);
+    return FreeClassroom(
+      name: var_name,
+      campus: var_campus,
+      building: var_building,
+      capacity: var_capacity,
+      roomType: var_roomType,
+    );
+  }
+
+  @protected
+  GpaFilter sse_decode_gpa_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_courseNatures = sse_decode_list_String(deserializer);
+    var var_courseAttrs = sse_decode_list_String(deserializer);
+    return GpaFilter(
+      courseNatures: var_courseNatures,
+      courseAttrs: var_courseAttrs,
+    );
+  }
+
+  @protected
+  GpaReport sse_decode_gpa_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_scheme = sse_decode_gpa_scheme(deserializer);
+    var var_overall = sse_decode_gpa_result(deserializer);
+    var var_semesters = sse_decode_list_semester_gpa(deserializer);
+    return GpaReport(
+      scheme: var_scheme,
+      overall: var_overall,
+      semesters: var_semesters,
+    );
+  }
+
+  @protected
+  GpaResult sse_decode_gpa_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_gpa = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_averageScore = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_credits = sse_decode_f_32(deserializer);
+    var var_courseCount = sse_decode_u_32(deserializer);
+    return GpaResult(
+      gpa: var_gpa,
+      averageScore: var_averageScore,
+      credits: var_credits,
+      courseCount: var_courseCount,
+    );
+  }
+
+  @protected
+  GpaScheme sse_decode_gpa_scheme(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return GpaScheme.values[inner];
+  }
+
+  @protected
+  Grade sse_decode_grade(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_raw = sse_decode_String(deserializer);
+    var var_value = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_isAbsent = sse_decode_bool(deserializer);
+    var var_isExempt = sse_decode_bool(deserializer);
+    var var_isRetake = sse_decode_bool(deserializer);
+    var var_isMakeup = sse_decode_bool(deserializer);
+    return Grade(
+      raw: var_raw,
+      value: var_value,
+      isAbsent: var_isAbsent,
+      isExempt: var_isExempt,
+      isRetake: var_isRetake,
+      isMakeup: var_isMakeup,
+    );
+  }
+
+  @protected
+  GradeScale sse_decode_grade_scale(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_excellent = sse_decode_f_32(deserializer);
+    var var_good = sse_decode_f_32(deserializer);
+    var var_medium = sse_decode_f_32(deserializer);
+    var var_pass = sse_decode_f_32(deserializer);
+    var var_fail = sse_decode_f_32(deserializer);
+    var var_qualified = sse_decode_f_32(deserializer);
+    var var_unqualified = sse_decode_f_32(deserializer);
+    return GradeScale(
+      excellent: var_excellent,
+      good: var_good,
+      medium: var_medium,
+      pass: var_pass,
+      fail: var_fail,
+      qualified: var_qualified,
+      unqualified: var_unqualified,
+    );
+  }
+
+  @protected
+  GraduationReport sse_decode_graduation_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_buckets = sse_decode_list_credit_bucket(deserializer);
+    var var_passed = sse_decode_bool(deserializer);
+    return GraduationReport(buckets: var_buckets, passed: var_passed);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AuditCourse> sse_decode_list_audit_course(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AuditCourse>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_audit_course(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CalendarDay> sse_decode_list_calendar_day(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CalendarDay>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_calendar_day(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CourseSchedule> sse_decode_list_course_schedule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CourseSchedule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_course_schedule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CreditBucket> sse_decode_list_credit_bucket(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CreditBucket>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_credit_bucket(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CreditInfo> sse_decode_list_credit_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CreditInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_credit_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CreditProgress> sse_decode_list_credit_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CreditProgress>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_credit_progress(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DEKTList> sse_decode_list_dekt_list(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DEKTList>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_dekt_list(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DektRequirement> sse_decode_list_dekt_requirement(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DektRequirement>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_dekt_requirement(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DEKTTotal> sse_decode_list_dekt_total(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DEKTTotal>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_dekt_total(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ExamCountdown> sse_decode_list_exam_countdown(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ExamCountdown>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_exam_countdown(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ExamSchedule> sse_decode_list_exam_schedule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ExamSchedule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_exam_schedule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ExecutionPlan> sse_decode_list_execution_plan(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ExecutionPlan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_execution_plan(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FilterOption> sse_decode_list_filter_option(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FilterOption>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_filter_option(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FreeClassroom> sse_decode_list_free_classroom(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FreeClassroom>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_free_classroom(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PlanSemester> sse_decode_list_plan_semester(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PlanSemester>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_plan_semester(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  HttpSession
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return HttpSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  List<SchoolWeek> sse_decode_list_school_week(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SchoolWeek>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_school_week(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  HttpSession
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return HttpSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  List<ScoreInfo> sse_decode_list_score_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ScoreInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_score_info(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  HttpSession
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return HttpSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  List<SectionTime> sse_decode_list_section_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SectionTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_section_time(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
+  List<SelectableCourse> sse_decode_list_selectable_course(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SelectableCourse>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_selectable_course(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  ArcCookieStoreMutex
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
-    dynamic raw,
+  List<SelectionRound> sse_decode_list_selection_round(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcCookieStoreMutexImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SelectionRound>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_selection_round(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  Client
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  List<SemesterGpa> sse_decode_list_semester_gpa(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SemesterGpa>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_semester_gpa(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  CryptoError
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
-    dynamic raw,
+  List<SemesterInfo> sse_decode_list_semester_info(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CryptoErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SemesterInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_semester_info(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  HttpSession
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return HttpSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  List<XqxkchInfo> sse_decode_list_xqxkch_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <XqxkchInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_xqxkch_info(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  LoginState sse_decode_login_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LoginState.values[inner];
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  CourseSchedule dco_decode_course_schedule(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return CourseSchedule(
-      id: dco_decode_String(arr[0]),
-      name: dco_decode_String(arr[1]),
-      teacher: dco_decode_String(arr[2]),
-      timeRange: dco_decode_String(arr[3]),
-      dayOfWeek: dco_decode_String(arr[4]),
-      section: dco_decode_usize(arr[5]),
-      weeks: dco_decode_String(arr[6]),
-      classroom: dco_decode_String(arr[7]),
-      duration: dco_decode_String(arr[8]),
-    );
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  CreditInfo dco_decode_credit_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return CreditInfo(
-      category: dco_decode_String(arr[0]),
-      required_: dco_decode_i_32(arr[1]),
-      limited: dco_decode_i_32(arr[2]),
-      elective: dco_decode_i_32(arr[3]),
-      public: dco_decode_i_32(arr[4]),
-      total: dco_decode_i_32(arr[5]),
-    );
+  Grade? sse_decode_opt_box_autoadd_grade(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_grade(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  DEKT dco_decode_dekt(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return DEKT(
-      list: dco_decode_list_dekt_list(arr[0]),
-      total: dco_decode_list_dekt_total(arr[1]),
-    );
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  DEKTDetail dco_decode_dekt_detail(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return DEKTDetail(field0: dco_decode_Map_String_String_None(arr[0]));
+  (String, int)? sse_decode_opt_box_autoadd_record_string_u_8(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_record_string_u_8(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  DEKTList dco_decode_dekt_list(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return DEKTList(
-      id: dco_decode_String(arr[0]),
-      semester: dco_decode_String(arr[1]),
-      category: dco_decode_String(arr[2]),
-      subCategory: dco_decode_String(arr[3]),
-      activityName: dco_decode_String(arr[4]),
-      credit: dco_decode_String(arr[5]),
-      operationId: dco_decode_String(arr[6]),
-    );
+  SemesterStart? sse_decode_opt_box_autoadd_semester_start(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_semester_start(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  DEKTTotal dco_decode_dekt_total(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return DEKTTotal(
-      category: dco_decode_String(arr[0]),
-      totalCredit: dco_decode_String(arr[1]),
-    );
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  ElectiveResponse dco_decode_elective_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ElectiveResponse(
-      credits: dco_decode_list_credit_info(arr[0]),
-      courses: dco_decode_list_xqxkch_info(arr[1]),
+  PlanSemester sse_decode_plan_semester(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_semester = sse_decode_String(deserializer);
+    var var_academicYear = sse_decode_String(deserializer);
+    var var_term = sse_decode_u_8(deserializer);
+    var var_credits = sse_decode_f_32(deserializer);
+    var var_totalHours = sse_decode_f_32(deserializer);
+    var var_plans = sse_decode_list_execution_plan(deserializer);
+    return PlanSemester(
+      semester: var_semester,
+      academicYear: var_academicYear,
+      term: var_term,
+      credits: var_credits,
+      totalHours: var_totalHours,
+      plans: var_plans,
     );
   }
 
   @protected
-  ExamSchedule dco_decode_exam_schedule(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return ExamSchedule(
-      id: dco_decode_u_32(arr[0]),
-      courseCode: dco_decode_String(arr[1]),
-      courseName: dco_decode_String(arr[2]),
-      examTime: dco_decode_String(arr[3]),
-      examLocation: dco_decode_String(arr[4]),
-    );
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  ExecutionPlan dco_decode_execution_plan(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return ExecutionPlan(
-      id: dco_decode_u_32(arr[0]),
-      semester: dco_decode_String(arr[1]),
-      courseCode: dco_decode_String(arr[2]),
-      courseName: dco_decode_String(arr[3]),
-      department: dco_decode_String(arr[4]),
-      credits: dco_decode_f_32(arr[5]),
-      totalHours: dco_decode_f_32(arr[6]),
-      assessmentMethod: dco_decode_String(arr[7]),
-      courseType: dco_decode_String(arr[8]),
-      isExam: dco_decode_String(arr[9]),
-    );
+  (String, int) sse_decode_record_string_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_u_8(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  ExecutionPlanResponse dco_decode_execution_plan_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ExecutionPlanResponse(
-      plans: dco_decode_list_execution_plan(arr[0]),
-      semesters: dco_decode_list_String(arr[1]),
+  SchoolCalendar sse_decode_school_calendar(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_semester = sse_decode_String(deserializer);
+    var var_startDate = sse_decode_String(deserializer);
+    var var_weeks = sse_decode_list_school_week(deserializer);
+    return SchoolCalendar(
+      semester: var_semester,
+      startDate: var_startDate,
+      weeks: var_weeks,
     );
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  SchoolWeek sse_decode_school_week(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_week = sse_decode_u_32(deserializer);
+    var var_monday = sse_decode_String(deserializer);
+    var var_holidays = sse_decode_list_calendar_day(deserializer);
+    var var_swapDays = sse_decode_list_calendar_day(deserializer);
+    return SchoolWeek(
+      week: var_week,
+      monday: var_monday,
+      holidays: var_holidays,
+      swapDays: var_swapDays,
+    );
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  ScoreInfo sse_decode_score_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_term = sse_decode_String(deserializer);
+    var var_courseId = sse_decode_String(deserializer);
+    var var_courseName = sse_decode_String(deserializer);
+    var var_score = sse_decode_grade(deserializer);
+    var var_credit = sse_decode_f_32(deserializer);
+    var var_hours = sse_decode_f_32(deserializer);
+    var var_gpa = sse_decode_f_32(deserializer);
+    var var_examType = sse_decode_String(deserializer);
+    var var_courseAttr = sse_decode_String(deserializer);
+    var var_courseNature = sse_decode_String(deserializer);
+    return ScoreInfo(
+      id: var_id,
+      term: var_term,
+      courseId: var_courseId,
+      courseName: var_courseName,
+      score: var_score,
+      credit: var_credit,
+      hours: var_hours,
+      gpa: var_gpa,
+      examType: var_examType,
+      courseAttr: var_courseAttr,
+      courseNature: var_courseNature,
+    );
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  ScoreSummary sse_decode_score_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_creditTotal = sse_decode_f_32(deserializer);
+    var var_gpaAverage = sse_decode_f_32(deserializer);
+    var var_averageScore = sse_decode_opt_box_autoadd_f_32(deserializer);
+    return ScoreSummary(
+      creditTotal: var_creditTotal,
+      gpaAverage: var_gpaAverage,
+      averageScore: var_averageScore,
+    );
   }
 
   @protected
-  List<CourseSchedule> dco_decode_list_course_schedule(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_course_schedule).toList();
+  ScoreTotal sse_decode_score_total(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_info = sse_decode_list_score_info(deserializer);
+    var var_summary = sse_decode_score_summary(deserializer);
+    return ScoreTotal(info: var_info, summary: var_summary);
   }
 
   @protected
-  List<CreditInfo> dco_decode_list_credit_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_credit_info).toList();
+  SeatWatchConfig sse_decode_seat_watch_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_category = sse_decode_selection_category(deserializer);
+    var var_courseIds = sse_decode_list_String(deserializer);
+    var var_intervalMs = sse_decode_u_32(deserializer);
+    var var_jitterMs = sse_decode_u_32(deserializer);
+    var var_maxRounds = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return SeatWatchConfig(
+      category: var_category,
+      courseIds: var_courseIds,
+      intervalMs: var_intervalMs,
+      jitterMs: var_jitterMs,
+      maxRounds: var_maxRounds,
+    );
   }
 
   @protected
-  List<DEKTList> dco_decode_list_dekt_list(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_dekt_list).toList();
+  SeatWatchEvent sse_decode_seat_watch_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_round = sse_decode_u_32(deserializer);
+        var var_courses = sse_decode_list_selectable_course(deserializer);
+        return SeatWatchEvent_Polled(round: var_round, courses: var_courses);
+      case 1:
+        var var_course = sse_decode_box_autoadd_selectable_course(deserializer);
+        var var_outcome =
+            sse_decode_box_autoadd_selection_outcome(deserializer);
+        return SeatWatchEvent_Attempted(
+          course: var_course,
+          outcome: var_outcome,
+        );
+      case 2:
+        var var_message = sse_decode_String(deserializer);
+        return SeatWatchEvent_Error(message: var_message);
+      case 3:
+        var var_selected = sse_decode_list_String(deserializer);
+        var var_pending = sse_decode_list_String(deserializer);
+        return SeatWatchEvent_Finished(
+          selected: var_selected,
+          pending: var_pending,
+        );
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  List<DEKTTotal> dco_decode_list_dekt_total(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_dekt_total).toList();
+  SectionTime sse_decode_section_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_section = sse_decode_usize(deserializer);
+    var var_start = sse_decode_String(deserializer);
+    var var_end = sse_decode_String(deserializer);
+    return SectionTime(section: var_section, start: var_start, end: var_end);
   }
 
   @protected
-  List<ExamSchedule> dco_decode_list_exam_schedule(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_exam_schedule).toList();
+  SelectableCourse sse_decode_selectable_course(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_category = sse_decode_selection_category(deserializer);
+    var var_courseCode = sse_decode_String(deserializer);
+    var var_courseName = sse_decode_String(deserializer);
+    var var_credits = sse_decode_f_32(deserializer);
+    var var_teacher = sse_decode_String(deserializer);
+    var var_time = sse_decode_String(deserializer);
+    var var_location = sse_decode_String(deserializer);
+    var var_campus = sse_decode_String(deserializer);
+    var var_capacity = sse_decode_u_32(deserializer);
+    var var_selected = sse_decode_u_32(deserializer);
+    var var_remaining = sse_decode_u_32(deserializer);
+    var var_conflict = sse_decode_String(deserializer);
+    return SelectableCourse(
+      id: var_id,
+      category: var_category,
+      courseCode: var_courseCode,
+      courseName: var_courseName,
+      credits: var_credits,
+      teacher: var_teacher,
+      time: var_time,
+      location: var_location,
+      campus: var_campus,
+      capacity: var_capacity,
+      selected: var_selected,
+      remaining: var_remaining,
+      conflict: var_conflict,
+    );
   }
 
   @protected
-  List<ExecutionPlan> dco_decode_list_execution_plan(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_execution_plan).toList();
+  SelectionCategory sse_decode_selection_category(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SelectionCategory.values[inner];
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  SelectionOutcome sse_decode_selection_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_result = sse_decode_selection_result(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return SelectionOutcome(result: var_result, message: var_message);
   }
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  SelectionResult sse_decode_selection_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SelectionResult.values[inner];
   }
 
   @protected
-  List<ScoreInfo> dco_decode_list_score_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_score_info).toList();
+  SelectionRound sse_decode_selection_round(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_semester = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_startTime = sse_decode_String(deserializer);
+    var var_endTime = sse_decode_String(deserializer);
+    var var_canEnter = sse_decode_bool(deserializer);
+    return SelectionRound(
+      id: var_id,
+      semester: var_semester,
+      name: var_name,
+      startTime: var_startTime,
+      endTime: var_endTime,
+      canEnter: var_canEnter,
+    );
   }
 
   @protected
-  List<SemesterInfo> dco_decode_list_semester_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_semester_info).toList();
+  SemesterGpa sse_decode_semester_gpa(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_term = sse_decode_String(deserializer);
+    var var_result = sse_decode_gpa_result(deserializer);
+    return SemesterGpa(term: var_term, result: var_result);
   }
 
   @protected
-  List<XqxkchInfo> dco_decode_list_xqxkch_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_xqxkch_info).toList();
+  SemesterInfo sse_decode_semester_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    var var_academicYear = sse_decode_String(deserializer);
+    var var_term = sse_decode_u_8(deserializer);
+    var var_isCurrent = sse_decode_bool(deserializer);
+    return SemesterInfo(
+      key: var_key,
+      value: var_value,
+      academicYear: var_academicYear,
+      term: var_term,
+      isCurrent: var_isCurrent,
+    );
   }
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  SemesterStart sse_decode_semester_start(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_semester = sse_decode_String(deserializer);
+    var var_startDate = sse_decode_String(deserializer);
+    return SemesterStart(semester: var_semester, startDate: var_startDate);
   }
 
   @protected
-  ScoreInfo dco_decode_score_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return ScoreInfo(
-      id: dco_decode_u_32(arr[0]),
-      term: dco_decode_String(arr[1]),
-      courseId: dco_decode_String(arr[2]),
-      courseName: dco_decode_String(arr[3]),
-      score: dco_decode_f_32(arr[4]),
-      credit: dco_decode_f_32(arr[5]),
-      hours: dco_decode_f_32(arr[6]),
-      gpa: dco_decode_f_32(arr[7]),
-      examType: dco_decode_String(arr[8]),
-      courseAttr: dco_decode_String(arr[9]),
-      courseNature: dco_decode_String(arr[10]),
+  StudentInfo sse_decode_student_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_gender = sse_decode_opt_String(deserializer);
+    var var_studentId = sse_decode_opt_String(deserializer);
+    var var_department = sse_decode_opt_String(deserializer);
+    var var_major = sse_decode_opt_String(deserializer);
+    var var_className = sse_decode_opt_String(deserializer);
+    var var_admissionDate = sse_decode_opt_String(deserializer);
+    var var_admissionNumber = sse_decode_opt_String(deserializer);
+    var var_idNumber = sse_decode_opt_String(deserializer);
+    var var_birthDate = sse_decode_opt_String(deserializer);
+    var var_ethnicity = sse_decode_opt_String(deserializer);
+    var var_politicalStatus = sse_decode_opt_String(deserializer);
+    var var_hometown = sse_decode_opt_String(deserializer);
+    var var_educationLevel = sse_decode_opt_String(deserializer);
+    var var_lengthOfSchooling = sse_decode_opt_String(deserializer);
+    var var_contact = sse_decode_opt_String(deserializer);
+    var var_highSchool = sse_decode_opt_String(deserializer);
+    return StudentInfo(
+      name: var_name,
+      gender: var_gender,
+      studentId: var_studentId,
+      department: var_department,
+      major: var_major,
+      className: var_className,
+      admissionDate: var_admissionDate,
+      admissionNumber: var_admissionNumber,
+      idNumber: var_idNumber,
+      birthDate: var_birthDate,
+      ethnicity: var_ethnicity,
+      politicalStatus: var_politicalStatus,
+      hometown: var_hometown,
+      educationLevel: var_educationLevel,
+      lengthOfSchooling: var_lengthOfSchooling,
+      contact: var_contact,
+      highSchool: var_highSchool,
     );
   }
 
   @protected
-  ScoreSummary dco_decode_score_summary(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ScoreSummary(
-      creditTotal: dco_decode_f_32(arr[0]),
-      gpaAverage: dco_decode_f_32(arr[1]),
-    );
+  StudentPhoto sse_decode_student_photo(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_mimeType = sse_decode_String(deserializer);
+    return StudentPhoto(bytes: var_bytes, mimeType: var_mimeType);
   }
 
   @protected
-  ScoreTotal dco_decode_score_total(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ScoreTotal(
-      info: dco_decode_list_score_info(arr[0]),
-      summary: dco_decode_score_summary(arr[1]),
-    );
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  SemesterInfo dco_decode_semester_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SemesterInfo(
-      key: dco_decode_String(arr[0]),
-      value: dco_decode_String(arr[1]),
-    );
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  StudentInfo dco_decode_student_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return StudentInfo(
-      name: dco_decode_String(arr[0]),
-      gender: dco_decode_String(arr[1]),
-      studentId: dco_decode_String(arr[2]),
-      department: dco_decode_String(arr[3]),
-      major: dco_decode_String(arr[4]),
-      className: dco_decode_String(arr[5]),
-      admissionDate: dco_decode_String(arr[6]),
-      admissionNumber: dco_decode_String(arr[7]),
-      idNumber: dco_decode_String(arr[8]),
-    );
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  XqxkchInfo sse_decode_xqxkch_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_courseId = sse_decode_String(deserializer);
+    var var_courseName = sse_decode_String(deserializer);
+    var var_department = sse_decode_String(deserializer);
+    var var_hours = sse_decode_i_32(deserializer);
+    var var_credits = sse_decode_f_32(deserializer);
+    var var_courseAttribute = sse_decode_String(deserializer);
+    var var_selectionType = sse_decode_String(deserializer);
+    var var_selected = sse_decode_String(deserializer);
+    return XqxkchInfo(
+      courseId: var_courseId,
+      courseName: var_courseName,
+      department: var_department,
+      hours: var_hours,
+      credits: var_credits,
+      courseAttribute: var_courseAttribute,
+      selectionType: var_selectionType,
+      selected: var_selected,
+    );
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
+    ArcCookieStoreMutex self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ArcCookieStoreMutexImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  XqxkchInfo dco_decode_xqxkch_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return XqxkchInfo(
-      courseId: dco_decode_String(arr[0]),
-      courseName: dco_decode_String(arr[1]),
-      department: dco_decode_String(arr[2]),
-      hours: dco_decode_i_32(arr[3]),
-      credits: dco_decode_f_32(arr[4]),
-      courseAttribute: dco_decode_String(arr[5]),
-      selectionType: dco_decode_String(arr[6]),
-      selected: dco_decode_String(arr[7]),
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
+    Client self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ClientImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  ArcCookieStoreMutex
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
+    CryptoError self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcCookieStoreMutexImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as CryptoErrorImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  Client
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    HttpSession self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ClientImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as HttpSessionImpl).frbInternalSseEncode(move: true),
+      serializer,
     );
   }
 
   @protected
-  CryptoError
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    HttpSession self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return CryptoErrorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as HttpSessionImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  HttpSession
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    SseDeserializer deserializer,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    HttpSession self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return HttpSessionImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as HttpSessionImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  HttpSession
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    SseDeserializer deserializer,
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return HttpSessionImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
     );
   }
 
   @protected
-  HttpSession
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
+    ArcCookieStoreMutex self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return HttpSessionImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as ArcCookieStoreMutexImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
+    Client self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    sse_encode_usize(
+      (self as ClientImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  ArcCookieStoreMutex
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
+    CryptoError self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcCookieStoreMutexImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as CryptoErrorImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  Client
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    HttpSession self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ClientImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as HttpSessionImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  CryptoError
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
-    SseDeserializer deserializer,
+  void sse_encode_StreamSink_seat_watch_event_Sse(
+    RustStreamSink<SeatWatchEvent> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return CryptoErrorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_seat_watch_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  HttpSession
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    SseDeserializer deserializer,
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_api_error(ApiError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ApiError_Network(cause: final cause):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(cause, serializer);
+      case ApiError_SessionExpired():
+        sse_encode_i_32(1, serializer);
+      case ApiError_BadCredentials(message: final message):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(message, serializer);
+      case ApiError_BadCaptcha():
+        sse_encode_i_32(3, serializer);
+      case ApiError_ParseLayoutChanged(
+        page: final page,
+        selector: final selector,
+      ):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(page, serializer);
+        sse_encode_String(selector, serializer);
+      case ApiError_NotInitialized():
+        sse_encode_i_32(5, serializer);
+      case ApiError_NotLoggedIn():
+        sse_encode_i_32(6, serializer);
+      case ApiError_InvalidArgument(message: final message):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(message, serializer);
+      case ApiError_Io(cause: final cause):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(cause, serializer);
+      case ApiError_Internal(cause: final cause):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(cause, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_audit_course(AuditCourse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.courseCode, serializer);
+    sse_encode_String(self.courseName, serializer);
+    sse_encode_String(self.semester, serializer);
+    sse_encode_String(self.courseType, serializer);
+    sse_encode_f_32(self.credits, serializer);
+    sse_encode_course_status(self.status, serializer);
+    sse_encode_opt_box_autoadd_grade(self.bestScore, serializer);
+    sse_encode_u_32(self.attempts, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_endpoints(
+    Endpoints self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return HttpSessionImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_endpoints(self, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_f_32(self, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_gpa_filter(
+    GpaFilter self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_gpa_filter(self, serializer);
   }
 
   @protected
-  CourseSchedule sse_decode_course_schedule(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_grade(Grade self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_teacher = sse_decode_String(deserializer);
-    var var_timeRange = sse_decode_String(deserializer);
-    var var_dayOfWeek = sse_decode_String(deserializer);
-    var var_section = sse_decode_usize(deserializer);
-    var var_weeks = sse_decode_String(deserializer);
-    var var_classroom = sse_decode_String(deserializer);
-    var var_duration = sse_decode_String(deserializer);
-    return CourseSchedule(
-      id: var_id,
-      name: var_name,
-      teacher: var_teacher,
-      timeRange: var_timeRange,
-      dayOfWeek: var_dayOfWeek,
-      section: var_section,
-      weeks: var_weeks,
-      classroom: var_classroom,
-      duration: var_duration,
-    );
+    sse_encode_grade(self, serializer);
   }
 
   @protected
-  CreditInfo sse_decode_credit_info(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_grade_scale(
+    GradeScale self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_category = sse_decode_String(deserializer);
-    var var_required_ = sse_decode_i_32(deserializer);
-    var var_limited = sse_decode_i_32(deserializer);
-    var var_elective = sse_decode_i_32(deserializer);
-    var var_public = sse_decode_i_32(deserializer);
-    var var_total = sse_decode_i_32(deserializer);
-    return CreditInfo(
-      category: var_category,
-      required_: var_required_,
-      limited: var_limited,
-      elective: var_elective,
-      public: var_public,
-      total: var_total,
-    );
+    sse_encode_grade_scale(self, serializer);
   }
 
   @protected
-  DEKT sse_decode_dekt(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_list = sse_decode_list_dekt_list(deserializer);
-    var var_total = sse_decode_list_dekt_total(deserializer);
-    return DEKT(list: var_list, total: var_total);
+    sse_encode_i_64(self, serializer);
   }
 
   @protected
-  DEKTDetail sse_decode_dekt_detail(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_record_string_u_8(
+    (String, int) self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_Map_String_String_None(deserializer);
-    return DEKTDetail(field0: var_field0);
+    sse_encode_record_string_u_8(self, serializer);
   }
 
   @protected
-  DEKTList sse_decode_dekt_list(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_seat_watch_config(
+    SeatWatchConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_semester = sse_decode_String(deserializer);
-    var var_category = sse_decode_String(deserializer);
-    var var_subCategory = sse_decode_String(deserializer);
-    var var_activityName = sse_decode_String(deserializer);
-    var var_credit = sse_decode_String(deserializer);
-    var var_operationId = sse_decode_String(deserializer);
-    return DEKTList(
-      id: var_id,
-      semester: var_semester,
-      category: var_category,
-      subCategory: var_subCategory,
-      activityName: var_activityName,
-      credit: var_credit,
-      operationId: var_operationId,
-    );
+    sse_encode_seat_watch_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_selectable_course(
+    SelectableCourse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_selectable_course(self, serializer);
   }
 
   @protected
-  DEKTTotal sse_decode_dekt_total(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_selection_outcome(
+    SelectionOutcome self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_category = sse_decode_String(deserializer);
-    var var_totalCredit = sse_decode_String(deserializer);
-    return DEKTTotal(category: var_category, totalCredit: var_totalCredit);
+    sse_encode_selection_outcome(self, serializer);
   }
 
   @protected
-  ElectiveResponse sse_decode_elective_response(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_semester_start(
+    SemesterStart self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_credits = sse_decode_list_credit_info(deserializer);
-    var var_courses = sse_decode_list_xqxkch_info(deserializer);
-    return ElectiveResponse(credits: var_credits, courses: var_courses);
+    sse_encode_semester_start(self, serializer);
   }
 
   @protected
-  ExamSchedule sse_decode_exam_schedule(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_courseCode = sse_decode_String(deserializer);
-    var var_courseName = sse_decode_String(deserializer);
-    var var_examTime = sse_decode_String(deserializer);
-    var var_examLocation = sse_decode_String(deserializer);
-    return ExamSchedule(
-      id: var_id,
-      courseCode: var_courseCode,
-      courseName: var_courseName,
-      examTime: var_examTime,
-      examLocation: var_examLocation,
-    );
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  ExecutionPlan sse_decode_execution_plan(SseDeserializer deserializer) {
+  void sse_encode_calendar_day(CalendarDay self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_semester = sse_decode_String(deserializer);
-    var var_courseCode = sse_decode_String(deserializer);
-    var var_courseName = sse_decode_String(deserializer);
-    var var_department = sse_decode_String(deserializer);
-    var var_credits = sse_decode_f_32(deserializer);
-    var var_totalHours = sse_decode_f_32(deserializer);
-    var var_assessmentMethod = sse_decode_String(deserializer);
-    var var_courseType = sse_decode_String(deserializer);
-    var var_isExam = sse_decode_String(deserializer);
-    return ExecutionPlan(
-      id: var_id,
-      semester: var_semester,
-      courseCode: var_courseCode,
-      courseName: var_courseName,
-      department: var_department,
-      credits: var_credits,
-      totalHours: var_totalHours,
-      assessmentMethod: var_assessmentMethod,
-      courseType: var_courseType,
-      isExam: var_isExam,
-    );
+    sse_encode_String(self.date, serializer);
+    sse_encode_u_32(self.weekday, serializer);
+    sse_encode_calendar_day_kind(self.kind, serializer);
+    sse_encode_String(self.note, serializer);
   }
 
   @protected
-  ExecutionPlanResponse sse_decode_execution_plan_response(
-    SseDeserializer deserializer,
+  void sse_encode_calendar_day_kind(
+    CalendarDayKind self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_plans = sse_decode_list_execution_plan(deserializer);
-    var var_semesters = sse_decode_list_String(deserializer);
-    return ExecutionPlanResponse(plans: var_plans, semesters: var_semesters);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_classroom_filters(
+    ClassroomFilters self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_list_filter_option(self.campuses, serializer);
+    sse_encode_list_filter_option(self.buildings, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_course_schedule(
+    CourseSchedule self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.teacher, serializer);
+    sse_encode_String(self.timeRange, serializer);
+    sse_encode_String(self.dayOfWeek, serializer);
+    sse_encode_usize(self.section, serializer);
+    sse_encode_String(self.weeks, serializer);
+    sse_encode_list_prim_u_8_strict(self.weekList, serializer);
+    sse_encode_String(self.classroom, serializer);
+    sse_encode_String(self.duration, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_course_status(CourseStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  List<CourseSchedule> sse_decode_list_course_schedule(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_credit_bucket(CreditBucket self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <CourseSchedule>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_course_schedule(deserializer));
-    }
-    return ans_;
+    sse_encode_credit_source(self.source, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_f_32(self.earned, serializer);
+    sse_encode_f_32(self.required_, serializer);
+    sse_encode_f_32(self.missing, serializer);
+    sse_encode_bool(self.passed, serializer);
   }
 
   @protected
-  List<CreditInfo> sse_decode_list_credit_info(SseDeserializer deserializer) {
+  void sse_encode_credit_info(CreditInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <CreditInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_credit_info(deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.category, serializer);
+    sse_encode_f_32(self.required_, serializer);
+    sse_encode_f_32(self.limited, serializer);
+    sse_encode_f_32(self.elective, serializer);
+    sse_encode_f_32(self.public, serializer);
+    sse_encode_f_32(self.total, serializer);
   }
 
   @protected
-  List<DEKTList> sse_decode_list_dekt_list(SseDeserializer deserializer) {
+  void sse_encode_credit_progress(
+    CreditProgress self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DEKTList>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_dekt_list(deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.key, serializer);
+    sse_encode_f_32(self.earned, serializer);
+    sse_encode_f_32(self.required_, serializer);
+    sse_encode_u_32(self.passedCount, serializer);
+    sse_encode_u_32(self.courseCount, serializer);
   }
 
   @protected
-  List<DEKTTotal> sse_decode_list_dekt_total(SseDeserializer deserializer) {
+  void sse_encode_credit_source(CreditSource self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DEKTTotal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_dekt_total(deserializer));
-    }
-    return ans_;
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  List<ExamSchedule> sse_decode_list_exam_schedule(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_current_week(CurrentWeek self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.semester, serializer);
+    sse_encode_String(self.startDate, serializer);
+    sse_encode_i_32(self.week, serializer);
+    sse_encode_u_32(self.weekday, serializer);
+    sse_encode_bool(self.isOverridden, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ExamSchedule>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_exam_schedule(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_degree_audit(DegreeAudit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_audit_course(self.courses, serializer);
+    sse_encode_list_credit_progress(self.byCourseType, serializer);
+    sse_encode_list_credit_progress(self.bySemester, serializer);
+    sse_encode_f_32(self.earned, serializer);
+    sse_encode_f_32(self.required_, serializer);
+    sse_encode_list_score_info(self.outsidePlan, serializer);
+    sse_encode_f_32(self.outsidePlanEarned, serializer);
   }
 
   @protected
-  List<ExecutionPlan> sse_decode_list_execution_plan(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_dekt(DEKT self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_dekt_list(self.list, serializer);
+    sse_encode_list_dekt_total(self.total, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ExecutionPlan>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_execution_plan(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_dekt_detail(DEKTDetail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Map_String_String_None(self.field0, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_dekt_list(DEKTList self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.semester, serializer);
+    sse_encode_String(self.category, serializer);
+    sse_encode_String(self.subCategory, serializer);
+    sse_encode_String(self.activityName, serializer);
+    sse_encode_String(self.credit, serializer);
+    sse_encode_String(self.operationId, serializer);
   }
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
+  void sse_encode_dekt_requirement(
+    DektRequirement self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.category, serializer);
+    sse_encode_f_32(self.credits, serializer);
   }
 
   @protected
-  List<ScoreInfo> sse_decode_list_score_info(SseDeserializer deserializer) {
+  void sse_encode_dekt_total(DEKTTotal self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ScoreInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_score_info(deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.category, serializer);
+    sse_encode_String(self.totalCredit, serializer);
   }
 
   @protected
-  List<SemesterInfo> sse_decode_list_semester_info(
-    SseDeserializer deserializer,
+  void sse_encode_elective_response(
+    ElectiveResponse self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SemesterInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_semester_info(deserializer));
-    }
-    return ans_;
+    sse_encode_list_credit_info(self.credits, serializer);
+    sse_encode_list_xqxkch_info(self.courses, serializer);
   }
 
   @protected
-  List<XqxkchInfo> sse_decode_list_xqxkch_info(SseDeserializer deserializer) {
+  void sse_encode_endpoints(Endpoints self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.cas, serializer);
+    sse_encode_String(self.jwxt, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <XqxkchInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_xqxkch_info(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_exam_countdown(ExamCountdown self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_exam_schedule(self.exam, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.remainingSeconds, serializer);
+    sse_encode_bool(self.isFinished, serializer);
+    sse_encode_list_prim_u_32_strict(self.overlapsWith, serializer);
+    sse_encode_list_prim_u_32_strict(self.sameDayWith, serializer);
   }
 
   @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_exam_schedule(ExamSchedule self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_u_32(self.id, serializer);
+    sse_encode_String(self.campus, serializer);
+    sse_encode_String(self.courseCode, serializer);
+    sse_encode_String(self.courseName, serializer);
+    sse_encode_String(self.examTime, serializer);
+    sse_encode_opt_String(self.startTime, serializer);
+    sse_encode_opt_String(self.endTime, serializer);
+    sse_encode_String(self.examLocation, serializer);
+    sse_encode_String(self.seatNumber, serializer);
+    sse_encode_String(self.examMethod, serializer);
+    sse_encode_String(self.remark, serializer);
   }
 
   @protected
-  ScoreInfo sse_decode_score_info(SseDeserializer deserializer) {
+  void sse_encode_execution_plan(ExecutionPlan self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_term = sse_decode_String(deserializer);
-    var var_courseId = sse_decode_String(deserializer);
-    var var_courseName = sse_decode_String(deserializer);
-    var var_score = sse_decode_f_32(deserializer);
-    var var_credit = sse_decode_f_32(deserializer);
-    var var_hours = sse_decode_f_32(deserializer);
-    var var_gpa = sse_decode_f_32(deserializer);
-    var var_examType = sse_decode_String(deserializer);
-    var var_courseAttr = sse_decode_String(deserializer);
-    var var_courseNature = sse_decode_String(deserializer);
-    return ScoreInfo(
-      id: var_id,
-      term: var_term,
-      courseId: var_courseId,
-      courseName: var_courseName,
-      score: var_score,
-      credit: var_credit,
-      hours: var_hours,
-      gpa: var_gpa,
-      examType: var_examType,
-      courseAttr: var_courseAttr,
-      courseNature: var_courseNature,
-    );
+    sse_encode_u_32(self.id, serializer);
+    sse_encode_String(self.semester, serializer);
+    sse_encode_String(self.academicYear, serializer);
+    sse_encode_u_8(self.term, serializer);
+    sse_encode_String(self.courseCode, serializer);
+    sse_encode_String(self.courseName, serializer);
+    sse_encode_String(self.department, serializer);
+    sse_encode_f_32(self.credits, serializer);
+    sse_encode_f_32(self.totalHours, serializer);
+    sse_encode_String(self.assessmentMethod, serializer);
+    sse_encode_String(self.courseType, serializer);
+    sse_encode_String(self.isExam, serializer);
   }
 
   @protected
-  ScoreSummary sse_decode_score_summary(SseDeserializer deserializer) {
+  void sse_encode_execution_plan_response(
+    ExecutionPlanResponse self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_creditTotal = sse_decode_f_32(deserializer);
-    var var_gpaAverage = sse_decode_f_32(deserializer);
-    return ScoreSummary(
-      creditTotal: var_creditTotal,
-      gpaAverage: var_gpaAverage,
-    );
+    sse_encode_list_execution_plan(self.plans, serializer);
+    sse_encode_list_String(self.semesters, serializer);
+    sse_encode_list_plan_semester(self.groups, serializer);
   }
 
   @protected
-  ScoreTotal sse_decode_score_total(SseDeserializer deserializer) {
+  void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_info = sse_decode_list_score_info(deserializer);
-    var var_summary = sse_decode_score_summary(deserializer);
-    return ScoreTotal(info: var_info, summary: var_summary);
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  SemesterInfo sse_decode_semester_info(SseDeserializer deserializer) {
+  void sse_encode_filter_option(FilterOption self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_key = sse_decode_String(deserializer);
-    var var_value = sse_decode_String(deserializer);
-    return SemesterInfo(key: var_key, value: var_value);
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
   }
 
   @protected
-  StudentInfo sse_decode_student_info(SseDeserializer deserializer) {
+  void sse_encode_free_classroom(FreeClassroom self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_gender = sse_decode_String(deserializer);
-    var var_studentId = sse_decode_String(deserializer);
-    var var_department = sse_decode_String(deserializer);
-    var var_major = sse_decode_String(deserializer);
-    var var_className = sse_decode_String(deserializer);
-    var var_admissionDate = sse_decode_String(deserializer);
-    var var_admissionNumber = sse_decode_String(deserializer);
-    var var_idNumber = sse_decode_String(deserializer);
-    return StudentInfo(
-      name: var_name,
-      gender: var_gender,
-      studentId: var_studentId,
-      department: var_department,
-      major: var_major,
-      className: var_className,
-      admissionDate: var_admissionDate,
-      admissionNumber: var_admissionNumber,
-      idNumber: var_idNumber,
-    );
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.campus, serializer);
+    sse_encode_String(self.building, serializer);
+    sse_encode_u_32(self.capacity, serializer);
+    sse_encode_String(self.roomType, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_gpa_filter(GpaFilter self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_list_String(self.courseNatures, serializer);
+    sse_encode_list_String(self.courseAttrs, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_gpa_report(GpaReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_gpa_scheme(self.scheme, serializer);
+    sse_encode_gpa_result(self.overall, serializer);
+    sse_encode_list_semester_gpa(self.semesters, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_gpa_result(GpaResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_f_32(self.gpa, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.averageScore, serializer);
+    sse_encode_f_32(self.credits, serializer);
+    sse_encode_u_32(self.courseCount, serializer);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_gpa_scheme(GpaScheme self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  XqxkchInfo sse_decode_xqxkch_info(SseDeserializer deserializer) {
+  void sse_encode_grade(Grade self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_courseId = sse_decode_String(deserializer);
-    var var_courseName = sse_decode_String(deserializer);
-    var var_department = sse_decode_String(deserializer);
-    var var_hours = sse_decode_i_32(deserializer);
-    var var_credits = sse_decode_f_32(deserializer);
-    var var_courseAttribute = sse_decode_String(deserializer);
-    var var_selectionType = sse_decode_String(deserializer);
-    var var_selected = sse_decode_String(deserializer);
-    return XqxkchInfo(
-      courseId: var_courseId,
-      courseName: var_courseName,
-      department: var_department,
-      hours: var_hours,
-      credits: var_credits,
-      courseAttribute: var_courseAttribute,
-      selectionType: var_selectionType,
-      selected: var_selected,
-    );
+    sse_encode_String(self.raw, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.value, serializer);
+    sse_encode_bool(self.isAbsent, serializer);
+    sse_encode_bool(self.isExempt, serializer);
+    sse_encode_bool(self.isRetake, serializer);
+    sse_encode_bool(self.isMakeup, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
-    ArcCookieStoreMutex self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_grade_scale(GradeScale self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcCookieStoreMutexImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_f_32(self.excellent, serializer);
+    sse_encode_f_32(self.good, serializer);
+    sse_encode_f_32(self.medium, serializer);
+    sse_encode_f_32(self.pass, serializer);
+    sse_encode_f_32(self.fail, serializer);
+    sse_encode_f_32(self.qualified, serializer);
+    sse_encode_f_32(self.unqualified, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
-    Client self,
+  void sse_encode_graduation_report(
+    GraduationReport self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ClientImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_list_credit_bucket(self.buckets, serializer);
+    sse_encode_bool(self.passed, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
-    CryptoError self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CryptoErrorImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    HttpSession self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as HttpSessionImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    HttpSession self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as HttpSessionImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    HttpSession self,
+  void sse_encode_list_audit_course(
+    List<AuditCourse> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as HttpSessionImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_audit_course(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
+  void sse_encode_list_calendar_day(
+    List<CalendarDay> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_calendar_day(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
-    ArcCookieStoreMutex self,
+  void sse_encode_list_course_schedule(
+    List<CourseSchedule> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ArcCookieStoreMutexImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_course_schedule(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
-    Client self,
+  void sse_encode_list_credit_bucket(
+    List<CreditBucket> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as ClientImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_credit_bucket(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
-    CryptoError self,
+  void sse_encode_list_credit_info(
+    List<CreditInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CryptoErrorImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_credit_info(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    HttpSession self,
+  void sse_encode_list_credit_progress(
+    List<CreditProgress> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as HttpSessionImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_credit_progress(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_course_schedule(
-    CourseSchedule self,
+  void sse_encode_list_dekt_list(
+    List<DEKTList> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.teacher, serializer);
-    sse_encode_String(self.timeRange, serializer);
-    sse_encode_String(self.dayOfWeek, serializer);
-    sse_encode_usize(self.section, serializer);
-    sse_encode_String(self.weeks, serializer);
-    sse_encode_String(self.classroom, serializer);
-    sse_encode_String(self.duration, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_dekt_list(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_credit_info(CreditInfo self, SseSerializer serializer) {
+  void sse_encode_list_dekt_requirement(
+    List<DektRequirement> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.category, serializer);
-    sse_encode_i_32(self.required_, serializer);
-    sse_encode_i_32(self.limited, serializer);
-    sse_encode_i_32(self.elective, serializer);
-    sse_encode_i_32(self.public, serializer);
-    sse_encode_i_32(self.total, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_dekt_requirement(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_dekt(DEKT self, SseSerializer serializer) {
+  void sse_encode_list_dekt_total(
+    List<DEKTTotal> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_dekt_list(self.list, serializer);
-    sse_encode_list_dekt_total(self.total, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_dekt_total(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_dekt_detail(DEKTDetail self, SseSerializer serializer) {
+  void sse_encode_list_exam_countdown(
+    List<ExamCountdown> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Map_String_String_None(self.field0, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_exam_countdown(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_dekt_list(DEKTList self, SseSerializer serializer) {
+  void sse_encode_list_exam_schedule(
+    List<ExamSchedule> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.semester, serializer);
-    sse_encode_String(self.category, serializer);
-    sse_encode_String(self.subCategory, serializer);
-    sse_encode_String(self.activityName, serializer);
-    sse_encode_String(self.credit, serializer);
-    sse_encode_String(self.operationId, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_exam_schedule(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_dekt_total(DEKTTotal self, SseSerializer serializer) {
+  void sse_encode_list_execution_plan(
+    List<ExecutionPlan> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.category, serializer);
-    sse_encode_String(self.totalCredit, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_execution_plan(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_elective_response(
-    ElectiveResponse self,
+  void sse_encode_list_filter_option(
+    List<FilterOption> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_credit_info(self.credits, serializer);
-    sse_encode_list_xqxkch_info(self.courses, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_filter_option(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_exam_schedule(ExamSchedule self, SseSerializer serializer) {
+  void sse_encode_list_free_classroom(
+    List<FreeClassroom> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_String(self.courseCode, serializer);
-    sse_encode_String(self.courseName, serializer);
-    sse_encode_String(self.examTime, serializer);
-    sse_encode_String(self.examLocation, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_free_classroom(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_execution_plan(ExecutionPlan self, SseSerializer serializer) {
+  void sse_encode_list_plan_semester(
+    List<PlanSemester> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_String(self.semester, serializer);
-    sse_encode_String(self.courseCode, serializer);
-    sse_encode_String(self.courseName, serializer);
-    sse_encode_String(self.department, serializer);
-    sse_encode_f_32(self.credits, serializer);
-    sse_encode_f_32(self.totalHours, serializer);
-    sse_encode_String(self.assessmentMethod, serializer);
-    sse_encode_String(self.courseType, serializer);
-    sse_encode_String(self.isExam, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_plan_semester(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_execution_plan_response(
-    ExecutionPlanResponse self,
+  void sse_encode_list_prim_u_32_loose(
+    List<int> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_execution_plan(self.plans, serializer);
-    sse_encode_list_String(self.semesters, serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(
+      self is Uint32List ? self : Uint32List.fromList(self),
+    );
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_String(item, serializer);
+      sse_encode_record_string_string(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_course_schedule(
-    List<CourseSchedule> self,
+  void sse_encode_list_school_week(
+    List<SchoolWeek> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_course_schedule(item, serializer);
+      sse_encode_school_week(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_credit_info(
-    List<CreditInfo> self,
+  void sse_encode_list_score_info(
+    List<ScoreInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_credit_info(item, serializer);
+      sse_encode_score_info(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_dekt_list(
-    List<DEKTList> self,
+  void sse_encode_list_section_time(
+    List<SectionTime> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_dekt_list(item, serializer);
+      sse_encode_section_time(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_dekt_total(
-    List<DEKTTotal> self,
+  void sse_encode_list_selectable_course(
+    List<SelectableCourse> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_dekt_total(item, serializer);
+      sse_encode_selectable_course(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_exam_schedule(
-    List<ExamSchedule> self,
+  void sse_encode_list_selection_round(
+    List<SelectionRound> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_exam_schedule(item, serializer);
+      sse_encode_selection_round(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_execution_plan(
-    List<ExecutionPlan> self,
+  void sse_encode_list_semester_gpa(
+    List<SemesterGpa> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_execution_plan(item, serializer);
+      sse_encode_semester_gpa(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_list_semester_info(
+    List<SemesterInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    for (final item in self) {
+      sse_encode_semester_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
+  void sse_encode_list_xqxkch_info(
+    List<XqxkchInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
+      sse_encode_xqxkch_info(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_score_info(
-    List<ScoreInfo> self,
+  void sse_encode_login_state(LoginState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_grade(Grade? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_grade(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_score_info(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_semester_info(
-    List<SemesterInfo> self,
+  void sse_encode_opt_box_autoadd_record_string_u_8(
+    (String, int)? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_semester_info(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_record_string_u_8(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_xqxkch_info(
-    List<XqxkchInfo> self,
+  void sse_encode_opt_box_autoadd_semester_start(
+    SemesterStart? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_xqxkch_info(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_semester_start(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
     }
   }
 
+  @protected
+  void sse_encode_plan_semester(PlanSemester self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.semester, serializer);
+    sse_encode_String(self.academicYear, serializer);
+    sse_encode_u_8(self.term, serializer);
+    sse_encode_f_32(self.credits, serializer);
+    sse_encode_f_32(self.totalHours, serializer);
+    sse_encode_list_execution_plan(self.plans, serializer);
+  }
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
@@ -2864,6 +7016,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_record_string_u_8(
+    (String, int) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_u_8(self.$2, serializer);
+  }
+
+  @protected
+  void sse_encode_school_calendar(
+    SchoolCalendar self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.semester, serializer);
+    sse_encode_String(self.startDate, serializer);
+    sse_encode_list_school_week(self.weeks, serializer);
+  }
+
+  @protected
+  void sse_encode_school_week(SchoolWeek self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.week, serializer);
+    sse_encode_String(self.monday, serializer);
+    sse_encode_list_calendar_day(self.holidays, serializer);
+    sse_encode_list_calendar_day(self.swapDays, serializer);
+  }
+
   @protected
   void sse_encode_score_info(ScoreInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2871,7 +7053,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.term, serializer);
     sse_encode_String(self.courseId, serializer);
     sse_encode_String(self.courseName, serializer);
-    sse_encode_f_32(self.score, serializer);
+    sse_encode_grade(self.score, serializer);
     sse_encode_f_32(self.credit, serializer);
     sse_encode_f_32(self.hours, serializer);
     sse_encode_f_32(self.gpa, serializer);
@@ -2885,6 +7067,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_f_32(self.creditTotal, serializer);
     sse_encode_f_32(self.gpaAverage, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.averageScore, serializer);
   }
 
   @protected
@@ -2894,25 +7077,172 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_score_summary(self.summary, serializer);
   }
 
+  @protected
+  void sse_encode_seat_watch_config(
+    SeatWatchConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_selection_category(self.category, serializer);
+    sse_encode_list_String(self.courseIds, serializer);
+    sse_encode_u_32(self.intervalMs, serializer);
+    sse_encode_u_32(self.jitterMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxRounds, serializer);
+  }
+
+  @protected
+  void sse_encode_seat_watch_event(
+    SeatWatchEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SeatWatchEvent_Polled(round: final round, courses: final courses):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_32(round, serializer);
+        sse_encode_list_selectable_course(courses, serializer);
+      case SeatWatchEvent_Attempted(
+        course: final course,
+        outcome: final outcome,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_selectable_course(course, serializer);
+        sse_encode_box_autoadd_selection_outcome(outcome, serializer);
+      case SeatWatchEvent_Error(message: final message):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(message, serializer);
+      case SeatWatchEvent_Finished(
+        selected: final selected,
+        pending: final pending,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_list_String(selected, serializer);
+        sse_encode_list_String(pending, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_section_time(SectionTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.section, serializer);
+    sse_encode_String(self.start, serializer);
+    sse_encode_String(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_selectable_course(
+    SelectableCourse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_selection_category(self.category, serializer);
+    sse_encode_String(self.courseCode, serializer);
+    sse_encode_String(self.courseName, serializer);
+    sse_encode_f_32(self.credits, serializer);
+    sse_encode_String(self.teacher, serializer);
+    sse_encode_String(self.time, serializer);
+    sse_encode_String(self.location, serializer);
+    sse_encode_String(self.campus, serializer);
+    sse_encode_u_32(self.capacity, serializer);
+    sse_encode_u_32(self.selected, serializer);
+    sse_encode_u_32(self.remaining, serializer);
+    sse_encode_String(self.conflict, serializer);
+  }
+
+  @protected
+  void sse_encode_selection_category(
+    SelectionCategory self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_selection_outcome(
+    SelectionOutcome self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_selection_result(self.result, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_selection_result(
+    SelectionResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_selection_round(
+    SelectionRound self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.semester, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.startTime, serializer);
+    sse_encode_String(self.endTime, serializer);
+    sse_encode_bool(self.canEnter, serializer);
+  }
+
+  @protected
+  void sse_encode_semester_gpa(SemesterGpa self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.term, serializer);
+    sse_encode_gpa_result(self.result, serializer);
+  }
+
   @protected
   void sse_encode_semester_info(SemesterInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.key, serializer);
     sse_encode_String(self.value, serializer);
+    sse_encode_String(self.academicYear, serializer);
+    sse_encode_u_8(self.term, serializer);
+    sse_encode_bool(self.isCurrent, serializer);
+  }
+
+  @protected
+  void sse_encode_semester_start(SemesterStart self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.semester, serializer);
+    sse_encode_String(self.startDate, serializer);
   }
 
   @protected
   void sse_encode_student_info(StudentInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.gender, serializer);
-    sse_encode_String(self.studentId, serializer);
-    sse_encode_String(self.department, serializer);
-    sse_encode_String(self.major, serializer);
-    sse_encode_String(self.className, serializer);
-    sse_encode_String(self.admissionDate, serializer);
-    sse_encode_String(self.admissionNumber, serializer);
-    sse_encode_String(self.idNumber, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.gender, serializer);
+    sse_encode_opt_String(self.studentId, serializer);
+    sse_encode_opt_String(self.department, serializer);
+    sse_encode_opt_String(self.major, serializer);
+    sse_encode_opt_String(self.className, serializer);
+    sse_encode_opt_String(self.admissionDate, serializer);
+    sse_encode_opt_String(self.admissionNumber, serializer);
+    sse_encode_opt_String(self.idNumber, serializer);
+    sse_encode_opt_String(self.birthDate, serializer);
+    sse_encode_opt_String(self.ethnicity, serializer);
+    sse_encode_opt_String(self.politicalStatus, serializer);
+    sse_encode_opt_String(self.hometown, serializer);
+    sse_encode_opt_String(self.educationLevel, serializer);
+    sse_encode_opt_String(self.lengthOfSchooling, serializer);
+    sse_encode_opt_String(self.contact, serializer);
+    sse_encode_opt_String(self.highSchool, serializer);
+  }
+
+  @protected
+  void sse_encode_student_photo(StudentPhoto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.bytes, serializer);
+    sse_encode_String(self.mimeType, serializer);
   }
 
   @protected
@@ -3079,4 +7409,27 @@ class HttpSessionImpl extends RustOpaque implements HttpSession {
 
   Future<String> getFlowExecutionKey() => RustLib.instance.api
       .crateApiSessionHttpSessionGetFlowExecutionKey(that: this);
+
+  /// 访问教务系统主页，判断服务端会话是否仍然有效
+  Future<bool> isAlive() =>
+      RustLib.instance.api.crateApiSessionHttpSessionIsAlive(that: this);
+
+  Future<LoginState> loginState() =>
+      RustLib.instance.api.crateApiSessionHttpSessionLoginState(that: this);
+
+  /// 保存全部 Cookie，JSESSIONID 等会话 Cookie 没有过期时间，也需要一并写出
+  Future<void> saveCookies({required String path}) => RustLib.instance.api
+      .crateApiSessionHttpSessionSaveCookies(that: this, path: path);
+
+  /// 保存账号密码，会话过期时用于免验证码重新登录
+  Future<void> setCredentials({
+    required String username,
+    required String vpnPassword,
+    required String oaPassword,
+  }) => RustLib.instance.api.crateApiSessionHttpSessionSetCredentials(
+    that: this,
+    username: username,
+    vpnPassword: vpnPassword,
+    oaPassword: oaPassword,
+  );
 }
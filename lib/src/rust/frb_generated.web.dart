@@ -8,14 +8,24 @@
 
 import 'api/aescbc.dart';
 import 'api/conwork.dart';
+import 'api/error.dart';
+import 'api/ics.dart';
+import 'api/jwxt/audit.dart';
+import 'api/jwxt/calendar.dart';
+import 'api/jwxt/classroom.dart';
 import 'api/jwxt/course.dart';
 import 'api/jwxt/dekt.dart';
 import 'api/jwxt/elective.dart';
 import 'api/jwxt/exam.dart';
+import 'api/jwxt/gpa.dart';
+import 'api/jwxt/grade.dart';
+import 'api/jwxt/graduation.dart';
 import 'api/jwxt/info.dart';
 import 'api/jwxt/plan.dart';
 import 'api/jwxt/score.dart';
+import 'api/jwxt/selection.dart';
 import 'api/jwxt/semester.dart';
+import 'api/seat_watcher.dart';
 import 'api/session.dart';
 import 'api/simple.dart';
 import 'dart:async';
@@ -48,6 +58,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_HttpSessionPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession;
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
   @protected
   ArcCookieStoreMutex
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
@@ -111,18 +124,92 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<SeatWatchEvent> dco_decode_StreamSink_seat_watch_event_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  ApiError dco_decode_api_error(dynamic raw);
+
+  @protected
+  AuditCourse dco_decode_audit_course(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  Endpoints dco_decode_box_autoadd_endpoints(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  GpaFilter dco_decode_box_autoadd_gpa_filter(dynamic raw);
+
+  @protected
+  Grade dco_decode_box_autoadd_grade(dynamic raw);
+
+  @protected
+  GradeScale dco_decode_box_autoadd_grade_scale(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  (String, int) dco_decode_box_autoadd_record_string_u_8(dynamic raw);
+
+  @protected
+  SeatWatchConfig dco_decode_box_autoadd_seat_watch_config(dynamic raw);
+
+  @protected
+  SelectableCourse dco_decode_box_autoadd_selectable_course(dynamic raw);
+
+  @protected
+  SelectionOutcome dco_decode_box_autoadd_selection_outcome(dynamic raw);
+
+  @protected
+  SemesterStart dco_decode_box_autoadd_semester_start(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  CalendarDay dco_decode_calendar_day(dynamic raw);
+
+  @protected
+  CalendarDayKind dco_decode_calendar_day_kind(dynamic raw);
+
+  @protected
+  ClassroomFilters dco_decode_classroom_filters(dynamic raw);
+
   @protected
   CourseSchedule dco_decode_course_schedule(dynamic raw);
 
+  @protected
+  CourseStatus dco_decode_course_status(dynamic raw);
+
+  @protected
+  CreditBucket dco_decode_credit_bucket(dynamic raw);
+
   @protected
   CreditInfo dco_decode_credit_info(dynamic raw);
 
+  @protected
+  CreditProgress dco_decode_credit_progress(dynamic raw);
+
+  @protected
+  CreditSource dco_decode_credit_source(dynamic raw);
+
+  @protected
+  CurrentWeek dco_decode_current_week(dynamic raw);
+
+  @protected
+  DegreeAudit dco_decode_degree_audit(dynamic raw);
+
   @protected
   DEKT dco_decode_dekt(dynamic raw);
 
@@ -132,12 +219,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DEKTList dco_decode_dekt_list(dynamic raw);
 
+  @protected
+  DektRequirement dco_decode_dekt_requirement(dynamic raw);
+
   @protected
   DEKTTotal dco_decode_dekt_total(dynamic raw);
 
   @protected
   ElectiveResponse dco_decode_elective_response(dynamic raw);
 
+  @protected
+  Endpoints dco_decode_endpoints(dynamic raw);
+
+  @protected
+  ExamCountdown dco_decode_exam_countdown(dynamic raw);
+
   @protected
   ExamSchedule dco_decode_exam_schedule(dynamic raw);
 
@@ -150,48 +246,162 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_f_32(dynamic raw);
 
+  @protected
+  FilterOption dco_decode_filter_option(dynamic raw);
+
+  @protected
+  FreeClassroom dco_decode_free_classroom(dynamic raw);
+
+  @protected
+  GpaFilter dco_decode_gpa_filter(dynamic raw);
+
+  @protected
+  GpaReport dco_decode_gpa_report(dynamic raw);
+
+  @protected
+  GpaResult dco_decode_gpa_result(dynamic raw);
+
+  @protected
+  GpaScheme dco_decode_gpa_scheme(dynamic raw);
+
+  @protected
+  Grade dco_decode_grade(dynamic raw);
+
+  @protected
+  GradeScale dco_decode_grade_scale(dynamic raw);
+
+  @protected
+  GraduationReport dco_decode_graduation_report(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AuditCourse> dco_decode_list_audit_course(dynamic raw);
+
+  @protected
+  List<CalendarDay> dco_decode_list_calendar_day(dynamic raw);
+
   @protected
   List<CourseSchedule> dco_decode_list_course_schedule(dynamic raw);
 
+  @protected
+  List<CreditBucket> dco_decode_list_credit_bucket(dynamic raw);
+
   @protected
   List<CreditInfo> dco_decode_list_credit_info(dynamic raw);
 
+  @protected
+  List<CreditProgress> dco_decode_list_credit_progress(dynamic raw);
+
   @protected
   List<DEKTList> dco_decode_list_dekt_list(dynamic raw);
 
+  @protected
+  List<DektRequirement> dco_decode_list_dekt_requirement(dynamic raw);
+
   @protected
   List<DEKTTotal> dco_decode_list_dekt_total(dynamic raw);
 
+  @protected
+  List<ExamCountdown> dco_decode_list_exam_countdown(dynamic raw);
+
   @protected
   List<ExamSchedule> dco_decode_list_exam_schedule(dynamic raw);
 
   @protected
   List<ExecutionPlan> dco_decode_list_execution_plan(dynamic raw);
 
+  @protected
+  List<FilterOption> dco_decode_list_filter_option(dynamic raw);
+
+  @protected
+  List<FreeClassroom> dco_decode_list_free_classroom(dynamic raw);
+
+  @protected
+  List<PlanSemester> dco_decode_list_plan_semester(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
+  @protected
+  List<SchoolWeek> dco_decode_list_school_week(dynamic raw);
+
   @protected
   List<ScoreInfo> dco_decode_list_score_info(dynamic raw);
 
+  @protected
+  List<SectionTime> dco_decode_list_section_time(dynamic raw);
+
+  @protected
+  List<SelectableCourse> dco_decode_list_selectable_course(dynamic raw);
+
+  @protected
+  List<SelectionRound> dco_decode_list_selection_round(dynamic raw);
+
+  @protected
+  List<SemesterGpa> dco_decode_list_semester_gpa(dynamic raw);
+
   @protected
   List<SemesterInfo> dco_decode_list_semester_info(dynamic raw);
 
   @protected
   List<XqxkchInfo> dco_decode_list_xqxkch_info(dynamic raw);
 
+  @protected
+  LoginState dco_decode_login_state(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  Grade? dco_decode_opt_box_autoadd_grade(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  (String, int)? dco_decode_opt_box_autoadd_record_string_u_8(dynamic raw);
+
+  @protected
+  SemesterStart? dco_decode_opt_box_autoadd_semester_start(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  PlanSemester dco_decode_plan_semester(dynamic raw);
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  (String, int) dco_decode_record_string_u_8(dynamic raw);
+
+  @protected
+  SchoolCalendar dco_decode_school_calendar(dynamic raw);
+
+  @protected
+  SchoolWeek dco_decode_school_week(dynamic raw);
+
   @protected
   ScoreInfo dco_decode_score_info(dynamic raw);
 
@@ -201,12 +411,45 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ScoreTotal dco_decode_score_total(dynamic raw);
 
+  @protected
+  SeatWatchConfig dco_decode_seat_watch_config(dynamic raw);
+
+  @protected
+  SeatWatchEvent dco_decode_seat_watch_event(dynamic raw);
+
+  @protected
+  SectionTime dco_decode_section_time(dynamic raw);
+
+  @protected
+  SelectableCourse dco_decode_selectable_course(dynamic raw);
+
+  @protected
+  SelectionCategory dco_decode_selection_category(dynamic raw);
+
+  @protected
+  SelectionOutcome dco_decode_selection_outcome(dynamic raw);
+
+  @protected
+  SelectionResult dco_decode_selection_result(dynamic raw);
+
+  @protected
+  SelectionRound dco_decode_selection_round(dynamic raw);
+
+  @protected
+  SemesterGpa dco_decode_semester_gpa(dynamic raw);
+
   @protected
   SemesterInfo dco_decode_semester_info(dynamic raw);
 
+  @protected
+  SemesterStart dco_decode_semester_start(dynamic raw);
+
   @protected
   StudentInfo dco_decode_student_info(dynamic raw);
 
+  @protected
+  StudentPhoto dco_decode_student_photo(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -222,6 +465,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   XqxkchInfo dco_decode_xqxkch_info(dynamic raw);
 
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
   @protected
   ArcCookieStoreMutex
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
@@ -288,171 +534,439 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  RustStreamSink<SeatWatchEvent> sse_decode_StreamSink_seat_watch_event_Sse(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  String sse_decode_String(SseDeserializer deserializer);
 
   @protected
-  CourseSchedule sse_decode_course_schedule(SseDeserializer deserializer);
+  ApiError sse_decode_api_error(SseDeserializer deserializer);
 
   @protected
-  CreditInfo sse_decode_credit_info(SseDeserializer deserializer);
+  AuditCourse sse_decode_audit_course(SseDeserializer deserializer);
 
   @protected
-  DEKT sse_decode_dekt(SseDeserializer deserializer);
+  bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
-  DEKTDetail sse_decode_dekt_detail(SseDeserializer deserializer);
+  Endpoints sse_decode_box_autoadd_endpoints(SseDeserializer deserializer);
 
   @protected
-  DEKTList sse_decode_dekt_list(SseDeserializer deserializer);
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
 
   @protected
-  DEKTTotal sse_decode_dekt_total(SseDeserializer deserializer);
+  GpaFilter sse_decode_box_autoadd_gpa_filter(SseDeserializer deserializer);
 
   @protected
-  ElectiveResponse sse_decode_elective_response(SseDeserializer deserializer);
+  Grade sse_decode_box_autoadd_grade(SseDeserializer deserializer);
 
   @protected
-  ExamSchedule sse_decode_exam_schedule(SseDeserializer deserializer);
+  GradeScale sse_decode_box_autoadd_grade_scale(SseDeserializer deserializer);
 
   @protected
-  ExecutionPlan sse_decode_execution_plan(SseDeserializer deserializer);
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
   @protected
-  ExecutionPlanResponse sse_decode_execution_plan_response(
+  (String, int) sse_decode_box_autoadd_record_string_u_8(
     SseDeserializer deserializer,
   );
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  SeatWatchConfig sse_decode_box_autoadd_seat_watch_config(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  SelectableCourse sse_decode_box_autoadd_selectable_course(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  SelectionOutcome sse_decode_box_autoadd_selection_outcome(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  List<CourseSchedule> sse_decode_list_course_schedule(
+  SemesterStart sse_decode_box_autoadd_semester_start(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<CreditInfo> sse_decode_list_credit_info(SseDeserializer deserializer);
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
-  List<DEKTList> sse_decode_list_dekt_list(SseDeserializer deserializer);
+  CalendarDay sse_decode_calendar_day(SseDeserializer deserializer);
 
   @protected
-  List<DEKTTotal> sse_decode_list_dekt_total(SseDeserializer deserializer);
+  CalendarDayKind sse_decode_calendar_day_kind(SseDeserializer deserializer);
 
   @protected
-  List<ExamSchedule> sse_decode_list_exam_schedule(
-    SseDeserializer deserializer,
-  );
+  ClassroomFilters sse_decode_classroom_filters(SseDeserializer deserializer);
 
   @protected
-  List<ExecutionPlan> sse_decode_list_execution_plan(
-    SseDeserializer deserializer,
-  );
+  CourseSchedule sse_decode_course_schedule(SseDeserializer deserializer);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  CourseStatus sse_decode_course_status(SseDeserializer deserializer);
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  );
+  CreditBucket sse_decode_credit_bucket(SseDeserializer deserializer);
 
   @protected
-  List<ScoreInfo> sse_decode_list_score_info(SseDeserializer deserializer);
+  CreditInfo sse_decode_credit_info(SseDeserializer deserializer);
 
   @protected
-  List<SemesterInfo> sse_decode_list_semester_info(
-    SseDeserializer deserializer,
-  );
+  CreditProgress sse_decode_credit_progress(SseDeserializer deserializer);
 
   @protected
-  List<XqxkchInfo> sse_decode_list_xqxkch_info(SseDeserializer deserializer);
+  CreditSource sse_decode_credit_source(SseDeserializer deserializer);
 
   @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  );
+  CurrentWeek sse_decode_current_week(SseDeserializer deserializer);
 
   @protected
-  ScoreInfo sse_decode_score_info(SseDeserializer deserializer);
+  DegreeAudit sse_decode_degree_audit(SseDeserializer deserializer);
 
   @protected
-  ScoreSummary sse_decode_score_summary(SseDeserializer deserializer);
+  DEKT sse_decode_dekt(SseDeserializer deserializer);
 
   @protected
-  ScoreTotal sse_decode_score_total(SseDeserializer deserializer);
+  DEKTDetail sse_decode_dekt_detail(SseDeserializer deserializer);
 
   @protected
-  SemesterInfo sse_decode_semester_info(SseDeserializer deserializer);
+  DEKTList sse_decode_dekt_list(SseDeserializer deserializer);
 
   @protected
-  StudentInfo sse_decode_student_info(SseDeserializer deserializer);
+  DektRequirement sse_decode_dekt_requirement(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  DEKTTotal sse_decode_dekt_total(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  ElectiveResponse sse_decode_elective_response(SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  Endpoints sse_decode_endpoints(SseDeserializer deserializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  ExamCountdown sse_decode_exam_countdown(SseDeserializer deserializer);
 
   @protected
-  XqxkchInfo sse_decode_xqxkch_info(SseDeserializer deserializer);
+  ExamSchedule sse_decode_exam_schedule(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
-    ArcCookieStoreMutex self,
-    SseSerializer serializer,
-  );
+  ExecutionPlan sse_decode_execution_plan(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
-    Client self,
-    SseSerializer serializer,
+  ExecutionPlanResponse sse_decode_execution_plan_response(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
-    CryptoError self,
-    SseSerializer serializer,
-  );
+  double sse_decode_f_32(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    HttpSession self,
-    SseSerializer serializer,
-  );
+  FilterOption sse_decode_filter_option(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    HttpSession self,
-    SseSerializer serializer,
-  );
+  FreeClassroom sse_decode_free_classroom(SseDeserializer deserializer);
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
-    HttpSession self,
-    SseSerializer serializer,
-  );
+  GpaFilter sse_decode_gpa_filter(SseDeserializer deserializer);
+
+  @protected
+  GpaReport sse_decode_gpa_report(SseDeserializer deserializer);
+
+  @protected
+  GpaResult sse_decode_gpa_result(SseDeserializer deserializer);
+
+  @protected
+  GpaScheme sse_decode_gpa_scheme(SseDeserializer deserializer);
+
+  @protected
+  Grade sse_decode_grade(SseDeserializer deserializer);
+
+  @protected
+  GradeScale sse_decode_grade_scale(SseDeserializer deserializer);
+
+  @protected
+  GraduationReport sse_decode_graduation_report(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AuditCourse> sse_decode_list_audit_course(SseDeserializer deserializer);
+
+  @protected
+  List<CalendarDay> sse_decode_list_calendar_day(SseDeserializer deserializer);
+
+  @protected
+  List<CourseSchedule> sse_decode_list_course_schedule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CreditBucket> sse_decode_list_credit_bucket(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CreditInfo> sse_decode_list_credit_info(SseDeserializer deserializer);
+
+  @protected
+  List<CreditProgress> sse_decode_list_credit_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DEKTList> sse_decode_list_dekt_list(SseDeserializer deserializer);
+
+  @protected
+  List<DektRequirement> sse_decode_list_dekt_requirement(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DEKTTotal> sse_decode_list_dekt_total(SseDeserializer deserializer);
+
+  @protected
+  List<ExamCountdown> sse_decode_list_exam_countdown(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ExamSchedule> sse_decode_list_exam_schedule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ExecutionPlan> sse_decode_list_execution_plan(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FilterOption> sse_decode_list_filter_option(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FreeClassroom> sse_decode_list_free_classroom(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PlanSemester> sse_decode_list_plan_semester(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SchoolWeek> sse_decode_list_school_week(SseDeserializer deserializer);
+
+  @protected
+  List<ScoreInfo> sse_decode_list_score_info(SseDeserializer deserializer);
+
+  @protected
+  List<SectionTime> sse_decode_list_section_time(SseDeserializer deserializer);
+
+  @protected
+  List<SelectableCourse> sse_decode_list_selectable_course(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SelectionRound> sse_decode_list_selection_round(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SemesterGpa> sse_decode_list_semester_gpa(SseDeserializer deserializer);
+
+  @protected
+  List<SemesterInfo> sse_decode_list_semester_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<XqxkchInfo> sse_decode_list_xqxkch_info(SseDeserializer deserializer);
+
+  @protected
+  LoginState sse_decode_login_state(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  Grade? sse_decode_opt_box_autoadd_grade(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  (String, int)? sse_decode_opt_box_autoadd_record_string_u_8(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SemesterStart? sse_decode_opt_box_autoadd_semester_start(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  PlanSemester sse_decode_plan_semester(SseDeserializer deserializer);
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (String, int) sse_decode_record_string_u_8(SseDeserializer deserializer);
+
+  @protected
+  SchoolCalendar sse_decode_school_calendar(SseDeserializer deserializer);
+
+  @protected
+  SchoolWeek sse_decode_school_week(SseDeserializer deserializer);
+
+  @protected
+  ScoreInfo sse_decode_score_info(SseDeserializer deserializer);
+
+  @protected
+  ScoreSummary sse_decode_score_summary(SseDeserializer deserializer);
+
+  @protected
+  ScoreTotal sse_decode_score_total(SseDeserializer deserializer);
+
+  @protected
+  SeatWatchConfig sse_decode_seat_watch_config(SseDeserializer deserializer);
+
+  @protected
+  SeatWatchEvent sse_decode_seat_watch_event(SseDeserializer deserializer);
+
+  @protected
+  SectionTime sse_decode_section_time(SseDeserializer deserializer);
+
+  @protected
+  SelectableCourse sse_decode_selectable_course(SseDeserializer deserializer);
+
+  @protected
+  SelectionCategory sse_decode_selection_category(SseDeserializer deserializer);
+
+  @protected
+  SelectionOutcome sse_decode_selection_outcome(SseDeserializer deserializer);
+
+  @protected
+  SelectionResult sse_decode_selection_result(SseDeserializer deserializer);
+
+  @protected
+  SelectionRound sse_decode_selection_round(SseDeserializer deserializer);
+
+  @protected
+  SemesterGpa sse_decode_semester_gpa(SseDeserializer deserializer);
+
+  @protected
+  SemesterInfo sse_decode_semester_info(SseDeserializer deserializer);
+
+  @protected
+  SemesterStart sse_decode_semester_start(SseDeserializer deserializer);
+
+  @protected
+  StudentInfo sse_decode_student_info(SseDeserializer deserializer);
+
+  @protected
+  StudentPhoto sse_decode_student_photo(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  XqxkchInfo sse_decode_xqxkch_info(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcCookieStoreMutex(
+    ArcCookieStoreMutex self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClient(
+    Client self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCryptoError(
+    CryptoError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    HttpSession self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    HttpSession self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttpSession(
+    HttpSession self,
+    SseSerializer serializer,
+  );
 
   @protected
   void sse_encode_Map_String_String_None(
@@ -488,21 +1002,132 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_seat_watch_event_Sse(
+    RustStreamSink<SeatWatchEvent> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_api_error(ApiError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audit_course(AuditCourse self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_endpoints(
+    Endpoints self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_gpa_filter(
+    GpaFilter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_grade(Grade self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_grade_scale(
+    GradeScale self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_record_string_u_8(
+    (String, int) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_seat_watch_config(
+    SeatWatchConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_selectable_course(
+    SelectableCourse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_selection_outcome(
+    SelectionOutcome self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_semester_start(
+    SemesterStart self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_calendar_day(CalendarDay self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_calendar_day_kind(
+    CalendarDayKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_classroom_filters(
+    ClassroomFilters self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_course_schedule(
     CourseSchedule self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_course_status(CourseStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_credit_bucket(CreditBucket self, SseSerializer serializer);
+
   @protected
   void sse_encode_credit_info(CreditInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_credit_progress(
+    CreditProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_credit_source(CreditSource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_current_week(CurrentWeek self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_degree_audit(DegreeAudit self, SseSerializer serializer);
+
   @protected
   void sse_encode_dekt(DEKT self, SseSerializer serializer);
 
@@ -512,6 +1137,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_dekt_list(DEKTList self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_dekt_requirement(
+    DektRequirement self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_dekt_total(DEKTTotal self, SseSerializer serializer);
 
@@ -521,6 +1152,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_endpoints(Endpoints self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_exam_countdown(ExamCountdown self, SseSerializer serializer);
+
   @protected
   void sse_encode_exam_schedule(ExamSchedule self, SseSerializer serializer);
 
@@ -536,33 +1173,102 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_filter_option(FilterOption self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_free_classroom(FreeClassroom self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_gpa_filter(GpaFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_gpa_report(GpaReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_gpa_result(GpaResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_gpa_scheme(GpaScheme self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_grade(Grade self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_grade_scale(GradeScale self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_graduation_report(
+    GraduationReport self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_audit_course(
+    List<AuditCourse> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_calendar_day(
+    List<CalendarDay> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_course_schedule(
     List<CourseSchedule> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_credit_bucket(
+    List<CreditBucket> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_credit_info(
     List<CreditInfo> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_credit_progress(
+    List<CreditProgress> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_dekt_list(List<DEKTList> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_dekt_requirement(
+    List<DektRequirement> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_dekt_total(
     List<DEKTTotal> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_exam_countdown(
+    List<ExamCountdown> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_exam_schedule(
     List<ExamSchedule> self,
@@ -575,6 +1281,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_filter_option(
+    List<FilterOption> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_free_classroom(
+    List<FreeClassroom> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_plan_semester(
+    List<PlanSemester> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_32_loose(
+    List<int> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -587,12 +1323,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_school_week(
+    List<SchoolWeek> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_score_info(
     List<ScoreInfo> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_section_time(
+    List<SectionTime> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_selectable_course(
+    List<SelectableCourse> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_selection_round(
+    List<SelectionRound> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_semester_gpa(
+    List<SemesterGpa> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_semester_info(
     List<SemesterInfo> self,
@@ -605,12 +1371,63 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_login_state(LoginState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_grade(Grade? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_record_string_u_8(
+    (String, int)? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_semester_start(
+    SemesterStart? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_plan_semester(PlanSemester self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_record_string_u_8(
+    (String, int) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_school_calendar(
+    SchoolCalendar self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_school_week(SchoolWeek self, SseSerializer serializer);
+
   @protected
   void sse_encode_score_info(ScoreInfo self, SseSerializer serializer);
 
@@ -620,12 +1437,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_score_total(ScoreTotal self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_seat_watch_config(
+    SeatWatchConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_seat_watch_event(
+    SeatWatchEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_section_time(SectionTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_selectable_course(
+    SelectableCourse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_selection_category(
+    SelectionCategory self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_selection_outcome(
+    SelectionOutcome self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_selection_result(
+    SelectionResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_selection_round(
+    SelectionRound self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_semester_gpa(SemesterGpa self, SseSerializer serializer);
+
   @protected
   void sse_encode_semester_info(SemesterInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_semester_start(SemesterStart self, SseSerializer serializer);
+
   @protected
   void sse_encode_student_info(StudentInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_student_photo(StudentPhoto self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
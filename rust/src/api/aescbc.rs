@@ -1,7 +1,7 @@
-use aes::Aes128;
 use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::Aes128;
 
-use base64::{Engine, engine::general_purpose};
+use base64::{engine::general_purpose, Engine};
 
 const BLOCK_SIZE: usize = 16;
 const KEY: &[u8; 16] = b"UH1eN7apoK9lY5VB";
@@ -47,13 +47,13 @@ impl From<base64::DecodeError> for CryptoError {
 pub fn aes_cbc_encrypt(plain_text: &str) -> Result<String, CryptoError> {
     let mut data = plain_text.as_bytes().to_vec();
     let padding_len = BLOCK_SIZE - (data.len() % BLOCK_SIZE);
-    data.extend(std::iter::repeat(padding_len as u8).take(padding_len));
+    data.extend(std::iter::repeat_n(padding_len as u8, padding_len));
 
     let cipher = Aes128::new(KEY.into());
     let mut ciphertext = Vec::with_capacity(data.len());
-    let mut prev_block = IV.clone();
+    let mut prev_block = *IV;
 
-    for block in data.chunks(BLOCK_SIZE as usize) {
+    for block in data.chunks(BLOCK_SIZE) {
         let mut chunk = [0u8; BLOCK_SIZE];
         for i in 0..BLOCK_SIZE {
             chunk[i] = block[i] ^ prev_block[i];
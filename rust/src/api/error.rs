@@ -0,0 +1,97 @@
+use scraper::error::SelectorErrorKind;
+
+use crate::api::aescbc::CryptoError;
+use flutter_rust_bridge::frb;
+
+#[derive(Debug, Clone)]
+pub enum ApiError {
+    /// 网络请求失败，`cause` 保留 reqwest 的原始错误
+    Network { cause: String },
+    /// 教务系统会话已过期，需要重新登录
+    SessionExpired,
+    /// 账号或密码错误
+    BadCredentials { message: String },
+    /// 验证码错误
+    BadCaptcha,
+    /// 页面结构发生变化，`selector` 未能匹配到元素
+    ParseLayoutChanged { page: String, selector: String },
+    /// 会话尚未初始化
+    NotInitialized,
+    /// 尚未登录，需要先获取验证码并登录
+    NotLoggedIn,
+    /// 调用参数不合法，如日期格式错误
+    InvalidArgument { message: String },
+    /// 本地文件读写失败
+    Io { cause: String },
+    /// 其他内部错误，`cause` 保留原始错误
+    Internal { cause: String },
+}
+
+impl ApiError {
+    #[frb(ignore)]
+    pub fn layout(page: &str, selector: &str) -> Self {
+        Self::ParseLayoutChanged {
+            page: page.to_string(),
+            selector: selector.to_string(),
+        }
+    }
+
+    /// 表格缺少按表头文字查找的列
+    #[frb(ignore)]
+    pub fn missing_column(page: &str, header: &str) -> Self {
+        Self::layout(page, &format!("表头「{}」", header))
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Network { cause } => write!(f, "网络请求失败: {}", cause),
+            Self::SessionExpired => write!(f, "登录已过期，请重新登录"),
+            Self::BadCredentials { message } => write!(f, "账号或密码错误: {}", message),
+            Self::BadCaptcha => write!(f, "验证码错误"),
+            Self::ParseLayoutChanged { page, selector } => {
+                write!(f, "页面 {} 结构已变化，未找到 {}", page, selector)
+            }
+            Self::NotInitialized => write!(f, "SESSION 未初始化"),
+            Self::NotLoggedIn => write!(f, "尚未登录，请先登录"),
+            Self::InvalidArgument { message } => write!(f, "参数错误: {}", message),
+            Self::Io { cause } => write!(f, "文件读写失败: {}", cause),
+            Self::Internal { cause } => write!(f, "内部错误: {}", cause),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<reqwest::Error> for ApiError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Network {
+            cause: err.to_string(),
+        }
+    }
+}
+
+impl From<std::io::Error> for ApiError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io {
+            cause: err.to_string(),
+        }
+    }
+}
+
+impl From<SelectorErrorKind<'_>> for ApiError {
+    fn from(err: SelectorErrorKind<'_>) -> Self {
+        Self::Internal {
+            cause: err.to_string(),
+        }
+    }
+}
+
+impl From<CryptoError> for ApiError {
+    fn from(err: CryptoError) -> Self {
+        Self::Internal {
+            cause: err.to_string(),
+        }
+    }
+}
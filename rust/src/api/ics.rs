@@ -0,0 +1,267 @@
+//! 生成 RFC 5545 iCalendar 文件，供手机与电脑日历导入
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use flutter_rust_bridge::frb;
+use serde::Serialize;
+
+use crate::api::{
+    error::ApiError,
+    jwxt::{course::CourseSchedule, exam::ExamSchedule},
+};
+
+/// 一个大节的上下课时间，`section` 对应 `CourseSchedule.section`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionTime {
+    pub section: usize,
+    pub start: String, // "08:00"
+    pub end: String,   // "09:40"
+}
+
+/// 一个日历事件，时间均为北京时间
+#[frb(ignore)]
+pub struct IcsEvent {
+    pub uid: String,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub summary: String,
+    pub location: String,
+    pub description: String,
+    /// 提前多少分钟提醒，每项生成一个 VALARM
+    pub alarms: Vec<i64>,
+}
+
+const PRODID: &str = "-//hbfu_alex//StudySee//ZH";
+const TZID: &str = "Asia/Shanghai";
+
+/// 转义 TEXT 类型的值（RFC 5545 3.3.11）
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 按 75 字节折行（RFC 5545 3.1），不拆开多字节字符
+fn push_folded(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+fn format_local(time: &NaiveDateTime) -> String {
+    time.format("%Y%m%dT%H%M%S").to_string()
+}
+
+/// 生成完整的 VCALENDAR，`dtstamp` 为生成时间
+#[frb(ignore)]
+pub fn write_calendar(name: &str, events: &[IcsEvent], dtstamp: DateTime<Utc>) -> String {
+    let dtstamp = dtstamp.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+        format!("X-WR-TIMEZONE:{}", TZID),
+        "BEGIN:VTIMEZONE".to_string(),
+        format!("TZID:{}", TZID),
+        "BEGIN:STANDARD".to_string(),
+        "DTSTART:19700101T000000".to_string(),
+        "TZOFFSETFROM:+0800".to_string(),
+        "TZOFFSETTO:+0800".to_string(),
+        "TZNAME:CST".to_string(),
+        "END:STANDARD".to_string(),
+        "END:VTIMEZONE".to_string(),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{}", dtstamp));
+        lines.push(format!(
+            "DTSTART;TZID={}:{}",
+            TZID,
+            format_local(&event.start)
+        ));
+        lines.push(format!("DTEND;TZID={}:{}", TZID, format_local(&event.end)));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if !event.location.is_empty() {
+            lines.push(format!("LOCATION:{}", escape_text(&event.location)));
+        }
+        if !event.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape_text(&event.description)));
+        }
+        for minutes in &event.alarms {
+            lines.push("BEGIN:VALARM".to_string());
+            lines.push("ACTION:DISPLAY".to_string());
+            lines.push(format!("DESCRIPTION:{}", escape_text(&event.summary)));
+            lines.push(format!("TRIGGER:-PT{}M", minutes));
+            lines.push("END:VALARM".to_string());
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut out = String::new();
+    for line in &lines {
+        push_folded(&mut out, line);
+    }
+    out
+}
+
+/// FNV-1a 64 位哈希，结果在不同版本、不同设备上保持一致，用于生成稳定的 UID
+pub(crate) fn stable_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[frb(ignore)]
+pub fn parse_date(text: &str) -> Result<NaiveDate, ApiError> {
+    NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").map_err(|_| ApiError::InvalidArgument {
+        message: format!("日期格式应为 YYYY-MM-DD: {}", text),
+    })
+}
+
+/// 当前北京时间，与设备所在时区无关
+#[frb(ignore)]
+pub fn beijing_now() -> NaiveDateTime {
+    (Utc::now() + Duration::hours(8)).naive_utc()
+}
+
+fn parse_time(text: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(text.trim(), "%H:%M").ok()
+}
+
+/// 课程的上下课时间，优先使用 `section_times`，否则取 `time_range` 的首尾时间
+fn course_times(
+    course: &CourseSchedule,
+    section_times: &[SectionTime],
+) -> Option<(NaiveTime, NaiveTime)> {
+    if let Some(time) = section_times.iter().find(|t| t.section == course.section) {
+        return Some((parse_time(&time.start)?, parse_time(&time.end)?));
+    }
+    let times: Vec<&str> = course
+        .time_range
+        .split(['~', '-'])
+        .filter(|t| !t.is_empty())
+        .collect();
+    Some((parse_time(times.first()?)?, parse_time(times.last()?)?))
+}
+
+fn weekday_offset(day_of_week: &str) -> Option<i64> {
+    [
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+        "星期日",
+    ]
+    .iter()
+    .position(|day| *day == day_of_week)
+    .map(|index| index as i64)
+}
+
+/// 把课表展开成每次上课一个事件。`first_monday` 为第一周的星期一，
+/// 无法确定时间或星期的课程会被跳过
+#[frb(ignore)]
+pub fn course_events(
+    courses: &[CourseSchedule],
+    semester: &str,
+    first_monday: NaiveDate,
+    section_times: &[SectionTime],
+) -> Vec<IcsEvent> {
+    let mut events = Vec::new();
+    for course in courses {
+        let (Some((start, end)), Some(offset)) = (
+            course_times(course, section_times),
+            weekday_offset(&course.day_of_week),
+        ) else {
+            continue;
+        };
+
+        let course_hash = stable_hash(&format!("{}|{}", semester, course.id));
+        let mut description = Vec::new();
+        if course.teacher != "未提供" {
+            description.push(format!("教师：{}", course.teacher));
+        }
+        description.push(format!("周次：{}", course.weeks));
+        if course.duration != "未提供" {
+            description.push(format!("节次：{}", course.duration));
+        }
+        let description = description.join("\n");
+
+        for week in &course.week_list {
+            let date = first_monday + Duration::days((*week as i64 - 1) * 7 + offset);
+            events.push(IcsEvent {
+                uid: format!("{:016x}-w{:02}@hbfu-alex", course_hash, week),
+                start: date.and_time(start),
+                end: date.and_time(end),
+                summary: course.name.clone(),
+                location: course.classroom.clone(),
+                description: description.clone(),
+                alarms: Vec::new(),
+            });
+        }
+    }
+    events.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.uid.cmp(&b.uid)));
+    events
+}
+
+/// 每场考试一个事件，`alarms` 为提前提醒的分钟数。UID 由学期与课程编号确定，
+/// 考试时间调整后重新导入会更新原有日程。时间未安排的考试会被跳过
+#[frb(ignore)]
+pub fn exam_events(exams: &[ExamSchedule], semester: &str, alarms: &[i64]) -> Vec<IcsEvent> {
+    let mut events: Vec<_> = exams
+        .iter()
+        .filter_map(|exam| {
+            let (start, end) = exam.time_span()?;
+            let key = format!(
+                "{}|exam|{}|{}",
+                semester, exam.course_code, exam.course_name
+            );
+            let mut description = vec![
+                format!("课程编号：{}", exam.course_code),
+                format!("考试时间：{}", exam.exam_time),
+            ];
+            for (label, value) in [
+                ("座位号", &exam.seat_number),
+                ("考试方式", &exam.exam_method),
+            ] {
+                if !value.is_empty() {
+                    description.push(format!("{}：{}", label, value));
+                }
+            }
+            Some(IcsEvent {
+                uid: format!("{:016x}-exam@hbfu-alex", stable_hash(&key)),
+                start,
+                end,
+                summary: format!("考试：{}", exam.course_name),
+                location: exam.exam_location.clone(),
+                description: description.join("\n"),
+                alarms: alarms.to_vec(),
+            })
+        })
+        .collect();
+    events.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.uid.cmp(&b.uid)));
+    events
+}
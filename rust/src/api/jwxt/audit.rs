@@ -0,0 +1,167 @@
+//! 培养方案完成情况：按课程编号把培养方案与成绩对应起来
+
+use crate::api::jwxt::{
+    grade::Grade,
+    plan::ExecutionPlan,
+    score::{effective_scores, ScoreInfo},
+};
+use flutter_rust_bridge::frb;
+use serde::Serialize;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashSet},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CourseStatus {
+    /// 已取得学分，免修视为通过
+    Passed,
+    /// 有成绩但未通过
+    Failed,
+    /// 本学期开设，尚未出成绩
+    InProgress,
+    /// 尚未修读
+    NotTaken,
+}
+
+/// 培养方案中的一门课程及其完成情况
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditCourse {
+    pub course_code: String,
+    pub course_name: String,
+    pub semester: String,    // 培养方案中的开课学期
+    pub course_type: String, // 课程属性，如 "必修"
+    pub credits: f32,
+    pub status: CourseStatus,
+    pub best_score: Option<Grade>, // 多次考试中最高的一次
+    pub attempts: u32,             // 考试次数，含补考与重修
+}
+
+/// 一组课程的学分完成情况，`key` 为课程属性或开课学期
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreditProgress {
+    pub key: String,
+    pub earned: f32,
+    pub required: f32,
+    pub passed_count: u32,
+    pub course_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DegreeAudit {
+    pub courses: Vec<AuditCourse>,
+    pub by_course_type: Vec<CreditProgress>,
+    pub by_semester: Vec<CreditProgress>, // 按学期先后排列
+    pub earned: f32,                      // 培养方案内已取得的学分
+    pub required: f32,                    // 培养方案总学分
+    pub outside_plan: Vec<ScoreInfo>,     // 培养方案以外修读的课程，每门只保留最高一次
+    pub outside_plan_earned: f32,         // 培养方案以外已取得的学分
+}
+
+fn course_status(scores: &[&ScoreInfo], semester: &str, current_semester: &str) -> CourseStatus {
+    if scores.iter().any(|score| score.score.is_passed()) {
+        CourseStatus::Passed
+    } else if !scores.is_empty() {
+        CourseStatus::Failed
+    } else if !current_semester.is_empty() && semester == current_semester {
+        CourseStatus::InProgress
+    } else {
+        CourseStatus::NotTaken
+    }
+}
+
+fn progress(groups: BTreeMap<String, Vec<&AuditCourse>>) -> Vec<CreditProgress> {
+    groups
+        .into_iter()
+        .map(|(key, courses)| {
+            let passed: Vec<_> = courses
+                .iter()
+                .filter(|course| course.status == CourseStatus::Passed)
+                .collect();
+            CreditProgress {
+                key,
+                earned: passed.iter().map(|course| course.credits).sum(),
+                required: courses.iter().map(|course| course.credits).sum(),
+                passed_count: passed.len() as u32,
+                course_count: courses.len() as u32,
+            }
+        })
+        .collect()
+}
+
+/// 按课程编号对应培养方案与成绩。`current_semester` 为当前学期，如 "2024-2025-1"，
+/// 该学期开设且还没有成绩的课程记为 `InProgress`，为空时不区分
+#[frb(ignore)]
+pub fn degree_audit(
+    plans: &[ExecutionPlan],
+    scores: &[ScoreInfo],
+    current_semester: &str,
+) -> DegreeAudit {
+    let courses: Vec<AuditCourse> = plans
+        .iter()
+        .map(|plan| {
+            let attempts: Vec<&ScoreInfo> = scores
+                .iter()
+                .filter(|score| score.course_id == plan.course_code)
+                .collect();
+            let best_score = attempts
+                .iter()
+                .max_by(|a, b| {
+                    a.score
+                        .value
+                        .partial_cmp(&b.score.value)
+                        .unwrap_or(Ordering::Equal)
+                })
+                .map(|score| score.score.clone());
+            AuditCourse {
+                course_code: plan.course_code.clone(),
+                course_name: plan.course_name.clone(),
+                semester: plan.semester.clone(),
+                course_type: plan.course_type.clone(),
+                credits: plan.credits,
+                status: course_status(&attempts, &plan.semester, current_semester),
+                best_score,
+                attempts: attempts.len() as u32,
+            }
+        })
+        .collect();
+
+    let mut by_course_type: BTreeMap<String, Vec<&AuditCourse>> = BTreeMap::new();
+    let mut by_semester: BTreeMap<String, Vec<&AuditCourse>> = BTreeMap::new();
+    for course in &courses {
+        by_course_type
+            .entry(course.course_type.clone())
+            .or_default()
+            .push(course);
+        by_semester
+            .entry(course.semester.clone())
+            .or_default()
+            .push(course);
+    }
+    let by_course_type = progress(by_course_type);
+    let by_semester = progress(by_semester);
+
+    let planned: HashSet<&str> = plans.iter().map(|plan| plan.course_code.as_str()).collect();
+    let outside_plan: Vec<ScoreInfo> = effective_scores(scores)
+        .into_iter()
+        .filter(|score| !planned.contains(score.course_id.as_str()))
+        .cloned()
+        .collect();
+
+    DegreeAudit {
+        earned: by_course_type.iter().map(|group| group.earned).sum(),
+        required: by_course_type.iter().map(|group| group.required).sum(),
+        outside_plan_earned: outside_plan
+            .iter()
+            .filter(|score| score.score.is_passed())
+            .map(|score| score.credit)
+            .sum(),
+        courses,
+        by_course_type,
+        by_semester,
+        outside_plan,
+    }
+}
@@ -0,0 +1,188 @@
+//! 教学周历（jxzl_query）：每周的日期以及节假日、调休安排
+
+use crate::api::error::ApiError;
+use chrono::{Datelike, Duration, NaiveDate};
+use flutter_rust_bridge::frb;
+use regex::Regex;
+use scraper::{Html, Selector};
+use serde::Serialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CalendarDayKind {
+    /// 放假，不上课
+    Holiday,
+    /// 调休上课的周末
+    Workday,
+}
+
+/// 校历中有标注的一天
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarDay {
+    pub date: String, // "2025-04-04"
+    pub weekday: u32, // 1 为周一，7 为周日
+    pub kind: CalendarDayKind,
+    pub note: String, // 校历上的标注，如 "清明节"、"调休上课"
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchoolWeek {
+    pub week: u32,
+    pub monday: String,              // 本周星期一 "2025-02-24"
+    pub holidays: Vec<CalendarDay>,  // 本周放假的日期
+    pub swap_days: Vec<CalendarDay>, // 本周调休上课的日期
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchoolCalendar {
+    pub semester: String,
+    pub start_date: String, // 第一周星期一
+    pub weeks: Vec<SchoolWeek>,
+}
+
+impl SchoolCalendar {
+    /// 所有放假的日期，用于展开课程时跳过
+    #[frb(ignore)]
+    pub fn holiday_dates(&self) -> HashSet<NaiveDate> {
+        self.weeks
+            .iter()
+            .flat_map(|week| &week.holidays)
+            .filter_map(|day| NaiveDate::parse_from_str(&day.date, "%Y-%m-%d").ok())
+            .collect()
+    }
+}
+
+/// 标注中含有这些字样的日期一定放假，如 "劳动节放假调休" 虽然含有 "调休" 也是放假
+const HOLIDAY_KEYWORDS: &[&str] = &["放假", "休息"];
+/// 调休上课的标注。只有 "调休" 两个字不能说明是上课还是放假，不作为依据
+const WORKDAY_KEYWORDS: &[&str] = &["调休上课", "调休上班", "补课", "补班", "上班"];
+/// 法定节假日的名称，校历上常只写节日名称而不写 "放假"
+const FESTIVALS: &[&str] = &[
+    "元旦",
+    "除夕",
+    "春节",
+    "清明",
+    "劳动节",
+    "五一",
+    "端午",
+    "中秋",
+    "国庆",
+];
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// 先看放假字样，再看调休上课字样，最后看节日名称。其他标注（如 "开学典礼"、"运动会"）
+/// 不影响上课，返回 None
+fn calendar_day(date: NaiveDate, note: &str) -> Option<CalendarDay> {
+    let contains_any = |keywords: &[&str]| keywords.iter().any(|keyword| note.contains(keyword));
+    let kind = if contains_any(HOLIDAY_KEYWORDS) {
+        CalendarDayKind::Holiday
+    } else if contains_any(WORKDAY_KEYWORDS) {
+        CalendarDayKind::Workday
+    } else if contains_any(FESTIVALS) {
+        CalendarDayKind::Holiday
+    } else {
+        return None;
+    };
+    Some(CalendarDay {
+        date: format_date(date),
+        weekday: date.weekday().number_from_monday(),
+        kind,
+        note: note.to_string(),
+    })
+}
+
+/// 解析校历页面。学期取下拉框中选中的一项；每个日期单元格的 title 为 "2025年04月04日 清明节"，
+/// 日期后的文字为标注，title 中没有标注时取单元格中日期数字以外的文字
+pub fn parse_school_calendar(html: &str) -> Result<SchoolCalendar, ApiError> {
+    let document = Html::parse_document(html);
+    let option_selector = Selector::parse("#xnxq01id option")?;
+    let row_selector = Selector::parse("#kbtable tr")?;
+    let cell_selector = Selector::parse("td")?;
+    let date_re = Regex::new(r"(\d{4})年(\d{1,2})月(\d{1,2})日\s*(.*)").unwrap();
+
+    let options: Vec<_> = document.select(&option_selector).collect();
+    let semester = options
+        .iter()
+        .find(|option| option.value().attr("selected").is_some())
+        .or(options.first())
+        .and_then(|option| option.value().attr("value"))
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| ApiError::layout("jxzl_query", "#xnxq01id option"))?;
+
+    let mut weeks: Vec<SchoolWeek> = Vec::new();
+    for row in document.select(&row_selector) {
+        let mut week_number = None;
+        let mut days = Vec::new();
+        for cell in row.select(&cell_selector) {
+            let Some(caps) = cell
+                .value()
+                .attr("title")
+                .and_then(|title| date_re.captures(title.trim()))
+            else {
+                // 日期之前的单元格为周次
+                if days.is_empty() {
+                    week_number = cell.text().collect::<String>().trim().parse::<u32>().ok();
+                }
+                continue;
+            };
+            let Some(date) = NaiveDate::from_ymd_opt(
+                caps[1].parse().unwrap_or_default(),
+                caps[2].parse().unwrap_or_default(),
+                caps[3].parse().unwrap_or_default(),
+            ) else {
+                continue;
+            };
+            let mut note = caps[4].trim().to_string();
+            if note.is_empty() {
+                note = cell
+                    .text()
+                    .collect::<String>()
+                    .trim()
+                    .trim_start_matches(|c: char| c.is_ascii_digit())
+                    .trim()
+                    .to_string();
+            }
+            days.push((date, note));
+        }
+
+        let Some((first, _)) = days.first() else {
+            continue;
+        };
+        let monday = *first - Duration::days(first.weekday().num_days_from_monday() as i64);
+        let mut week = SchoolWeek {
+            week: week_number.unwrap_or(weeks.len() as u32 + 1),
+            monday: format_date(monday),
+            holidays: Vec::new(),
+            swap_days: Vec::new(),
+        };
+        for (date, note) in days.iter().filter(|(_, note)| !note.is_empty()) {
+            let Some(day) = calendar_day(*date, note) else {
+                continue;
+            };
+            match day.kind {
+                CalendarDayKind::Holiday => week.holidays.push(day),
+                CalendarDayKind::Workday => week.swap_days.push(day),
+            }
+        }
+        weeks.push(week);
+    }
+
+    let start_date = weeks
+        .iter()
+        .map(|week| week.monday.clone())
+        .min()
+        .ok_or_else(|| ApiError::layout("jxzl_query", "#kbtable td[title]"))?;
+
+    Ok(SchoolCalendar {
+        semester,
+        start_date,
+        weeks,
+    })
+}
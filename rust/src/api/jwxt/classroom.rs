@@ -0,0 +1,107 @@
+//! 空闲教室：查询条件页（jsjy_query）中的校区、教学楼选项，以及教室使用情况（jsjy_query2）
+
+use crate::api::{error::ApiError, jwxt::table::Table};
+use regex::Regex;
+use scraper::{Html, Selector};
+use serde::Serialize;
+
+/// 下拉框中的一个选项
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilterOption {
+    pub key: String,   // 显示的名称，如 "公共教学楼A"
+    pub value: String, // 提交的编号，如 "0101"
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassroomFilters {
+    pub campuses: Vec<FilterOption>,
+    pub buildings: Vec<FilterOption>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FreeClassroom {
+    pub name: String, // 教室名称，如 "A101"
+    pub campus: String,
+    pub building: String,
+    pub capacity: u32,     // 座位数
+    pub room_type: String, // 教室类型，如 "多媒体教室"
+}
+
+fn select_options(document: &Html, selector: &str) -> Result<Vec<FilterOption>, ApiError> {
+    let option_selector = Selector::parse(selector)?;
+    let options: Vec<_> = document.select(&option_selector).collect();
+    if options.is_empty() {
+        return Err(ApiError::layout("jsjy_query", selector));
+    }
+    // 跳过 "---请选择---" 这类没有值的选项
+    Ok(options
+        .into_iter()
+        .filter_map(|option| {
+            let value = option.value().attr("value")?.trim().to_string();
+            let key = option.text().collect::<String>().trim().to_string();
+            (!value.is_empty()).then_some(FilterOption { key, value })
+        })
+        .collect())
+}
+
+pub fn parse_classroom_filters(html: &str) -> Result<ClassroomFilters, ApiError> {
+    let document = Html::parse_document(html);
+    Ok(ClassroomFilters {
+        campuses: select_options(&document, "#xqbh option")?,
+        buildings: select_options(&document, "#jzwbh option")?,
+    })
+}
+
+/// 解析教室使用情况，返回 `sections` 中各节都空闲的教室。
+/// 表格中有 "第1节" 这样的节次列时，单元格有内容表示该节已被占用；
+/// 没有节次列时表格只列出空闲教室，全部返回。有节次列但缺少所查的节次时返回
+/// `ApiError::InvalidArgument`
+pub fn parse_free_classrooms(html: &str, sections: &[u32]) -> Result<Vec<FreeClassroom>, ApiError> {
+    let document = Html::parse_document(html);
+    let table = Table::find(&document, "jsjy_query2", "#dataList")?;
+    let name = table.column("教室名称")?;
+    let campus = table.optional_column("校区");
+    let building = table.optional_column("教学楼");
+    let capacity = table.optional_column("座位数");
+    let room_type = table.optional_column("教室类型");
+
+    let section_re = Regex::new(r"^第?0?(\d{1,2})节?$").unwrap();
+    let section_columns: Vec<(u32, usize)> = table
+        .headers()
+        .iter()
+        .enumerate()
+        .filter_map(|(column, header)| {
+            let section = section_re.captures(header)?[1].parse().ok()?;
+            Some((section, column))
+        })
+        .collect();
+    let mut requested = Vec::new();
+    for section in sections {
+        match section_columns.iter().find(|(number, _)| number == section) {
+            Some((_, column)) => requested.push(*column),
+            // 表格有节次列却缺少所查的节次，无法判断该节是否空闲
+            None if !section_columns.is_empty() => {
+                return Err(ApiError::InvalidArgument {
+                    message: format!("教室使用情况中没有第{}节", section),
+                });
+            }
+            None => {}
+        }
+    }
+
+    Ok(table
+        .rows()
+        .filter(|row| requested.iter().all(|column| row.text(*column).is_empty()))
+        .map(|row| FreeClassroom {
+            name: row.text(name),
+            campus: row.text_or_default(campus),
+            building: row.text_or_default(building),
+            capacity: row.parse(capacity),
+            room_type: row.text_or_default(room_type),
+        })
+        .filter(|room| !room.name.is_empty())
+        .collect())
+}
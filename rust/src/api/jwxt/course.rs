@@ -1,4 +1,6 @@
-use scraper::{Html, Selector};
+use crate::api::error::ApiError;
+use flutter_rust_bridge::frb;
+use scraper::{ElementRef, Html, Selector};
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -10,10 +12,64 @@ pub struct CourseSchedule {
     pub day_of_week: String, // 星期几 "星期一"~"星期日"
     pub section: usize,      // 节次 1~8
     pub weeks: String,       // 周次 "4-17周"
+    pub week_list: Vec<u8>,  // 上课的周次，由 weeks 解析，升序
     pub classroom: String,   // 教室
     pub duration: String,    // 持续节次 "01-02节"
 }
 
+impl CourseSchedule {
+    /// 第 `week` 周是否有这门课
+    #[frb(ignore)]
+    pub fn is_active_in_week(&self, week: u8) -> bool {
+        self.week_list.binary_search(&week).is_ok()
+    }
+}
+
+/// 解析周次文字，支持范围、列表与单双周，如 "4-17周"、"2,4,6周"、"1-8,10-16(单周)"。
+/// 只有最后一段带单双周标记时，视为对整个列表生效
+pub fn parse_weeks(text: &str) -> Vec<u8> {
+    let range_re = regex::Regex::new(r"(\d+)(?:\s*-\s*(\d+))?").unwrap();
+    let parity = |segment: &str| {
+        if segment.contains('单') {
+            Some(1)
+        } else if segment.contains('双') {
+            Some(0)
+        } else {
+            None
+        }
+    };
+
+    let segments: Vec<&str> = text
+        .split([',', '，'])
+        .filter(|segment| !segment.trim().is_empty())
+        .collect();
+    let trailing = match segments.split_last() {
+        Some((last, rest)) if rest.iter().all(|segment| parity(segment).is_none()) => parity(last),
+        _ => None,
+    };
+
+    let mut weeks = Vec::new();
+    for segment in &segments {
+        let Some(caps) = range_re.captures(segment) else {
+            continue;
+        };
+        let start: u8 = caps[1].parse().unwrap_or(0);
+        let end: u8 = caps
+            .get(2)
+            .and_then(|m| m.as_str().parse().ok())
+            .unwrap_or(start);
+        let parity = parity(segment).or(trailing);
+        weeks.extend(
+            (start.min(end)..=start.max(end))
+                .filter(|week| *week > 0)
+                .filter(|week| parity.is_none_or(|p| week % 2 == p)),
+        );
+    }
+    weeks.sort_unstable();
+    weeks.dedup();
+    weeks
+}
+
 fn extract_text(el: Option<scraper::element_ref::ElementRef>, selector: &str) -> String {
     if let Some(e) = el {
         e.select(&Selector::parse(selector).unwrap())
@@ -36,6 +92,28 @@ fn extract_duration(el: Option<scraper::element_ref::ElementRef>) -> String {
     String::new()
 }
 
+/// kbcontent 中的周次带有节次，如 "1-16(周)[01-02]节"，只保留周次部分
+fn extract_weeks(text: &str) -> String {
+    let re = regex::Regex::new(r"\[[\d-]*]节?").unwrap();
+    re.replace_all(text, "").trim().replace("(周)", "周")
+}
+
+/// jsxsd 在同一单元格内用一行 "---------------------" 分隔多门课程
+fn split_cell_courses(html: &str) -> Vec<String> {
+    let re = regex::Regex::new(r"-{10,}\s*(?:<br\s*/?>)?").unwrap();
+    re.split(html)
+        .filter(|part| {
+            !Html::parse_fragment(part)
+                .root_element()
+                .text()
+                .collect::<String>()
+                .trim()
+                .is_empty()
+        })
+        .map(|part| part.trim().to_string())
+        .collect()
+}
+
 fn extract_time_ranges(text: &str) -> String {
     let re = regex::Regex::new(r"\d{2}:\d{2}~\d{2}:\d{2}").unwrap();
     let matches: Vec<String> = re.find_iter(text).map(|m| m.as_str().to_string()).collect();
@@ -46,12 +124,12 @@ fn extract_time_ranges(text: &str) -> String {
     }
 }
 
-pub fn parse_course_schedule(html: &str) -> Result<Vec<CourseSchedule>, String> {
+pub fn parse_course_schedule(html: &str) -> Result<Vec<CourseSchedule>, ApiError> {
     let doc = Html::parse_document(html);
     let table = doc
         .select(&Selector::parse("#kbtable").unwrap())
         .next()
-        .ok_or_else(|| "未找到id=kbtable的表格".to_string())?;
+        .ok_or_else(|| ApiError::layout("xskb_list", "#kbtable"))?;
 
     let week_days = [
         "星期一",
@@ -93,54 +171,71 @@ pub fn parse_course_schedule(html: &str) -> Result<Vec<CourseSchedule>, String>
             .take(7)
             .enumerate()
         {
-            let course_div = cell.select(&Selector::parse(".kbcontent1").unwrap()).next();
-            if course_div.is_none()
-                || course_div
-                    .as_ref()
-                    .unwrap()
-                    .text()
-                    .collect::<String>()
-                    .trim()
-                    .is_empty()
-            {
+            let has_text = |div: &ElementRef| !div.text().collect::<String>().trim().is_empty();
+            // 优先使用包含教师与节次的 kbcontent，缺失时退回只有周次与教室的 kbcontent1
+            let Some(course_div) = cell
+                .select(&Selector::parse(".kbcontent").unwrap())
+                .find(has_text)
+                .or_else(|| {
+                    cell.select(&Selector::parse(".kbcontent1").unwrap())
+                        .find(has_text)
+                })
+            else {
                 continue;
-            }
-            let course_name = course_div
-                .unwrap()
-                .inner_html()
-                .split("<br>")
-                .next()
-                .unwrap_or("")
-                .trim()
-                .to_string();
+            };
 
-            let weeks =
-                extract_text(Some(cell.clone()), "font[title*=\"周次\"]").replace("(周)", "周");
-            let classroom = extract_text(Some(cell.clone()), "font[title*=\"教室\"]");
-            let detail_div = cell.select(&Selector::parse(".kbcontent").unwrap()).next();
+            let mut names_in_cell: Vec<String> = Vec::new();
+            for part in split_cell_courses(&course_div.inner_html()) {
+                let fragment = Html::parse_fragment(&part);
+                let root = Some(fragment.root_element());
 
-            let teacher = extract_text(detail_div, "font[title*=\"老师\"]");
-            let duration = extract_duration(detail_div);
+                let course_name = Html::parse_fragment(part.split("<br>").next().unwrap_or(""))
+                    .root_element()
+                    .text()
+                    .collect::<String>()
+                    .trim()
+                    .to_string();
+                let weeks = extract_weeks(&extract_text(root, "font[title*=\"周次\"]"));
+                let classroom = extract_text(root, "font[title*=\"教室\"]");
+                let teacher = extract_text(root, "font[title*=\"老师\"]");
+                let duration = extract_duration(root);
 
-            courses.push(CourseSchedule {
-                id: format!("{}-{}-{}", day_index + 1, section_num, course_name),
-                name: course_name,
-                teacher: if teacher.is_empty() {
-                    "未提供".to_string()
+                // 同一格内同名课程（如单双周换教室）追加序号，保证 id 唯一
+                let repeat = names_in_cell.iter().filter(|n| **n == course_name).count();
+                names_in_cell.push(course_name.clone());
+                let id = if repeat == 0 {
+                    format!("{}-{}-{}", day_index + 1, section_num, course_name)
                 } else {
-                    teacher
-                },
-                time_range: time_range.clone(),
-                day_of_week: week_days[day_index].to_string(),
-                section: section_num,
-                weeks,
-                classroom,
-                duration: if duration.is_empty() {
-                    "未提供".to_string()
-                } else {
-                    duration
-                },
-            });
+                    format!(
+                        "{}-{}-{}-{}",
+                        day_index + 1,
+                        section_num,
+                        course_name,
+                        repeat + 1
+                    )
+                };
+
+                courses.push(CourseSchedule {
+                    id,
+                    name: course_name,
+                    teacher: if teacher.is_empty() {
+                        "未提供".to_string()
+                    } else {
+                        teacher
+                    },
+                    time_range: time_range.clone(),
+                    day_of_week: week_days[day_index].to_string(),
+                    section: section_num,
+                    week_list: parse_weeks(&weeks),
+                    weeks,
+                    classroom,
+                    duration: if duration.is_empty() {
+                        "未提供".to_string()
+                    } else {
+                        duration
+                    },
+                });
+            }
         }
     }
     Ok(courses)
@@ -1,3 +1,4 @@
+use crate::api::{error::ApiError, jwxt::table::Table};
 use regex::Regex;
 use scraper::{Html, Selector};
 use serde::Serialize;
@@ -33,108 +34,66 @@ pub struct DEKT {
     pub total: Vec<DEKTTotal>,
 }
 
-pub fn parse_dekt_list(html: &str) -> Result<Vec<DEKTList>, String> {
+pub fn parse_dekt_list(html: &str) -> Result<Vec<DEKTList>, ApiError> {
     let document = Html::parse_document(html);
-    let table_selector = Selector::parse("#dataList").unwrap();
-    let row_selector = Selector::parse("tr").unwrap();
-    let cell_selector = Selector::parse("td").unwrap();
     let link_selector = Selector::parse("a").unwrap();
-
-    let table = document
-        .select(&table_selector)
-        .next()
-        .ok_or_else(|| "未找到 id=dataList 的表格".to_string())?;
-
-    let mut dekts = Vec::new();
-
-    for (i, row) in table.select(&row_selector).enumerate() {
-        if i == 0 {
-            continue;
-        } // 跳过表头
-
-        let cells: Vec<_> = row.select(&cell_selector).collect();
-        if cells.len() < 7 {
-            continue;
-        }
-
-        let operation_id = if let Some(link) = cells[6].select(&link_selector).next() {
-            if let Some(onclick) = link.value().attr("onclick") {
-                let re = Regex::new(r"cxxf07id=([^&]+)").unwrap();
-                re.captures(onclick)
-                    .and_then(|caps| caps.get(1).map(|m| m.as_str().to_string()))
-                    .unwrap_or_default()
-            } else {
-                String::new()
+    let id_re = Regex::new(r"cxxf07id=([^&]+)").unwrap();
+    let table = Table::find(&document, "cxxf07List", "#dataList")?;
+
+    let activity_name = table.column("活动名称")?;
+    let credit = table.column("所得学分")?;
+    let id = table.optional_column("序号");
+    let semester = table.optional_column("学年学期");
+    let category = table.optional_column("学分类别");
+    let sub_category = table.optional_column("学分子类");
+    let operation = table.optional_column("操作");
+
+    Ok(table
+        .rows()
+        .map(|row| {
+            let operation_id = operation
+                .and_then(|column| row.cell(column))
+                .and_then(|cell| cell.select(&link_selector).next())
+                .and_then(|link| link.value().attr("onclick"))
+                .and_then(|onclick| id_re.captures(onclick))
+                .and_then(|caps| caps.get(1).map(|m| m.as_str().to_string()))
+                .unwrap_or_default();
+
+            DEKTList {
+                id: row.text_or_default(id),
+                semester: row.text_or_default(semester),
+                category: row.text_or_default(category),
+                sub_category: row.text_or_default(sub_category),
+                activity_name: row.text(activity_name),
+                credit: row.text(credit),
+                operation_id,
             }
-        } else {
-            String::new()
-        };
-
-        let get_text = |idx: usize| {
-            cells
-                .get(idx)
-                .map(|c| c.text().collect::<Vec<_>>().join("").trim().to_string())
-                .unwrap_or_default()
-        };
-        dekts.push(DEKTList {
-            id: get_text(0),
-            semester: get_text(1),
-            category: get_text(2),
-            sub_category: get_text(3),
-            activity_name: get_text(4),
-            credit: get_text(5),
-            operation_id,
-        });
-    }
-
-    Ok(dekts)
+        })
+        .collect())
 }
-pub fn parse_dekt_total(html: &str) -> Result<Vec<DEKTTotal>, String> {
+pub fn parse_dekt_total(html: &str) -> Result<Vec<DEKTTotal>, ApiError> {
     let document = Html::parse_document(html);
-    let table_selector = Selector::parse("table.Nsb_r_list").unwrap();
-
-    let table = document
-        .select(&table_selector)
-        .next()
-        .ok_or_else(|| "未找到 class=Nsb_r_list 的表格".to_string())?;
-
-    let mut totals = Vec::new();
-
-    for row in table.select(&Selector::parse("tr").unwrap()).skip(1) {
-        let cells: Vec<_> = row.select(&Selector::parse("td").unwrap()).collect();
-        if cells.len() < 2 {
-            continue;
-        }
-
-        let category = cells[0]
-            .text()
-            .collect::<Vec<_>>()
-            .join("")
-            .trim()
-            .to_string();
-        let total_credit = cells[1]
-            .text()
-            .collect::<Vec<_>>()
-            .join("")
-            .trim()
-            .to_string();
-
-        totals.push(DEKTTotal {
-            category,
-            total_credit,
-        });
-    }
-
-    Ok(totals)
+    let table = Table::find(&document, "cxxf07List", "table.Nsb_r_list")?;
+
+    let category = table.column("学分类别")?;
+    let total_credit = table.column("总学分")?;
+
+    Ok(table
+        .rows()
+        .map(|row| DEKTTotal {
+            category: row.text(category),
+            total_credit: row.text(total_credit),
+        })
+        .collect())
 }
-pub fn parse_dekt_detail(html: &str) -> Result<DEKTDetail, String> {
+pub fn parse_dekt_detail(html: &str) -> Result<DEKTDetail, ApiError> {
     let document = Html::parse_document(html);
     let table_selector = Selector::parse("table.dataTable").unwrap();
 
     let table = document
         .select(&table_selector)
         .next()
-        .ok_or_else(|| "未找到 class=dataTable 的表格".to_string())?;
+        .ok_or_else(|| ApiError::layout("cxxf07View", "table.dataTable"))?;
 
     let mut map = HashMap::new();
     let row_selector = Selector::parse("tr").unwrap();
@@ -171,7 +130,7 @@ pub fn parse_dekt_detail(html: &str) -> Result<DEKTDetail, String> {
     Ok(DEKTDetail(map))
 }
 
-pub fn parse_dekt(html: &str) -> Result<DEKT, String> {
+pub fn parse_dekt(html: &str) -> Result<DEKT, ApiError> {
     let list = parse_dekt_list(html)?;
     let total = parse_dekt_total(html)?;
     Ok(DEKT { list, total })
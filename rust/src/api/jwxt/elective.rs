@@ -1,15 +1,16 @@
+use crate::api::{error::ApiError, jwxt::table::Table};
 use scraper::{Html, Selector};
 use serde::Serialize;
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreditInfo {
-    pub category: String,
-    pub required: i32,
-    pub limited: i32,
-    pub elective: i32,
-    pub public: i32,
-    pub total: i32,
+    pub category: String, // "培养方案要求"、"已获得学分"、"本学期已选"
+    pub required: f32,    // 必修
+    pub limited: f32,     // 限选
+    pub elective: f32,    // 任选
+    pub public: f32,      // 公选
+    pub total: f32,       // 合计
 }
 
 #[derive(Serialize)]
@@ -31,90 +32,56 @@ pub struct ElectiveResponse {
     pub courses: Vec<XqxkchInfo>,
 }
 
-pub fn parse_elective(html: &str) -> Result<ElectiveResponse, String> {
+pub fn parse_elective(html: &str) -> Result<ElectiveResponse, ApiError> {
     let document = Html::parse_document(html);
-    let table_selector = Selector::parse("table.Nsb_r_list.Nsb_table")
-        .map_err(|_| "无法找到选课表格".to_string())?;
-    let row_selector = Selector::parse("tr").unwrap();
-    let td_selector = Selector::parse("td").unwrap();
-    let th_selector = Selector::parse("th").unwrap();
+    let table_selector = Selector::parse("table.Nsb_r_list.Nsb_table")?;
 
     let tables: Vec<_> = document.select(&table_selector).collect();
     if tables.len() < 2 {
-        return Err("无法找到选课表格".to_string());
+        return Err(ApiError::layout("xqxkchList", "table.Nsb_r_list.Nsb_table"));
     }
 
-    let mut credits = Vec::new();
-    for row in tables[0].select(&row_selector) {
-        if row.select(&th_selector).next().is_some() {
-            continue;
-        }
-        let cells: Vec<_> = row.select(&td_selector).collect();
-        if cells.len() >= 6 {
-            credits.push(CreditInfo {
-                category: cells[0].text().collect::<String>().trim().to_string(),
-                required: cells[1]
-                    .text()
-                    .collect::<String>()
-                    .trim()
-                    .parse()
-                    .unwrap_or(0),
-                limited: cells[2]
-                    .text()
-                    .collect::<String>()
-                    .trim()
-                    .parse()
-                    .unwrap_or(0),
-                elective: cells[3]
-                    .text()
-                    .collect::<String>()
-                    .trim()
-                    .parse()
-                    .unwrap_or(0),
-                public: cells[4]
-                    .text()
-                    .collect::<String>()
-                    .trim()
-                    .parse()
-                    .unwrap_or(0),
-                total: cells[5]
-                    .text()
-                    .collect::<String>()
-                    .trim()
-                    .parse()
-                    .unwrap_or(0),
-            });
-        }
-    }
-    let mut courses = Vec::new();
-    for row in tables[1].select(&row_selector) {
-        if row.select(&th_selector).next().is_some() {
-            continue;
-        }
-        let cells: Vec<_> = row.select(&td_selector).collect();
-        if cells.len() >= 8 {
-            courses.push(XqxkchInfo {
-                course_id: cells[0].text().collect::<String>().trim().to_string(),
-                course_name: cells[1].text().collect::<String>().trim().to_string(),
-                department: cells[2].text().collect::<String>().trim().to_string(),
-                hours: cells[3]
-                    .text()
-                    .collect::<String>()
-                    .trim()
-                    .parse()
-                    .unwrap_or(0),
-                credits: cells[4]
-                    .text()
-                    .collect::<String>()
-                    .trim()
-                    .parse()
-                    .unwrap_or(0.0),
-                course_attribute: cells[5].text().collect::<String>().trim().to_string(),
-                selection_type: cells[6].text().collect::<String>().trim().to_string(),
-                selected: cells[7].text().collect::<String>().trim().to_string(),
-            });
-        }
-    }
+    let credit_table = Table::new("xqxkchList", tables[0]);
+    let category = credit_table.column("类别")?;
+    let required = credit_table.optional_column("必修");
+    let limited = credit_table.optional_column("限选");
+    let elective = credit_table.optional_column("任选");
+    let public = credit_table.optional_column("公选");
+    let total = credit_table.optional_column("合计");
+    let credits = credit_table
+        .rows()
+        .map(|row| CreditInfo {
+            category: row.text(category),
+            required: row.parse(required),
+            limited: row.parse(limited),
+            elective: row.parse(elective),
+            public: row.parse(public),
+            total: row.parse(total),
+        })
+        .collect();
+
+    let course_table = Table::new("xqxkchList", tables[1]);
+    let course_id = course_table.column("课程编号")?;
+    let course_name = course_table.column("课程名称")?;
+    let department = course_table.optional_column("开课单位");
+    let hours = course_table.optional_column("学时");
+    let course_credits = course_table.optional_column("学分");
+    let course_attribute = course_table.optional_column("课程属性");
+    let selection_type = course_table.optional_column("选课方式");
+    let selected = course_table.optional_column("是否选中");
+    let courses = course_table
+        .rows()
+        .map(|row| XqxkchInfo {
+            course_id: row.text(course_id),
+            course_name: row.text(course_name),
+            department: row.text_or_default(department),
+            hours: row.parse(hours),
+            credits: row.parse(course_credits),
+            course_attribute: row.text_or_default(course_attribute),
+            selection_type: row.text_or_default(selection_type),
+            selected: row.text_or_default(selected),
+        })
+        .collect();
 
     Ok(ElectiveResponse { credits, courses })
 }
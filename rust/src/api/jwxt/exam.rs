@@ -1,51 +1,150 @@
-use scraper::{Html, Selector};
+use crate::api::{error::ApiError, jwxt::table::Table};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use flutter_rust_bridge::frb;
+use regex::Regex;
+use scraper::Html;
 use serde::Serialize;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExamSchedule {
-    pub id: u32,               // 序号
-    pub course_code: String,   // 课程编号
-    pub course_name: String,   // 课程名称
-    pub exam_time: String,     // 考试时间
-    pub exam_location: String, // 考场
+    pub id: u32,                    // 序号
+    pub campus: String,             // 校区
+    pub course_code: String,        // 课程编号
+    pub course_name: String,        // 课程名称
+    pub exam_time: String,          // 考试时间 "2024-01-08 09:00~11:00"
+    pub start_time: Option<String>, // 开考时间 "2024-01-08T09:00:00"，无法识别时为 None
+    pub end_time: Option<String>,   // 结束时间 "2024-01-08T11:00:00"
+    pub exam_location: String,      // 考场
+    pub seat_number: String,        // 座位号
+    pub exam_method: String,        // 考试方式 "闭卷"、"上机"
+    pub remark: String,             // 备注
 }
 
-pub fn parse_exam(html: &str) -> Result<Vec<ExamSchedule>, String> {
-    let document = Html::parse_document(html);
-    let table_selector = Selector::parse("#dataList").unwrap();
-    let row_selector = Selector::parse("tr").unwrap();
-    let cell_selector = Selector::parse("td").unwrap();
-    let table = document
-        .select(&table_selector)
-        .next()
-        .ok_or("无法找到考试表格".to_string())?;
-
-    let mut exams = Vec::new();
-
-    for (i, row) in table.select(&row_selector).enumerate() {
-        if i == 0 {
-            continue;
-        }
-        let cells: Vec<_> = row.select(&cell_selector).collect();
-        if cells.len() < 9 {
-            continue;
-        }
-        let text_at = |idx: usize| {
-            cells
-                .get(idx)
-                .map(|c| c.text().collect::<String>().trim().to_string())
-                .unwrap_or_default()
-        };
-        let exam = ExamSchedule {
-            id: text_at(0).parse::<u32>().unwrap_or(0),
-            course_code: text_at(2),
-            course_name: text_at(3),
-            exam_time: text_at(4),
-            exam_location: text_at(5),
-        };
-        exams.push(exam);
+impl ExamSchedule {
+    /// 考试的起止时间（北京时间），考试时间未安排或无法识别时为 None
+    #[frb(ignore)]
+    pub fn time_span(&self) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        parse_exam_time(&self.exam_time)
     }
+}
+
+/// 一场考试的倒计时，以及与其他考试的冲突情况
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExamCountdown {
+    pub exam: ExamSchedule,
+    pub remaining_seconds: Option<i64>, // 距开考的秒数，已开考为负数，时间未知时为 None
+    pub is_finished: bool,              // 考试已结束
+    pub overlaps_with: Vec<u32>,        // 时间重叠的考试在返回列表中的下标
+    pub same_day_with: Vec<u32>,        // 同一天的其他考试在返回列表中的下标
+}
+
+/// 解析考试时间，支持 "2024-01-08 09:00~11:00"、"2024-01-08 09:00-11:00"
+/// 以及结束时间带日期的 "2024-01-08 09:00~2024-01-08 11:00"
+#[frb(ignore)]
+pub fn parse_exam_time(text: &str) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let pattern = Regex::new(
+        r"(\d{4}-\d{1,2}-\d{1,2})\s*(\d{1,2}:\d{2})\s*[~\-－至]+\s*(?:(\d{4}-\d{1,2}-\d{1,2})\s*)?(\d{1,2}:\d{2})",
+    )
+    .unwrap();
+    let caps = pattern.captures(text)?;
+    let date = |m: &str| NaiveDate::parse_from_str(m, "%Y-%m-%d").ok();
+    let time = |m: &str| NaiveTime::parse_from_str(m, "%H:%M").ok();
+
+    let start_date = date(&caps[1])?;
+    let end_date = match caps.get(3) {
+        Some(m) => date(m.as_str())?,
+        None => start_date,
+    };
+    let start = start_date.and_time(time(&caps[2])?);
+    let end = end_date.and_time(time(&caps[4])?);
+    (end >= start).then_some((start, end))
+}
+
+/// 按距开考时间排序：未结束的考试在前，已结束的在后，时间未知的排在最后。
+/// `now` 为当前北京时间。冲突的考试以其在返回列表中的下标标识，序号、课程编号等列都可能缺失
+#[frb(ignore)]
+pub fn exam_countdown(exams: Vec<ExamSchedule>, now: NaiveDateTime) -> Vec<ExamCountdown> {
+    let mut exams: Vec<_> = exams
+        .into_iter()
+        .map(|exam| {
+            let span = exam.time_span();
+            (exam, span)
+        })
+        .collect();
+    exams.sort_by_key(|(_, span)| match span {
+        Some((start, end)) => ((*end <= now) as u8, (*start - now).num_seconds()),
+        None => (2, 0),
+    });
+    let spans: Vec<_> = exams.iter().map(|(_, span)| *span).collect();
+
+    exams
+        .into_iter()
+        .enumerate()
+        .map(|(i, (exam, span))| {
+            let (mut overlaps_with, mut same_day_with) = (Vec::new(), Vec::new());
+            if let Some((start, end)) = span {
+                for (j, other) in spans.iter().enumerate() {
+                    let Some((other_start, other_end)) = *other else {
+                        continue;
+                    };
+                    if j == i {
+                        continue;
+                    }
+                    if other_start < end && start < other_end {
+                        overlaps_with.push(j as u32);
+                    }
+                    if other_start.date() == start.date() {
+                        same_day_with.push(j as u32);
+                    }
+                }
+            }
+            ExamCountdown {
+                remaining_seconds: span.map(|(start, _)| (start - now).num_seconds()),
+                is_finished: span.is_some_and(|(_, end)| end <= now),
+                overlaps_with,
+                same_day_with,
+                exam,
+            }
+        })
+        .collect()
+}
+
+/// 课程名称与考试时间为必需列，其余列缺失时留空
+pub fn parse_exam(html: &str) -> Result<Vec<ExamSchedule>, ApiError> {
+    let document = Html::parse_document(html);
+    let table = Table::find(&document, "xsksap_list", "#dataList")?;
+
+    let course_name = table.column("课程名称")?;
+    let exam_time = table.column("考试时间")?;
+    let id = table.optional_column("序号");
+    let campus = table.optional_column("校区");
+    let course_code = table.optional_column("课程编号");
+    let exam_location = table.optional_column("考场");
+    let seat_number = table.optional_column("座位号");
+    let exam_method = table.optional_column("考试方式");
+    let remark = table.optional_column("备注");
 
-    Ok(exams)
+    let format = |time: NaiveDateTime| time.format("%Y-%m-%dT%H:%M:%S").to_string();
+    Ok(table
+        .rows()
+        .map(|row| {
+            let exam_time = row.text(exam_time);
+            let span = parse_exam_time(&exam_time);
+            ExamSchedule {
+                id: row.parse(id),
+                campus: row.text_or_default(campus),
+                course_code: row.text_or_default(course_code),
+                course_name: row.text(course_name),
+                start_time: span.map(|(start, _)| format(start)),
+                end_time: span.map(|(_, end)| format(end)),
+                exam_time,
+                exam_location: row.text_or_default(exam_location),
+                seat_number: row.text_or_default(seat_number),
+                exam_method: row.text_or_default(exam_method),
+                remark: row.text_or_default(remark),
+            }
+        })
+        .collect())
 }
@@ -0,0 +1,144 @@
+use crate::api::jwxt::score::{effective_scores, ScoreInfo};
+use flutter_rust_bridge::frb;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// 绩点算法，等级制成绩先按 `GradeScale` 换算成百分制再计算
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum GpaScheme {
+    /// 教务系统成绩单中每门课程的绩点
+    School,
+    /// 标准 4.0：90/80/70/60 分别对应 4/3/2/1
+    Standard4,
+    /// 改进 4.0：85/70/60 分别对应 4/3/2
+    Improved4,
+    /// 北大 4.0：十档，90 分以上 4.0，60 分 1.0
+    Pku4,
+    /// WES：85/75/60 分别对应 4/3/2
+    Wes,
+}
+
+impl GpaScheme {
+    /// 百分制成绩对应的绩点，`School` 直接使用教务系统给出的绩点
+    #[frb(ignore)]
+    pub fn grade_point(&self, score: &ScoreInfo) -> Option<f32> {
+        let table: &[(f32, f32)] = match self {
+            GpaScheme::School => return Some(score.gpa),
+            GpaScheme::Standard4 => &[(90.0, 4.0), (80.0, 3.0), (70.0, 2.0), (60.0, 1.0)],
+            GpaScheme::Improved4 => &[(85.0, 4.0), (70.0, 3.0), (60.0, 2.0)],
+            GpaScheme::Pku4 => &[
+                (90.0, 4.0),
+                (85.0, 3.7),
+                (82.0, 3.3),
+                (78.0, 3.0),
+                (75.0, 2.7),
+                (72.0, 2.3),
+                (68.0, 2.0),
+                (64.0, 1.5),
+                (60.0, 1.0),
+            ],
+            GpaScheme::Wes => &[(85.0, 4.0), (75.0, 3.0), (60.0, 2.0)],
+        };
+        let value = score.score.value?;
+        Some(
+            table
+                .iter()
+                .find(|(min, _)| value >= *min)
+                .map(|(_, point)| *point)
+                .unwrap_or(0.0),
+        )
+    }
+}
+
+/// 按课程性质、课程属性筛选参与计算的课程，列表为空时不限制
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GpaFilter {
+    pub course_natures: Vec<String>, // 课程性质，如 "专业核心课"
+    pub course_attrs: Vec<String>,   // 课程属性，如 "必修"
+}
+
+impl GpaFilter {
+    fn matches(&self, score: &ScoreInfo) -> bool {
+        (self.course_natures.is_empty() || self.course_natures.contains(&score.course_nature))
+            && (self.course_attrs.is_empty() || self.course_attrs.contains(&score.course_attr))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GpaResult {
+    pub gpa: Option<f32>,           // 学分加权平均绩点
+    pub average_score: Option<f32>, // 学分加权平均分
+    pub credits: f32,               // 参与计算的学分
+    pub course_count: u32,          // 参与计算的课程数
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemesterGpa {
+    pub term: String,
+    pub result: GpaResult,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GpaReport {
+    pub scheme: GpaScheme,
+    pub overall: GpaResult,
+    pub semesters: Vec<SemesterGpa>, // 按学期先后排列
+}
+
+/// 计算学分加权绩点与平均分。同一课程多次考试只取最高一次，免修课程不计入
+#[frb(ignore)]
+pub fn compute_gpa(scores: &[ScoreInfo], scheme: GpaScheme, filter: &GpaFilter) -> GpaResult {
+    let counted: Vec<_> = effective_scores(scores)
+        .into_iter()
+        .filter(|score| filter.matches(score))
+        .filter(|score| score.score.is_counted() && score.credit > 0.0)
+        .collect();
+
+    let credits: f32 = counted.iter().map(|score| score.credit).sum();
+    let weighted = |value: &dyn Fn(&ScoreInfo) -> f32| {
+        (credits > 0.0).then(|| {
+            counted
+                .iter()
+                .map(|score| value(score) * score.credit)
+                .sum::<f32>()
+                / credits
+        })
+    };
+
+    GpaResult {
+        gpa: weighted(&|score| scheme.grade_point(score).unwrap_or(0.0)),
+        average_score: weighted(&|score| score.score.value.unwrap_or(0.0)),
+        credits,
+        course_count: counted.len() as u32,
+    }
+}
+
+/// 计算总体与每学期的绩点，每学期只统计该学期内的考试
+#[frb(ignore)]
+pub fn gpa_report(scores: &[ScoreInfo], scheme: GpaScheme, filter: &GpaFilter) -> GpaReport {
+    let mut by_term: BTreeMap<&str, Vec<&ScoreInfo>> = BTreeMap::new();
+    for score in scores {
+        by_term.entry(score.term.as_str()).or_default().push(score);
+    }
+
+    let semesters = by_term
+        .into_iter()
+        .map(|(term, scores)| {
+            let scores: Vec<ScoreInfo> = scores.into_iter().cloned().collect();
+            SemesterGpa {
+                term: term.to_string(),
+                result: compute_gpa(&scores, scheme, filter),
+            }
+        })
+        .collect();
+
+    GpaReport {
+        scheme,
+        overall: compute_gpa(scores, scheme, filter),
+        semesters,
+    }
+}
@@ -0,0 +1,114 @@
+use flutter_rust_bridge::frb;
+use regex::Regex;
+use serde::Serialize;
+
+/// 等级制成绩换算成百分制的分值，可由用户按本校规定调整
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GradeScale {
+    pub excellent: f32,   // 优秀
+    pub good: f32,        // 良好
+    pub medium: f32,      // 中等
+    pub pass: f32,        // 及格
+    pub fail: f32,        // 不及格
+    pub qualified: f32,   // 合格
+    pub unqualified: f32, // 不合格
+}
+
+impl Default for GradeScale {
+    fn default() -> Self {
+        Self {
+            excellent: 95.0,
+            good: 85.0,
+            medium: 75.0,
+            pass: 65.0,
+            fail: 0.0,
+            qualified: 85.0,
+            unqualified: 0.0,
+        }
+    }
+}
+
+impl GradeScale {
+    /// 按等级文字查找分值，"不及格"、"不合格"需要先于"及格"、"合格"匹配
+    fn value_of(&self, text: &str) -> Option<f32> {
+        [
+            ("优秀", self.excellent),
+            ("良好", self.good),
+            ("中等", self.medium),
+            ("不及格", self.fail),
+            ("及格", self.pass),
+            ("不合格", self.unqualified),
+            ("合格", self.qualified),
+        ]
+        .into_iter()
+        .find(|(level, _)| text.contains(level))
+        .map(|(_, value)| value)
+    }
+}
+
+/// 一门课程的成绩，保留教务系统显示的原始文字
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Grade {
+    pub raw: String,        // 原始成绩 "85.5"、"良好"、"缺考"
+    pub value: Option<f32>, // 百分制分值，免修等没有分数时为 None
+    pub is_absent: bool,    // 缺考
+    pub is_exempt: bool,    // 免修
+    pub is_retake: bool,    // 重修
+    pub is_makeup: bool,    // 补考
+}
+
+impl Grade {
+    #[frb(ignore)]
+    pub fn parse(raw: &str, scale: &GradeScale) -> Self {
+        let raw = raw.trim();
+        let is_absent = raw.contains("缺考");
+        let is_exempt = raw.contains("免修") || raw.contains("免考");
+        let is_retake = raw.contains("重修");
+        let is_makeup = raw.contains("补考");
+
+        let number = Regex::new(r"\d+(?:\.\d+)?").unwrap();
+        let value = if is_exempt {
+            None
+        } else if is_absent {
+            Some(0.0)
+        } else {
+            number
+                .find(raw)
+                .and_then(|m| m.as_str().parse::<f32>().ok())
+                .or_else(|| scale.value_of(raw))
+        };
+
+        Self {
+            raw: raw.to_string(),
+            value,
+            is_absent,
+            is_exempt,
+            is_retake,
+            is_makeup,
+        }
+    }
+
+    /// 成绩列表有 "考试性质" 或 "补重" 列时以该列为准设置重修、补考标记，
+    /// 该列的取值为 "正常考试"、"补考"、"重修"，或简写为 "补"、"重"
+    #[frb(ignore)]
+    pub fn with_exam_nature(mut self, nature: &str) -> Self {
+        let nature = nature.trim();
+        self.is_retake = nature.contains("重修") || nature == "重";
+        self.is_makeup = nature.contains("补考") || nature == "补";
+        self
+    }
+
+    /// 是否计入平均分与平均绩点，免修和无法识别的成绩不计入
+    #[frb(ignore)]
+    pub fn is_counted(&self) -> bool {
+        !self.is_exempt && self.value.is_some()
+    }
+
+    /// 是否取得该课程学分，免修视为通过
+    #[frb(ignore)]
+    pub fn is_passed(&self) -> bool {
+        self.is_exempt || self.value.is_some_and(|value| value >= 60.0)
+    }
+}
@@ -0,0 +1,158 @@
+//! 毕业学分要求检查：课程学分来自选课页的学分要求表与成绩单，第二课堂学分来自 cxxf07List
+
+use crate::api::{
+    error::ApiError,
+    jwxt::{
+        dekt::DEKTTotal,
+        elective::CreditInfo,
+        score::{effective_scores, ScoreInfo},
+    },
+};
+use flutter_rust_bridge::frb;
+use regex::Regex;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CreditSource {
+    /// 课程学分
+    Course,
+    /// 第二课堂学分
+    SecondClassroom,
+}
+
+/// 第二课堂某一类别需要的学分，教务系统页面上没有给出，由用户按本校规定设置
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DektRequirement {
+    pub category: String, // 学分类别，如 "创新创业"
+    pub credits: f32,
+}
+
+/// 一项学分要求的完成情况
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreditBucket {
+    pub source: CreditSource,
+    pub name: String,
+    pub earned: f32,
+    pub required: f32,
+    pub missing: f32, // 还差的学分，已满足时为 0
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraduationReport {
+    pub buckets: Vec<CreditBucket>,
+    pub passed: bool, // 所有要求均已满足
+}
+
+impl CreditBucket {
+    fn new(source: CreditSource, name: &str, earned: f32, required: f32) -> Self {
+        let missing = (required - earned).max(0.0);
+        Self {
+            source,
+            name: name.to_string(),
+            earned,
+            required,
+            missing,
+            passed: missing <= 0.0,
+        }
+    }
+}
+
+/// 取出学分文字中的数字，如 "1.5"、"2分"，无法识别时为 0
+pub fn parse_credit(text: &str) -> f32 {
+    Regex::new(r"\d+(?:\.\d+)?")
+        .unwrap()
+        .find(text)
+        .and_then(|m| m.as_str().parse().ok())
+        .unwrap_or(0.0)
+}
+
+/// 汇总毕业学分要求。
+/// 必修、限选、任选、公选按学分要求表中 "培养方案要求" 与 "已获得学分" 两行比较；
+/// 课程总学分以成绩单中已通过课程的学分为准，同一课程只计一次；
+/// 第二课堂按 `dekt_requirements` 中的类别比较，未设置要求的类别只列出已获学分
+#[frb(ignore)]
+pub fn graduation_check(
+    credits: &[CreditInfo],
+    scores: &[ScoreInfo],
+    dekt: &[DEKTTotal],
+    dekt_requirements: &[DektRequirement],
+) -> Result<GraduationReport, ApiError> {
+    let row = |label: &str| {
+        credits
+            .iter()
+            .find(|info| info.category.contains(label))
+            .ok_or_else(|| ApiError::layout("xqxkchList", label))
+    };
+    let required = row("培养方案要求")?;
+    let earned = row("已获得")?;
+
+    let mut buckets = vec![
+        CreditBucket::new(
+            CreditSource::Course,
+            "必修",
+            earned.required,
+            required.required,
+        ),
+        CreditBucket::new(
+            CreditSource::Course,
+            "限选",
+            earned.limited,
+            required.limited,
+        ),
+        CreditBucket::new(
+            CreditSource::Course,
+            "任选",
+            earned.elective,
+            required.elective,
+        ),
+        CreditBucket::new(CreditSource::Course, "公选", earned.public, required.public),
+    ];
+
+    let transcript_credits: f32 = effective_scores(scores)
+        .into_iter()
+        .filter(|score| score.score.is_passed())
+        .map(|score| score.credit)
+        .sum();
+    buckets.push(CreditBucket::new(
+        CreditSource::Course,
+        "课程总学分",
+        transcript_credits,
+        required.total,
+    ));
+
+    let dekt_earned = |category: &str| {
+        dekt.iter()
+            .filter(|total| total.category == category)
+            .map(|total| parse_credit(&total.total_credit))
+            .sum::<f32>()
+    };
+    for requirement in dekt_requirements {
+        buckets.push(CreditBucket::new(
+            CreditSource::SecondClassroom,
+            &requirement.category,
+            dekt_earned(&requirement.category),
+            requirement.credits,
+        ));
+    }
+    for total in dekt {
+        if !buckets.iter().any(|bucket| {
+            bucket.source == CreditSource::SecondClassroom && bucket.name == total.category
+        }) {
+            buckets.push(CreditBucket::new(
+                CreditSource::SecondClassroom,
+                &total.category,
+                dekt_earned(&total.category),
+                0.0,
+            ));
+        }
+    }
+
+    Ok(GraduationReport {
+        passed: buckets.iter().all(|bucket| bucket.passed),
+        buckets,
+    })
+}
@@ -1,72 +1,177 @@
+use crate::api::error::ApiError;
+use flutter_rust_bridge::frb;
 use scraper::{Html, Selector};
 use serde::Serialize;
+use std::collections::HashMap;
 
-
+/// 学籍卡片中的个人信息，卡片上没有的项为 None
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StudentInfo {
-    pub name: String,
-    pub gender: String,
-    pub student_id: String,
-    pub department: String,
-    pub major: String,
-    pub class_name: String,
-    pub admission_date: String,
-    pub admission_number: String,
-    pub id_number: String,
+    pub name: Option<String>,                // 姓名
+    pub gender: Option<String>,              // 性别
+    pub student_id: Option<String>,          // 学号
+    pub department: Option<String>,          // 院系
+    pub major: Option<String>,               // 专业
+    pub class_name: Option<String>,          // 班级
+    pub admission_date: Option<String>,      // 入学日期
+    pub admission_number: Option<String>,    // 入学考号
+    pub id_number: Option<String>,           // 身份证编号
+    pub birth_date: Option<String>,          // 出生日期
+    pub ethnicity: Option<String>,           // 民族
+    pub political_status: Option<String>,    // 政治面貌
+    pub hometown: Option<String>,            // 籍贯
+    pub education_level: Option<String>,     // 学历层次
+    pub length_of_schooling: Option<String>, // 学制
+    pub contact: Option<String>,             // 联系电话
+    pub high_school: Option<String>,         // 毕业中学
 }
 
-pub fn parse_student_info(html: &str) -> Result<StudentInfo, String> {
+/// 卡片上各项的标签，同一项在不同学校的页面上可能有不同写法
+const LABELS: &[(&str, &[&str])] = &[
+    ("name", &["姓名"]),
+    ("gender", &["性别"]),
+    ("student_id", &["学号"]),
+    ("department", &["院系", "学院"]),
+    ("major", &["专业"]),
+    ("class_name", &["班级"]),
+    ("admission_date", &["入学日期", "入学时间"]),
+    ("admission_number", &["入学考号", "考生号"]),
+    ("id_number", &["身份证编号", "身份证号"]),
+    ("birth_date", &["出生日期"]),
+    ("ethnicity", &["民族"]),
+    ("political_status", &["政治面貌"]),
+    ("hometown", &["籍贯"]),
+    ("education_level", &["学历层次", "培养层次"]),
+    ("length_of_schooling", &["学制"]),
+    ("contact", &["联系电话", "手机号码"]),
+    ("high_school", &["毕业中学"]),
+];
+
+fn is_label(text: &str) -> bool {
+    LABELS.iter().any(|(_, labels)| labels.contains(&text))
+}
+
+/// 扫描卡片中的所有单元格，收集 "标签：值" 形式的单元格，以及标签单元格与其右侧单元格。
+/// 同一标签出现多次时（如家庭成员表头中的 "姓名"）只取第一次
+fn collect_fields(html: &str) -> Result<HashMap<String, String>, ApiError> {
     let document = Html::parse_document(html);
+    let table_selector = Selector::parse("#xjkpTable")?;
+    let row_selector = Selector::parse("tr")?;
+    let cell_selector = Selector::parse("td")?;
 
-    let table_selector = Selector::parse("#xjkpTable").map_err(|e| e.to_string())?;
     let table = document
         .select(&table_selector)
         .next()
-        .ok_or_else(|| "无法找到学籍信息表格".to_string())?;
+        .ok_or_else(|| ApiError::layout("xsxx", "#xjkpTable"))?;
 
-    let row_selector = Selector::parse("tr").map_err(|e| e.to_string())?;
-    let rows: Vec<_> = table.select(&row_selector).collect();
+    let mut fields = HashMap::new();
+    for row in table.select(&row_selector) {
+        let cells: Vec<String> = row
+            .select(&cell_selector)
+            .map(|cell| {
+                cell.text()
+                    .collect::<String>()
+                    .replace('\u{a0}', " ")
+                    .trim()
+                    .to_string()
+            })
+            .collect();
 
-    let get_text = |elem: Option<scraper::element_ref::ElementRef>, selector: Option<&str>| -> String {
-        if let Some(el) = elem {
-            if let Some(sel) = selector {
-                el.select(&Selector::parse(sel).unwrap())
-                    .next()
-                    .map(|e| e.text().collect::<String>().trim().replace('\u{a0}', " "))
-                    .unwrap_or_default()
-            } else {
-                el.text().collect::<String>().trim().replace('\u{a0}', " ")
+        for (i, text) in cells.iter().enumerate() {
+            let (label, value) = match text.split_once(['：', ':']) {
+                Some((label, value)) => (label.trim(), value.trim()),
+                None if is_label(text) => match cells.get(i + 1) {
+                    Some(value) if !is_label(value) => (text.as_str(), value.as_str()),
+                    _ => continue,
+                },
+                None => continue,
+            };
+            if !value.is_empty() {
+                fields
+                    .entry(label.to_string())
+                    .or_insert_with(|| value.to_string());
             }
-        } else {
-            "".to_string()
         }
-    };
+    }
+    Ok(fields)
+}
 
-    let info_cells: Vec<_> = rows[2].select(&Selector::parse("td").unwrap()).collect();
-    let personal_cells: Vec<_> = rows[3].select(&Selector::parse("td").unwrap()).collect();
-    let admission_date_cells: Vec<_> = rows[46].select(&Selector::parse("td").unwrap()).collect();
-    let id_number_cells: Vec<_> = rows[47].select(&Selector::parse("td").unwrap()).collect();
+pub fn parse_student_info(html: &str) -> Result<StudentInfo, ApiError> {
+    let fields = collect_fields(html)?;
+    let field = |key: &str| {
+        LABELS
+            .iter()
+            .find(|(name, _)| *name == key)
+            .and_then(|(_, labels)| labels.iter().find_map(|label| fields.get(*label)))
+            .cloned()
+    };
 
     Ok(StudentInfo {
-        name: get_text(personal_cells.get(1).cloned(), None),
-        gender: get_text(personal_cells.get(3).cloned(), None),
-        student_id: get_text(info_cells.get(4).cloned(), None).replace("学号：", ""),
-        department: get_text(info_cells.get(0).cloned(), None).replace("院系：", ""),
-        major: get_text(info_cells.get(1).cloned(), None).replace("专业：", ""),
-        class_name: get_text(info_cells.get(3).cloned(), None).replace("班级：", ""),
-        admission_date: get_text(admission_date_cells.get(1).cloned(), None)
-            .replace('\u{a0}', "")
-            .trim()
-            .to_string(),
-        admission_number: get_text(id_number_cells.get(1).cloned(), None)
-            .replace('\u{a0}', "")
-            .trim()
-            .to_string(),
-        id_number: get_text(id_number_cells.get(3).cloned(), None)
-            .replace('\u{a0}', "")
-            .trim()
-            .to_string(),
+        name: field("name"),
+        gender: field("gender"),
+        student_id: field("student_id"),
+        department: field("department"),
+        major: field("major"),
+        class_name: field("class_name"),
+        admission_date: field("admission_date"),
+        admission_number: field("admission_number"),
+        id_number: field("id_number"),
+        birth_date: field("birth_date"),
+        ethnicity: field("ethnicity"),
+        political_status: field("political_status"),
+        hometown: field("hometown"),
+        education_level: field("education_level"),
+        length_of_schooling: field("length_of_schooling"),
+        contact: field("contact"),
+        high_school: field("high_school"),
     })
 }
 
+/// 学籍卡片上的学生照片
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StudentPhoto {
+    pub bytes: Vec<u8>,
+    pub mime_type: String, // "image/jpeg"
+}
+
+impl StudentPhoto {
+    /// 优先使用响应的 Content-Type，不是图片类型时按文件头识别，都无法识别时返回错误
+    #[frb(ignore)]
+    pub fn new(bytes: Vec<u8>, content_type: Option<&str>) -> Result<Self, ApiError> {
+        let declared = content_type
+            .map(|value| value.split(';').next().unwrap_or_default().trim())
+            .filter(|value| value.starts_with("image/"));
+        let sniffed = if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some("image/jpeg")
+        } else if bytes.starts_with(b"\x89PNG") {
+            Some("image/png")
+        } else if bytes.starts_with(b"GIF8") {
+            Some("image/gif")
+        } else if bytes.starts_with(b"BM") {
+            Some("image/bmp")
+        } else {
+            None
+        };
+        let mime_type = declared
+            .or(sniffed)
+            .ok_or_else(|| ApiError::layout("xszpLoad", "照片"))?
+            .to_string();
+        Ok(Self { bytes, mime_type })
+    }
+}
+
+/// 学籍卡片中照片的地址，通常为 "/jsxsd/grxx/xszpLoad"
+pub fn parse_photo_src(html: &str) -> Result<String, ApiError> {
+    let document = Html::parse_document(html);
+    let selector = "#xjkpTable img[src]";
+    let img_selector = Selector::parse(selector)?;
+    document
+        .select(&img_selector)
+        .filter_map(|img| img.value().attr("src"))
+        .map(str::trim)
+        .find(|src| !src.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| ApiError::layout("xsxx", selector))
+}
@@ -1,8 +1,16 @@
+pub mod audit;
+pub mod calendar;
+pub mod classroom;
 pub mod course;
 pub mod dekt;
 pub mod elective;
 pub mod exam;
+pub mod gpa;
+pub mod grade;
+pub mod graduation;
 pub mod info;
 pub mod plan;
 pub mod score;
+pub mod selection;
 pub mod semester;
+pub(crate) mod table;
@@ -1,11 +1,17 @@
-use scraper::{ElementRef, Html, Selector};
+use crate::api::{
+    error::ApiError,
+    jwxt::{semester::split_semester, table::Table},
+};
+use scraper::Html;
 use serde::Serialize;
-use std::collections::HashSet;
+use std::collections::BTreeMap;
 
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 pub struct ExecutionPlan {
     pub id: u32,
     pub semester: String,
+    pub academic_year: String, // 学年 "2023-2024"，无法识别时为空
+    pub term: u8,              // 学期序号，1 秋季、2 春季、3 夏季小学期，无法识别时为 0
     pub course_code: String,
     pub course_name: String,
     pub department: String,
@@ -16,64 +22,95 @@ pub struct ExecutionPlan {
     pub is_exam: String,
 }
 
+/// 一个学期的培养方案课程及学分、学时合计
+#[derive(Serialize)]
+pub struct PlanSemester {
+    pub semester: String,
+    pub academic_year: String,
+    pub term: u8,
+    pub credits: f32,
+    pub total_hours: f32,
+    pub plans: Vec<ExecutionPlan>,
+}
+
 #[derive(Serialize)]
 pub struct ExecutionPlanResponse {
     pub plans: Vec<ExecutionPlan>,
-    pub semesters: Vec<String>,
+    pub semesters: Vec<String>,    // 按时间先后排列
+    pub groups: Vec<PlanSemester>, // 按学期分组，顺序与 semesters 一致
 }
 
-pub fn parse_plan(html: &str) -> Result<ExecutionPlanResponse, String> {
+pub fn parse_plan(html: &str) -> Result<ExecutionPlanResponse, ApiError> {
     let document = Html::parse_document(html);
+    let table = Table::find(
+        &document,
+        "pyfa_query",
+        "table#dataList.Nsb_r_list.Nsb_table",
+    )?;
 
-    let selector_str = "table#dataList.Nsb_r_list.Nsb_table";
-    let selector = Selector::parse(selector_str).map_err(|_| "选择器解析失败".to_string())?;
-
-    let table = document
-        .select(&selector)
-        .next()
-        .ok_or_else(|| "未找到符合条件的表格元素".to_string())?;
-
-    let tr_selector = Selector::parse("tr").map_err(|_| "tr选择器解析失败".to_string())?;
-    let td_selector = Selector::parse("td").map_err(|_| "td选择器解析失败".to_string())?;
+    let semester = table.column("开课学期")?;
+    let course_code = table.column("课程编号")?;
+    let course_name = table.column("课程名称")?;
+    let credits = table.column("学分")?;
+    let id = table.optional_column("序号");
+    let department = table.optional_column("开课单位");
+    let total_hours = table.optional_column("总学时");
+    let assessment_method = table.optional_column("考核方式");
+    let course_type = table.optional_column("课程属性");
+    let is_exam = table.optional_column("是否考试");
 
     let mut plans = Vec::new();
-    let mut semesters_set = HashSet::new();
-
-    for (i, row) in table.select(&tr_selector).enumerate() {
-        if i == 0 {
-            continue;
-        }
-        let cells: Vec<ElementRef> = row.select(&td_selector).collect();
-        if cells.len() < 10 {
-            continue;
-        }
 
-        let text =
-            |idx: usize| -> String { cells[idx].text().collect::<String>().trim().to_string() };
-        let parse_f32 = |idx| text(idx).parse::<f32>().unwrap_or(0.0);
-        let id = text(0).parse::<u32>().unwrap_or(i as u32);
-        let semester = text(1);
-
-        if semesters_set.len() < 8 {
-            semesters_set.insert(semester.clone());
-        }
+    for (i, row) in table.rows().enumerate() {
+        let id = row
+            .text_or_default(id)
+            .parse::<u32>()
+            .unwrap_or(i as u32 + 1);
+        let semester = row.text(semester);
+        let (academic_year, term) = split_semester(&semester).unwrap_or_default();
 
         plans.push(ExecutionPlan {
             id,
-            semester: semester.clone(),
-            course_code: text(2),
-            course_name: text(3),
-            department: text(4),
-            credits: parse_f32(5),
-            total_hours: parse_f32(6),
-            assessment_method: text(7),
-            course_type: text(8),
-            is_exam: text(9),
+            semester,
+            academic_year,
+            term,
+            course_code: row.text(course_code),
+            course_name: row.text(course_name),
+            department: row.text_or_default(department),
+            credits: row.parse(Some(credits)),
+            total_hours: row.parse(total_hours),
+            assessment_method: row.text_or_default(assessment_method),
+            course_type: row.text_or_default(course_type),
+            is_exam: row.text_or_default(is_exam),
         });
     }
 
+    // 按学年、学期序号排序，无法识别的学期排在最后
+    let mut groups: BTreeMap<(bool, String, u8, String), Vec<ExecutionPlan>> = BTreeMap::new();
+    for plan in &plans {
+        let key = (
+            plan.academic_year.is_empty(),
+            plan.academic_year.clone(),
+            plan.term,
+            plan.semester.clone(),
+        );
+        groups.entry(key).or_default().push(plan.clone());
+    }
+    let groups: Vec<PlanSemester> = groups
+        .into_iter()
+        .map(|((_, academic_year, term, semester), plans)| PlanSemester {
+            semester,
+            academic_year,
+            term,
+            credits: plans.iter().map(|plan| plan.credits).sum(),
+            total_hours: plans.iter().map(|plan| plan.total_hours).sum(),
+            plans,
+        })
+        .collect();
+
     Ok(ExecutionPlanResponse {
         plans,
-        semesters: semesters_set.into_iter().collect(),
+        semesters: groups.iter().map(|group| group.semester.clone()).collect(),
+        groups,
     })
 }
@@ -1,14 +1,19 @@
+use crate::api::error::ApiError;
+use crate::api::jwxt::grade::{Grade, GradeScale};
+use crate::api::jwxt::table::Table;
+use flutter_rust_bridge::frb;
 use scraper::{Html, Selector};
 use serde::Serialize;
+use std::collections::HashMap;
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ScoreInfo {
     pub id: u32,
     pub term: String,
     pub course_id: String,
     pub course_name: String,
-    pub score: f32,
+    pub score: Grade,
     pub credit: f32,
     pub hours: f32,
     pub gpa: f32,
@@ -16,56 +21,50 @@ pub struct ScoreInfo {
     pub course_attr: String,
     pub course_nature: String,
 }
-pub fn parse_score(html: &str) -> Result<Vec<ScoreInfo>, String> {
+/// 课程编号、课程名称、成绩与学分为必需列，其余列缺失时留空或按 0 计。
+/// 重修、补考标记优先取 "考试性质" 列，没有该列时从成绩文字判断
+#[frb(ignore)]
+pub fn parse_score(html: &str, scale: &GradeScale) -> Result<Vec<ScoreInfo>, ApiError> {
     let document = Html::parse_document(html);
-    let table_selector = Selector::parse("#dataList").unwrap();
-    let row_selector = Selector::parse("tr").unwrap();
-    let cell_selector = Selector::parse("td").unwrap();
-
-    let table = document
-        .select(&table_selector)
-        .next()
-        .ok_or_else(|| "无法找到成绩表格".to_string())?;
-
-    let mut scores = Vec::new();
-
-    for (i, row) in table.select(&row_selector).enumerate() {
-        if i == 0 {
-            continue;
-        }
-
-        let cells: Vec<_> = row.select(&cell_selector).collect();
-        if cells.len() < 11 {
-            continue;
-        }
-
-        let parse_text = |index: usize| {
-            cells
-                .get(index)
-                .map(|c| c.text().collect::<String>().trim().to_string())
-                .unwrap_or_default()
-        };
-
-        let parse_f32 = |index: usize| parse_text(index).parse::<f32>().unwrap_or(0.0);
+    let table = Table::find(&document, "cjcx_list", "#dataList")?;
 
-        let parse_u32 = |index: usize| parse_text(index).parse::<u32>().unwrap_or(0);
+    let course_id = table.column("课程编号")?;
+    let course_name = table.column("课程名称")?;
+    let score = table.column("成绩")?;
+    let credit = table.column("学分")?;
+    let id = table.optional_column("序号");
+    let term = table.optional_column("开课学期");
+    let hours = table.optional_column("总学时");
+    let gpa = table.optional_column("绩点");
+    let exam_type = table.optional_column("考核方式");
+    let course_attr = table.optional_column("课程属性");
+    let course_nature = table.optional_column("课程性质");
+    let exam_nature = ["考试性质", "补重", "补重标志"]
+        .into_iter()
+        .find_map(|header| table.optional_column(header));
 
-        let score = ScoreInfo {
-            id: parse_u32(0),
-            term: parse_text(1),
-            course_id: parse_text(2),
-            course_name: parse_text(3),
-            score: parse_f32(4),
-            credit: parse_f32(5),
-            hours: parse_f32(6),
-            gpa: parse_f32(7),
-            exam_type: parse_text(8),
-            course_attr: parse_text(9),
-            course_nature: parse_text(10),
-        };
-        scores.push(score);
-    }
-    Ok(scores)
+    Ok(table
+        .rows()
+        .map(|row| ScoreInfo {
+            id: row.parse(id),
+            term: row.text_or_default(term),
+            course_id: row.text(course_id),
+            course_name: row.text(course_name),
+            score: {
+                let grade = Grade::parse(&row.text(score), scale);
+                match exam_nature {
+                    Some(column) => grade.with_exam_nature(&row.text(column)),
+                    None => grade,
+                }
+            },
+            credit: row.parse(Some(credit)),
+            hours: row.parse(hours),
+            gpa: row.parse(gpa),
+            exam_type: row.text_or_default(exam_type),
+            course_attr: row.text_or_default(course_attr),
+            course_nature: row.text_or_default(course_nature),
+        })
+        .collect())
 }
 
 #[derive(Serialize)]
@@ -73,33 +72,36 @@ pub fn parse_score(html: &str) -> Result<Vec<ScoreInfo>, String> {
 pub struct ScoreSummary {
     pub credit_total: f32,
     pub gpa_average: f32,
+    /// 按课程成绩计算的学分加权平均分，没有可计入的成绩时为 None
+    pub average_score: Option<f32>,
 }
-pub fn parse_score_summary(html: &str) -> Result<ScoreSummary, String> {
+pub fn parse_score_summary(html: &str) -> Result<ScoreSummary, ApiError> {
     let document = Html::parse_document(html);
-    let table_selector = Selector::parse(r#"table[style*="text-align: center"]"#)
-        .map_err(|_| "未找到成绩汇总表格".to_string())?;
+    let summary_selector = r#"table[style*="text-align: center"]"#;
+    let table_selector = Selector::parse(summary_selector)?;
     let tr_selector = Selector::parse("tr").unwrap();
     let td_selector = Selector::parse("td").unwrap();
     let table = document
         .select(&table_selector)
         .next()
-        .ok_or("未找到成绩汇总表格".to_string())?;
+        .ok_or_else(|| ApiError::layout("cjcx_list", summary_selector))?;
     let summary_row = table
         .select(&tr_selector)
         .next()
-        .ok_or("未找到成绩汇总行".to_string())?;
+        .ok_or_else(|| ApiError::layout("cjcx_list", "tr"))?;
     let tds: Vec<_> = summary_row.select(&td_selector).collect();
     if tds.len() < 8 {
-        return Err("成绩汇总行数据列不足".to_string());
+        return Err(ApiError::layout("cjcx_list", "td:nth-child(8)"));
     }
     let credit_text = tds[5].text().collect::<String>().trim().to_string();
     let gpa_text = tds[7].text().collect::<String>().trim().to_string();
-    let credit_total = credit_text.parse::<f32>().unwrap_or_else(|_| 0.0);
-    let gpa_average = gpa_text.parse::<f32>().unwrap_or_else(|_| 0.0);
+    let credit_total = credit_text.parse::<f32>().unwrap_or(0.0);
+    let gpa_average = gpa_text.parse::<f32>().unwrap_or(0.0);
 
     Ok(ScoreSummary {
         credit_total,
         gpa_average,
+        average_score: None,
     })
 }
 
@@ -109,8 +111,59 @@ pub struct ScoreTotal {
     pub info: Vec<ScoreInfo>,
     pub summary: ScoreSummary,
 }
-pub fn parse_score_all(html: &str) -> Result<ScoreTotal, String> {
-    let info = parse_score(html)?;
-    let summary = parse_score_summary(html)?;
+#[frb(ignore)]
+pub fn parse_score_all(html: &str, scale: &GradeScale) -> Result<ScoreTotal, ApiError> {
+    let info = parse_score(html, scale)?;
+    let mut summary = parse_score_summary(html)?;
+    summary.average_score = weighted_average(&info);
     Ok(ScoreTotal { info, summary })
 }
+
+/// 同一课程有补考、重修等多次成绩时只保留分数最高的一次，顺序与原列表一致
+#[frb(ignore)]
+pub fn effective_scores(scores: &[ScoreInfo]) -> Vec<&ScoreInfo> {
+    let mut best: HashMap<&str, &ScoreInfo> = HashMap::new();
+    for score in scores {
+        let entry = best.entry(score.course_id.as_str()).or_insert(score);
+        if score.score.value > entry.score.value {
+            *entry = score;
+        }
+    }
+    scores
+        .iter()
+        .filter(|score| {
+            best.get(score.course_id.as_str())
+                .is_some_and(|kept| std::ptr::eq(*kept, *score))
+        })
+        .collect()
+}
+
+fn credit_weighted(scores: &[ScoreInfo], value: impl Fn(&ScoreInfo) -> f32) -> Option<f32> {
+    let counted: Vec<_> = effective_scores(scores)
+        .into_iter()
+        .filter(|score| score.score.is_counted() && score.credit > 0.0)
+        .collect();
+    let credits: f32 = counted.iter().map(|score| score.credit).sum();
+    if credits <= 0.0 {
+        return None;
+    }
+    Some(
+        counted
+            .iter()
+            .map(|score| value(score) * score.credit)
+            .sum::<f32>()
+            / credits,
+    )
+}
+
+/// 学分加权平均分，免修课程不计入，缺考按 0 分计入
+#[frb(ignore)]
+pub fn weighted_average(scores: &[ScoreInfo]) -> Option<f32> {
+    credit_weighted(scores, |score| score.score.value.unwrap_or(0.0))
+}
+
+/// 按教务系统给出的绩点计算学分加权平均绩点，计入规则与 `weighted_average` 相同
+#[frb(ignore)]
+pub fn gpa_average(scores: &[ScoreInfo]) -> Option<f32> {
+    credit_weighted(scores, |score| score.gpa)
+}
@@ -0,0 +1,262 @@
+//! 选课：选课轮次（xklc_list）、可选课程列表（xsxkkc/xsxk*）以及选课、退课操作（*Oper）。
+//! 课程列表与选课结果为 JSON，其余为 HTML 页面
+
+use crate::api::{error::ApiError, jwxt::table::Table};
+use regex::Regex;
+use scraper::Html;
+use serde::Serialize;
+use serde_json::Value;
+
+/// 可选课程的类别，对应选课页面的各个标签页
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SelectionCategory {
+    /// 公选课
+    PublicElective,
+    /// 专业选修课
+    MajorElective,
+    /// 跨专业选课（培养方案外）
+    CrossMajor,
+}
+
+impl SelectionCategory {
+    /// 课程列表地址
+    pub(crate) fn list_path(self) -> &'static str {
+        match self {
+            Self::PublicElective => "/jsxsd/xsxkkc/xsxkGgxxkxk",
+            Self::MajorElective => "/jsxsd/xsxkkc/xsxkXxxk",
+            Self::CrossMajor => "/jsxsd/xsxkkc/xsxkFawxk",
+        }
+    }
+
+    /// 选课操作地址
+    pub(crate) fn oper_path(self) -> &'static str {
+        match self {
+            Self::PublicElective => "/jsxsd/xsxkkc/ggxxkxkOper",
+            Self::MajorElective => "/jsxsd/xsxkkc/xxxkOper",
+            Self::CrossMajor => "/jsxsd/xsxkkc/fawxkOper",
+        }
+    }
+}
+
+/// 退课操作地址
+pub(crate) const DROP_PATH: &str = "/jsxsd/xsxkjg/xstkOper";
+
+/// 一个选课轮次
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionRound {
+    pub id: String,       // jx0502zbid，不能进入时为空
+    pub semester: String, // "2024-2025-2"
+    pub name: String,
+    pub start_time: String, // "2025-02-20 10:00"
+    pub end_time: String,
+    pub can_enter: bool, // 页面上有 "进入选课" 链接
+}
+
+/// 可选的教学班
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectableCourse {
+    pub id: String, // 教学班 jx0404id，选课、退课时使用
+    pub category: SelectionCategory,
+    pub course_code: String,
+    pub course_name: String,
+    pub credits: f32,
+    pub teacher: String,
+    pub time: String,     // 上课时间，如 "1-16周 星期二 9-10节"
+    pub location: String, // 上课地点
+    pub campus: String,
+    pub capacity: u32,    // 容量
+    pub selected: u32,    // 已选人数
+    pub remaining: u32,   // 剩余名额
+    pub conflict: String, // 与已选课程的冲突说明，没有冲突时为空
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SelectionResult {
+    /// 选课或退课成功
+    Success,
+    /// 与已选课程上课时间冲突
+    TimeConflict,
+    /// 教学班人数已满
+    Full,
+    /// 不在选课时间内或选课未开放
+    OutsideWindow,
+    /// 其他原因失败，见 `message`
+    Failed,
+}
+
+/// 进入选课、选课、退课的结果，`message` 为教务系统返回的原始提示
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionOutcome {
+    pub result: SelectionResult,
+    pub message: String,
+}
+
+const OUTSIDE_WINDOW_KEYWORDS: &[&str] = &[
+    "不在选课时间",
+    "选课时间未到",
+    "选课已结束",
+    "未开放",
+    "不允许选课",
+];
+const FULL_KEYWORDS: &[&str] = &["已满", "人数已达上限", "无剩余", "没有余量"];
+
+fn classify(message: &str) -> SelectionResult {
+    if message.contains("冲突") {
+        SelectionResult::TimeConflict
+    } else if FULL_KEYWORDS
+        .iter()
+        .any(|keyword| message.contains(keyword))
+    {
+        SelectionResult::Full
+    } else if OUTSIDE_WINDOW_KEYWORDS
+        .iter()
+        .any(|keyword| message.contains(keyword))
+    {
+        SelectionResult::OutsideWindow
+    } else {
+        SelectionResult::Failed
+    }
+}
+
+/// 页面中 `alert('...')` 的提示文字
+fn alert_message(html: &str) -> Option<String> {
+    Regex::new(r#"alert\(\s*['"]([^'"]*)['"]"#)
+        .unwrap()
+        .captures(html)
+        .map(|caps| caps[1].trim().to_string())
+}
+
+pub fn parse_selection_rounds(html: &str) -> Result<Vec<SelectionRound>, ApiError> {
+    let document = Html::parse_document(html);
+    let table = Table::find(&document, "xklc_list", "table.Nsb_r_list.Nsb_table")?;
+    let name = table.column("选课名称")?;
+    let start_time = table.column("开始时间")?;
+    let end_time = table.column("结束时间")?;
+    let semester = table.optional_column("学年学期");
+    let operation = table.optional_column("操作");
+
+    let id_re = Regex::new(r"jx0502zbid=([0-9A-Za-z]+)").unwrap();
+    Ok(table
+        .rows()
+        .map(|row| {
+            let id = operation
+                .and_then(|column| row.cell(column))
+                .and_then(|cell| id_re.captures(&cell.html()).map(|caps| caps[1].to_string()))
+                .unwrap_or_default();
+            SelectionRound {
+                can_enter: !id.is_empty(),
+                id,
+                semester: row.text_or_default(semester),
+                name: row.text(name),
+                start_time: row.text(start_time),
+                end_time: row.text(end_time),
+            }
+        })
+        .collect())
+}
+
+/// 进入选课轮次（xsxk_index）的结果。未开放时页面只有一个 alert 提示
+pub fn parse_enter_result(html: &str) -> SelectionOutcome {
+    match alert_message(html) {
+        Some(message) => SelectionOutcome {
+            result: classify(&message),
+            message,
+        },
+        None if OUTSIDE_WINDOW_KEYWORDS
+            .iter()
+            .any(|keyword| html.contains(keyword)) =>
+        {
+            SelectionOutcome {
+                result: SelectionResult::OutsideWindow,
+                message: "选课未开放".to_string(),
+            }
+        }
+        None => SelectionOutcome {
+            result: SelectionResult::Success,
+            message: String::new(),
+        },
+    }
+}
+
+/// JSON 中的字段有时是字符串有时是数字，统一转为字符串
+fn json_text(row: &Value, key: &str) -> String {
+    match row.get(key) {
+        Some(Value::String(text)) => text.trim().to_string(),
+        Some(Value::Number(number)) => number.to_string(),
+        _ => String::new(),
+    }
+}
+
+fn json_parse<T: std::str::FromStr + Default>(row: &Value, key: &str) -> T {
+    json_text(row, key).parse().unwrap_or_default()
+}
+
+/// 解析课程列表接口返回的 JSON（DataTables 格式，课程在 `aaData` 中）。
+/// 没有进入选课轮次或选课已结束时接口返回 HTML 页面，此时返回 `ParseLayoutChanged`
+pub fn parse_selection_courses(
+    json: &str,
+    category: SelectionCategory,
+) -> Result<Vec<SelectableCourse>, ApiError> {
+    let page = category.list_path().trim_start_matches("/jsxsd/xsxkkc/");
+    let value: Value = serde_json::from_str(json).map_err(|_| ApiError::layout(page, "aaData"))?;
+    let rows = value
+        .get("aaData")
+        .and_then(Value::as_array)
+        .ok_or_else(|| ApiError::layout(page, "aaData"))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let capacity: u32 = json_parse(row, "pkrs");
+            let selected: u32 = json_parse(row, "xkrs");
+            let remaining = json_text(row, "syrs")
+                .parse()
+                .unwrap_or_else(|_| capacity.saturating_sub(selected));
+            SelectableCourse {
+                id: json_text(row, "jx0404id"),
+                category,
+                course_code: json_text(row, "kch"),
+                course_name: json_text(row, "kcmc"),
+                credits: json_parse(row, "xf"),
+                teacher: json_text(row, "skls"),
+                time: json_text(row, "sksj"),
+                location: json_text(row, "skdd"),
+                campus: json_text(row, "xqmc"),
+                capacity,
+                selected,
+                remaining,
+                conflict: json_text(row, "ctsm"),
+            }
+        })
+        .collect())
+}
+
+/// 解析选课、退课接口的结果 `{"success": true, "message": "..."}`。
+/// 不在选课时间内时接口可能直接返回带 alert 的 HTML 页面
+pub fn parse_selection_outcome(text: &str) -> Result<SelectionOutcome, ApiError> {
+    if let Ok(value) = serde_json::from_str::<Value>(text) {
+        let success = match value.get("success") {
+            Some(Value::Bool(success)) => *success,
+            Some(Value::String(success)) => success == "true",
+            _ => return Err(ApiError::layout("xsxkOper", "success")),
+        };
+        let message = json_text(&value, "message");
+        return Ok(SelectionOutcome {
+            result: if success {
+                SelectionResult::Success
+            } else {
+                classify(&message)
+            },
+            message,
+        });
+    }
+
+    let outcome = parse_enter_result(text);
+    match outcome.result {
+        SelectionResult::Success => Err(ApiError::layout("xsxkOper", "success")),
+        _ => Ok(outcome),
+    }
+}
@@ -1,44 +1,152 @@
+use crate::api::{error::ApiError, ics::parse_date, jwxt::calendar::parse_school_calendar};
+use chrono::{Datelike, Duration, NaiveDate};
+use flutter_rust_bridge::frb;
 use scraper::{Html, Selector};
 use serde::Serialize;
+use std::cmp::Reverse;
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SemesterInfo {
     pub key: String,
     pub value: String,
+    pub academic_year: String, // 学年 "2024-2025"，"全部学期" 为空
+    pub term: u8,              // 学期序号，无法识别时为 0
+    pub is_current: bool,      // 教务系统默认选中的学期
 }
-pub fn parse_semester(html: &str, is_all: bool) -> Result<Vec<SemesterInfo>, String> {
-    let document = Html::parse_document(html);
-    let selector = Selector::parse(".Nsb_layout_r table tr td").unwrap();
 
-    let mut semesters = Vec::new();
+/// 学期的第一天，取自校历页面或由用户设置
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemesterStart {
+    pub semester: String,   // "2024-2025-2"
+    pub start_date: String, // 第一教学周的周一 "2025-02-24"
+}
 
-    if let Some(td) = document.select(&selector).next() {
-        let raw_text = td.text().collect::<Vec<_>>().join("").trim().to_string();
+/// 今天所在的教学周
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentWeek {
+    pub semester: String,
+    pub start_date: String,
+    pub week: i32,           // 第几教学周，开学前一周为 0，再往前为负数
+    pub weekday: u32,        // 星期几，1 为周一，7 为周日
+    pub is_overridden: bool, // 开学日期来自用户设置而不是校历
+}
 
-        let mut semester_list = raw_text
-            .split('\n')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
+/// 拆分学期代码 "2023-2024-1" 为学年 "2023-2024" 与学期序号 1，格式不符时为 None
+pub fn split_semester(semester: &str) -> Option<(String, u8)> {
+    let (year, term) = semester.trim().rsplit_once('-')?;
+    let term = term.parse::<u8>().ok()?;
+    let (start, end) = year.split_once('-')?;
+    if start.len() != 4
+        || end.len() != 4
+        || start.parse::<u16>().is_err()
+        || end.parse::<u16>().is_err()
+    {
+        return None;
+    }
+    Some((year.to_string(), term))
+}
 
-        semester_list.sort_by(|a, b| b.cmp(a));
-        semester_list.truncate(10);
+fn semester_info(value: String, is_current: bool) -> SemesterInfo {
+    let (academic_year, term) = split_semester(&value).unwrap_or_default();
+    SemesterInfo {
+        key: value.clone(),
+        value,
+        academic_year,
+        term,
+        is_current,
+    }
+}
 
-        if is_all {
-            semesters.push(SemesterInfo {
-                key: "全部学期".to_string(),
-                value: "".to_string(),
-            });
-        }
+/// 解析学期下拉框，按时间倒序保留最近 10 个学期，选中的选项记为当前学期
+pub fn parse_semester(html: &str, is_all: bool) -> Result<Vec<SemesterInfo>, ApiError> {
+    let document = Html::parse_document(html);
+    let selector = ".Nsb_layout_r select option";
+    let option_selector = Selector::parse(selector)?;
 
-        for sem in semester_list {
-            semesters.push(SemesterInfo {
-                key: sem.clone(),
-                value: sem,
-            });
+    let mut semester_list: Vec<SemesterInfo> = Vec::new();
+    for option in document.select(&option_selector) {
+        let value = option
+            .value()
+            .attr("value")
+            .map(str::to_string)
+            .unwrap_or_else(|| option.text().collect::<String>())
+            .trim()
+            .to_string();
+        if value.is_empty() || semester_list.iter().any(|s| s.value == value) {
+            continue;
         }
+        let is_current = option.value().attr("selected").is_some();
+        semester_list.push(semester_info(value, is_current));
     }
+    if semester_list.is_empty() {
+        return Err(ApiError::layout("xsksap_query", selector));
+    }
+
+    semester_list.sort_by_key(|s| {
+        (
+            s.academic_year.is_empty(),
+            Reverse((s.academic_year.clone(), s.term)),
+        )
+    });
+    semester_list.truncate(10);
+
+    let mut semesters = Vec::new();
+    if is_all {
+        semesters.push(SemesterInfo {
+            key: "全部学期".to_string(),
+            value: "".to_string(),
+            academic_year: "".to_string(),
+            term: 0,
+            is_current: false,
+        });
+    }
+    semesters.extend(semester_list);
     Ok(semesters)
-}
\ No newline at end of file
+}
+
+/// 页面没有选中学期时，按校历中的学期标记当前学期
+#[frb(ignore)]
+pub fn mark_current(semesters: &mut [SemesterInfo], current: &str) {
+    for semester in semesters {
+        semester.is_current = !semester.value.is_empty() && semester.value == current;
+    }
+}
+
+/// 解析校历页面（jxzl_query）：选中的学期及其第一周的周一
+pub fn parse_semester_start(html: &str) -> Result<SemesterStart, ApiError> {
+    let calendar = parse_school_calendar(html)?;
+    Ok(SemesterStart {
+        semester: calendar.semester,
+        start_date: calendar.start_date,
+    })
+}
+
+fn week_monday(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// `date` 所在的教学周，`start` 所在的一周为第 1 周
+#[frb(ignore)]
+pub fn teaching_week(start: NaiveDate, date: NaiveDate) -> i32 {
+    let days = (date - week_monday(start)).num_days();
+    days.div_euclid(7) as i32 + 1
+}
+
+#[frb(ignore)]
+pub fn current_week(
+    start: &SemesterStart,
+    today: NaiveDate,
+    is_overridden: bool,
+) -> Result<CurrentWeek, ApiError> {
+    let start_date = parse_date(&start.start_date)?;
+    Ok(CurrentWeek {
+        semester: start.semester.clone(),
+        start_date: start.start_date.clone(),
+        week: teaching_week(start_date, today),
+        weekday: today.weekday().number_from_monday(),
+        is_overridden,
+    })
+}
@@ -0,0 +1,97 @@
+//! 教务系统列表页的表格都是第一行 `<th>` 表头、其余行 `<td>` 数据的结构，
+//! 这里按表头文字定位列，学校增删或调整列顺序时解析结果不受影响
+
+use crate::api::error::ApiError;
+use scraper::{ElementRef, Html, Selector};
+use std::str::FromStr;
+
+pub(crate) struct Table<'a> {
+    page: &'static str,
+    headers: Vec<String>,
+    rows: Vec<Vec<ElementRef<'a>>>,
+}
+
+/// 表格中的一行数据
+pub(crate) struct Row<'t, 'a> {
+    cells: &'t [ElementRef<'a>],
+}
+
+/// 单元格文字，去掉首尾空白
+fn cell_text(cell: &ElementRef) -> String {
+    cell.text().collect::<String>().trim().to_string()
+}
+
+impl<'a> Table<'a> {
+    /// 取页面中第一个匹配 `selector` 的表格，找不到时返回 `ParseLayoutChanged`
+    pub fn find(document: &'a Html, page: &'static str, selector: &str) -> Result<Self, ApiError> {
+        let table_selector = Selector::parse(selector)?;
+        let table = document
+            .select(&table_selector)
+            .next()
+            .ok_or_else(|| ApiError::layout(page, selector))?;
+        Ok(Self::new(page, table))
+    }
+
+    /// 读取表头与数据行。单元格数少于表头列数的行（如 "未查询到数据"）会被跳过
+    pub fn new(page: &'static str, table: ElementRef<'a>) -> Self {
+        let row_selector = Selector::parse("tr").unwrap();
+        let header_selector = Selector::parse("th").unwrap();
+        let cell_selector = Selector::parse("td").unwrap();
+
+        let headers: Vec<String> = table
+            .select(&header_selector)
+            .map(|th| th.text().flat_map(str::split_whitespace).collect())
+            .collect();
+        let rows = table
+            .select(&row_selector)
+            .map(|row| row.select(&cell_selector).collect::<Vec<_>>())
+            .filter(|cells| !cells.is_empty() && cells.len() >= headers.len())
+            .collect();
+
+        Self {
+            page,
+            headers,
+            rows,
+        }
+    }
+
+    /// 必需列的下标，表头中没有这一列时返回指明页面与表头的错误
+    pub fn column(&self, header: &str) -> Result<usize, ApiError> {
+        self.optional_column(header)
+            .ok_or_else(|| ApiError::missing_column(self.page, header))
+    }
+
+    /// 可选列的下标，缺失时该列按空值处理
+    pub fn optional_column(&self, header: &str) -> Option<usize> {
+        self.headers.iter().position(|h| h == header)
+    }
+
+    /// 表头文字，已去掉空白
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = Row<'_, 'a>> {
+        self.rows.iter().map(|cells| Row { cells })
+    }
+}
+
+impl<'a> Row<'_, 'a> {
+    pub fn cell(&self, column: usize) -> Option<ElementRef<'a>> {
+        self.cells.get(column).copied()
+    }
+
+    pub fn text(&self, column: usize) -> String {
+        self.cells.get(column).map(cell_text).unwrap_or_default()
+    }
+
+    /// 可选列的文字，列不存在时为空字符串
+    pub fn text_or_default(&self, column: Option<usize>) -> String {
+        column.map(|column| self.text(column)).unwrap_or_default()
+    }
+
+    /// 解析数字等类型，无法解析或列不存在时返回默认值
+    pub fn parse<T: FromStr + Default>(&self, column: Option<usize>) -> T {
+        self.text_or_default(column).parse().unwrap_or_default()
+    }
+}
@@ -1,5 +1,8 @@
 pub mod aescbc;
 pub mod conwork;
+pub mod error;
+pub mod ics;
 pub mod jwxt;
+pub mod seat_watcher;
 pub mod session;
 pub mod simple;
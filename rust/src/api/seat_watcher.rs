@@ -0,0 +1,170 @@
+//! 抢课：定时查询已满教学班的剩余名额，出现空位时立即选课
+
+use crate::api::{
+    error::ApiError,
+    jwxt::selection::{SelectableCourse, SelectionCategory, SelectionOutcome, SelectionResult},
+    session::HttpSession,
+    simple::{fetch_selection_courses, submit_selection},
+};
+use flutter_rust_bridge::frb;
+use std::time::Duration;
+
+/// 轮询间隔的下限，避免给教务系统造成压力
+pub const MIN_INTERVAL_MS: u32 = 3000;
+
+#[derive(Debug, Clone)]
+pub struct SeatWatchConfig {
+    pub category: SelectionCategory,
+    pub course_ids: Vec<String>, // 要监视的教学班 jx0404id
+    pub interval_ms: u32,        // 两次查询之间的间隔
+    pub jitter_ms: u32,          // 每次在间隔上随机增加 0 到该值的时间
+    pub max_rounds: Option<u32>, // 最多查询几轮，None 时一直查询到结束
+}
+
+#[derive(Debug, Clone)]
+pub enum SeatWatchEvent {
+    /// 完成一轮查询，`courses` 为仍在等待的教学班的最新名额
+    Polled {
+        round: u32,
+        courses: Vec<SelectableCourse>,
+    },
+    /// 出现空位后尝试选课的结果
+    Attempted {
+        course: SelectableCourse,
+        outcome: SelectionOutcome,
+    },
+    /// 查询或选课出错，会话失效、页面结构变化以外的错误之后仍会继续查询
+    Error { message: String },
+    /// 监视结束，`pending` 为没有选上的教学班
+    Finished {
+        selected: Vec<String>,
+        pending: Vec<String>,
+    },
+}
+
+/// 会话失效后继续查询没有意义；不在选课轮次内或选课已结束时课程列表返回的是 HTML 页面，
+/// 解析为 `ParseLayoutChanged`，重试也不会恢复
+fn is_fatal(error: &ApiError) -> bool {
+    matches!(
+        error,
+        ApiError::SessionExpired
+            | ApiError::NotLoggedIn
+            | ApiError::NotInitialized
+            | ApiError::ParseLayoutChanged { .. }
+    )
+}
+
+fn next_delay(config: &SeatWatchConfig) -> Duration {
+    let jitter = match config.jitter_ms {
+        0 => 0,
+        jitter => rand::random_range(0..=jitter),
+    };
+    Duration::from_millis(config.interval_ms as u64 + jitter as u64)
+}
+
+/// 用 `session` 按 `config` 轮询并自动选课，每个进度通过 `emit` 送出，`emit` 返回 false 时（如 Dart 端已取消订阅）
+/// 立即停止。选上、时间冲突或其他原因无法选课的教学班不再监视；人数已满时继续等待；
+/// 不在选课时间内时全部停止。不限制轮询间隔，调用方负责保证间隔合理
+#[frb(ignore)]
+pub async fn watch_seats(
+    session: &HttpSession,
+    config: &SeatWatchConfig,
+    mut emit: impl FnMut(SeatWatchEvent) -> bool,
+) {
+    let mut pending: Vec<String> = Vec::new();
+    for id in &config.course_ids {
+        if !pending.contains(id) {
+            pending.push(id.clone());
+        }
+    }
+    let mut selected = Vec::new();
+    let mut round = 0;
+
+    while !pending.is_empty() && config.max_rounds.is_none_or(|max| round < max) {
+        if round > 0 {
+            tokio::time::sleep(next_delay(config)).await;
+        }
+        round += 1;
+
+        let courses = match fetch_selection_courses(session, config.category, "").await {
+            Ok(courses) => courses,
+            Err(error) => {
+                let fatal = is_fatal(&error);
+                if !emit(SeatWatchEvent::Error {
+                    message: error.to_string(),
+                }) {
+                    return;
+                }
+                if fatal {
+                    break;
+                }
+                continue;
+            }
+        };
+
+        let watched: Vec<SelectableCourse> = courses
+            .into_iter()
+            .filter(|course| pending.contains(&course.id))
+            .collect();
+        let missing: Vec<String> = pending
+            .iter()
+            .filter(|id| !watched.iter().any(|course| &course.id == *id))
+            .cloned()
+            .collect();
+        for id in missing {
+            pending.retain(|pending_id| *pending_id != id);
+            if !emit(SeatWatchEvent::Error {
+                message: format!("教学班 {} 不在可选课程中", id),
+            }) {
+                return;
+            }
+        }
+        if !emit(SeatWatchEvent::Polled {
+            round,
+            courses: watched.clone(),
+        }) {
+            return;
+        }
+
+        let mut window_closed = false;
+        for course in watched.into_iter().filter(|course| course.remaining > 0) {
+            let outcome = match submit_selection(session, config.category, &course.id).await {
+                Ok(outcome) => outcome,
+                Err(error) => {
+                    let fatal = is_fatal(&error);
+                    if !emit(SeatWatchEvent::Error {
+                        message: error.to_string(),
+                    }) {
+                        return;
+                    }
+                    if fatal {
+                        window_closed = true;
+                        break;
+                    }
+                    continue;
+                }
+            };
+            match outcome.result {
+                SelectionResult::Full => {}
+                SelectionResult::OutsideWindow => window_closed = true,
+                result => {
+                    pending.retain(|id| *id != course.id);
+                    if result == SelectionResult::Success {
+                        selected.push(course.id.clone());
+                    }
+                }
+            }
+            if !emit(SeatWatchEvent::Attempted { course, outcome }) {
+                return;
+            }
+            if window_closed {
+                break;
+            }
+        }
+        if window_closed {
+            break;
+        }
+    }
+
+    emit(SeatWatchEvent::Finished { selected, pending });
+}
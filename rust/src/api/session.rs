@@ -1,19 +1,100 @@
-use crate::api::{aescbc::aes_cbc_encrypt, conwork::encode_inp};
+use crate::api::{aescbc::aes_cbc_encrypt, conwork::encode_inp, error::ApiError};
 use regex::Regex;
-use reqwest::header::HeaderMap;
-use reqwest::Client;
+use reqwest::header::{HeaderMap, CONTENT_TYPE};
+use reqwest::{Client, RequestBuilder, Url};
 use reqwest_cookie_store::{CookieStore, CookieStoreMutex};
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::sync::{Arc, Mutex};
 #[derive(Clone)]
 pub struct HttpSession {
     pub client: Client,
     pub _cookie_store: Arc<CookieStoreMutex>,
+    credentials: Arc<Mutex<Option<Credentials>>>,
+    relogin_lock: Arc<tokio::sync::Mutex<()>>,
+    state: Arc<Mutex<LoginState>>,
+    endpoints: Arc<Endpoints>,
+}
+
+/// 服务地址，默认经由学校 WebVPN 访问，也可以指向校内直连地址或本地模拟服务
+#[derive(Debug, Clone)]
+pub struct Endpoints {
+    /// VPN 统一认证地址
+    pub cas: String,
+    /// 教务系统地址
+    pub jwxt: String,
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Self {
+            cas: "https://oa-443.v.hbfu.edu.cn".to_string(),
+            jwxt: "https://jw.v.hbfu.edu.cn".to_string(),
+        }
+    }
+}
+
+/// 登录状态机：NoSession → CaptchaFetched → VpnAuthenticated → JwxtAuthenticated → Expired
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginState {
+    /// 尚未创建会话
+    NoSession,
+    /// 已获取验证码，等待登录
+    CaptchaFetched,
+    /// VPN 登录成功，教务系统尚未登录
+    VpnAuthenticated,
+    /// 教务系统登录成功
+    JwxtAuthenticated,
+    /// 会话已过期且无法自动重新登录
+    Expired,
+}
+
+#[derive(Clone)]
+struct Credentials {
+    username: String,
+    vpn_password: String,
+    oa_password: String,
+}
+
+/// 请求被重定向到的登录页类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LoginPage {
+    /// VPN 的 CAS 统一认证页，需要重新登录 VPN
+    Cas,
+    /// 教务系统 jsxsd 登录页，VPN 仍然有效
+    Jwxt,
+}
+
+fn detect_login_page(url: &Url, text: &str) -> Option<LoginPage> {
+    let path = url.path();
+    if path.contains("/cas/login") || text.contains("flowExecutionKey") {
+        return Some(LoginPage::Cas);
+    }
+    if path.ends_with("/xk/LoginToXk") || (text.contains("用户登录") && text.contains("LoginToXk"))
+    {
+        return Some(LoginPage::Jwxt);
+    }
+    None
+}
+
+impl Default for HttpSession {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl HttpSession {
     pub fn new() -> Self {
-        let cookie_store = Arc::new(CookieStoreMutex::new(CookieStore::default()));
+        Self::with_endpoints(Endpoints::default())
+    }
+
+    pub fn with_endpoints(endpoints: Endpoints) -> Self {
+        Self::build(CookieStore::default(), endpoints)
+    }
+
+    fn build(cookie_store: CookieStore, endpoints: Endpoints) -> Self {
+        let cookie_store = Arc::new(CookieStoreMutex::new(cookie_store));
         let client = Client::builder()
             .cookie_provider(cookie_store.clone())
             .build()
@@ -22,52 +103,256 @@ impl HttpSession {
         Self {
             client,
             _cookie_store: cookie_store,
+            credentials: Arc::new(Mutex::new(None)),
+            relogin_lock: Arc::new(tokio::sync::Mutex::new(())),
+            state: Arc::new(Mutex::new(LoginState::NoSession)),
+            endpoints: Arc::new(endpoints),
+        }
+    }
+
+    fn cas_url(&self, path: &str) -> String {
+        format!("{}{}", self.endpoints.cas.trim_end_matches('/'), path)
+    }
+
+    pub(crate) fn jwxt_url(&self, path: &str) -> String {
+        format!("{}{}", self.endpoints.jwxt.trim_end_matches('/'), path)
+    }
+
+    pub fn login_state(&self) -> LoginState {
+        self.state
+            .lock()
+            .map(|state| *state)
+            .unwrap_or(LoginState::Expired)
+    }
+
+    pub(crate) fn set_login_state(&self, login_state: LoginState) {
+        if let Ok(mut state) = self.state.lock() {
+            *state = login_state;
         }
     }
-    pub async fn get_captcha(&self) -> Result<Vec<u8>, String> {
+
+    /// 保存账号密码，会话过期时用于免验证码重新登录
+    pub fn set_credentials(&self, username: &str, vpn_password: &str, oa_password: &str) {
+        if let Ok(mut credentials) = self.credentials.lock() {
+            *credentials = Some(Credentials {
+                username: username.to_string(),
+                vpn_password: vpn_password.to_string(),
+                oa_password: oa_password.to_string(),
+            });
+        }
+    }
+
+    fn credentials(&self) -> Option<Credentials> {
+        self.credentials.lock().ok().and_then(|c| c.clone())
+    }
+
+    /// 请求教务系统页面，`path` 为相对教务系统地址的路径
+    pub(crate) async fn get_page(&self, path: &str) -> Result<String, ApiError> {
+        let url = self.jwxt_url(path);
+        self.fetch_page(|| self.client.get(&url)).await
+    }
+
+    /// 与 `get_page` 相同，`query` 中的参数经过 URL 编码后附加在地址上
+    pub(crate) async fn get_page_with_query(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<String, ApiError> {
+        let mut url = Url::parse(&self.jwxt_url(path)).map_err(|e| ApiError::Internal {
+            cause: format!("教务系统地址无效 {}: {}", path, e),
+        })?;
+        url.query_pairs_mut().extend_pairs(query);
+        self.fetch_page(|| self.client.get(url.clone())).await
+    }
+
+    pub(crate) async fn post_page(
+        &self,
+        path: &str,
+        form: &HashMap<&str, &str>,
+    ) -> Result<String, ApiError> {
+        let url = self.jwxt_url(path);
+        self.fetch_page(|| self.client.post(&url).form(form)).await
+    }
+
+    /// 发送请求并检查响应是否为登录页，会话过期时重新登录后重试一次
+    async fn fetch_page<F>(&self, request: F) -> Result<String, ApiError>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let res = request().send().await?;
+        let url = res.url().clone();
+        let text = res.text().await?;
+        let Some(login_page) = detect_login_page(&url, &text) else {
+            return Ok(text);
+        };
+
+        self.relogin(login_page).await?;
+
+        let res = request().send().await?;
+        let url = res.url().clone();
+        let text = res.text().await?;
+        match detect_login_page(&url, &text) {
+            Some(_) => {
+                self.set_login_state(LoginState::Expired);
+                Err(ApiError::SessionExpired)
+            }
+            None => Ok(text),
+        }
+    }
+
+    /// 下载图片等二进制内容，返回内容与 Content-Type。`url` 为完整地址，
+    /// 响应为登录页时与 `get_page` 一样重新登录后重试一次
+    pub(crate) async fn get_bytes(&self, url: &str) -> Result<(Vec<u8>, Option<String>), ApiError> {
+        let mut relogged = false;
+        loop {
+            let res = self.client.get(url).send().await?;
+            if !res.status().is_success() {
+                return Err(ApiError::Network {
+                    cause: format!("下载 {} 失败: {}", res.url().path(), res.status()),
+                });
+            }
+            let res_url = res.url().clone();
+            let content_type = res
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
+            let bytes = res.bytes().await?.to_vec();
+
+            let is_html = content_type
+                .as_deref()
+                .is_some_and(|value| value.starts_with("text/html"));
+            let login_page = is_html
+                .then(|| detect_login_page(&res_url, &String::from_utf8_lossy(&bytes)))
+                .flatten();
+            match login_page {
+                None => return Ok((bytes, content_type)),
+                Some(_) if relogged => {
+                    self.set_login_state(LoginState::Expired);
+                    return Err(ApiError::SessionExpired);
+                }
+                Some(login_page) => {
+                    self.relogin(login_page).await?;
+                    relogged = true;
+                }
+            }
+        }
+    }
+
+    /// 使用保存的账号密码重新登录教务系统，只有 VPN 也失效时才重新登录 VPN
+    async fn relogin(&self, login_page: LoginPage) -> Result<(), ApiError> {
+        let _guard = self.relogin_lock.lock().await;
+        // 等待期间其他请求可能已经完成了重新登录
+        if self.is_alive().await? {
+            return Ok(());
+        }
+        let result = self.relogin_with_credentials(login_page).await;
+        self.set_login_state(match result {
+            Ok(()) => LoginState::JwxtAuthenticated,
+            Err(_) => LoginState::Expired,
+        });
+        result
+    }
+
+    async fn relogin_with_credentials(&self, login_page: LoginPage) -> Result<(), ApiError> {
+        let credentials = self.credentials().ok_or(ApiError::SessionExpired)?;
+
+        if login_page == LoginPage::Cas || !self.access_jwxt().await? {
+            // CAS 在未要求验证码时可以直接登录，否则只能交给用户输入验证码。
+            // 网络错误等与账号无关的错误原样返回
+            self.login_vpn(&credentials.username, &credentials.vpn_password, "")
+                .await
+                .map_err(|error| match error {
+                    ApiError::BadCaptcha | ApiError::BadCredentials { .. } => {
+                        ApiError::SessionExpired
+                    }
+                    error => error,
+                })?;
+            if !self.access_jwxt().await? {
+                return Err(ApiError::SessionExpired);
+            }
+        }
+
+        if !self
+            .login_jwxt(&credentials.username, &credentials.oa_password)
+            .await?
+        {
+            return Err(ApiError::SessionExpired);
+        }
+        Ok(())
+    }
+
+    /// 从 `save_cookies` 写出的文件重建会话
+    pub fn load_cookies(path: &str, endpoints: Endpoints) -> Result<Self, ApiError> {
+        let file = File::open(path)?;
+        let cookie_store =
+            cookie_store::serde::json::load(BufReader::new(file)).map_err(|e| ApiError::Io {
+                cause: format!("解析 Cookie 文件失败: {}", e),
+            })?;
+        Ok(Self::build(cookie_store, endpoints))
+    }
+
+    /// 保存全部 Cookie，JSESSIONID 等会话 Cookie 没有过期时间，也需要一并写出
+    pub fn save_cookies(&self, path: &str) -> Result<(), ApiError> {
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+        let store = self._cookie_store.lock().map_err(|_| ApiError::Internal {
+            cause: "Cookie 存储锁定失败".to_string(),
+        })?;
+        cookie_store::serde::json::save_incl_expired_and_nonpersistent(&store, &mut writer).map_err(
+            |e| ApiError::Io {
+                cause: format!("写入 Cookie 文件失败: {}", e),
+            },
+        )
+    }
+
+    /// 访问教务系统主页，判断服务端会话是否仍然有效
+    pub async fn is_alive(&self) -> Result<bool, ApiError> {
+        let res = self
+            .client
+            .get(self.jwxt_url("/jsxsd/framework/xsMain.jsp"))
+            .send()
+            .await?;
+        if !res.status().is_success() {
+            return Ok(false);
+        }
+        let text = res.text().await?;
+
+        Ok(text.contains("学生个人中心"))
+    }
+    pub async fn get_captcha(&self) -> Result<Vec<u8>, ApiError> {
         let r = rand::random::<f64>();
-        let url = format!(
-            "https://oa-443.v.hbfu.edu.cn/backstage/cas/captcha.jpg?r={}",
-            r
-        );
+        let url = self.cas_url(&format!("/backstage/cas/captcha.jpg?r={}", r));
         let mut headers = HeaderMap::new();
         headers.insert("Content-Type", "text/html;charset=utf-8".parse().unwrap());
         headers.insert("Vary", "Accept-Encoding".parse().unwrap());
         headers.insert("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/136.0.0.0 Safari/537.36".parse().unwrap());
-        let resp = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+        let resp = self.client.get(&url).headers(headers).send().await?;
         let status = resp.status();
         if !status.is_success() {
-            return Err(format!("获取验证码失败: {}", status));
+            return Err(ApiError::Network {
+                cause: format!("获取验证码失败: {}", status),
+            });
         }
-        let bytes = resp.bytes().await.map_err(|e| e.to_string())?;
+        let bytes = resp.bytes().await?;
+        self.set_login_state(LoginState::CaptchaFetched);
         Ok(bytes.to_vec())
     }
 
-    pub async fn get_flow_execution_key(&self) -> Result<String, String> {
+    pub async fn get_flow_execution_key(&self) -> Result<String, ApiError> {
         let response = self
             .client
-            .get("https://oa-443.v.hbfu.edu.cn/backstage/cas/login")
+            .get(self.cas_url("/backstage/cas/login"))
             .send()
-            .await
-            .map_err(|e| e.to_string())?;
-        let text = response.text().await.map_err(|e| e.to_string())?;
-        let pattern = Regex::new(r#"flowExecutionKey: "(.*?)""#).map_err(|e| e.to_string())?;
-
-        if let Some(captures) = pattern.captures(&text) {
-            if let Some(matched) = captures.get(1) {
-                Ok(matched.as_str().to_string())
-            } else {
-                Err("未找到 flowExecutionKey".to_string())
-            }
-        } else {
-            Err("未找到 flowExecutionKey".to_string())
-        }
+            .await?;
+        let text = response.text().await?;
+        let pattern = Regex::new(r#"flowExecutionKey: "(.*?)""#).unwrap();
+
+        pattern
+            .captures(&text)
+            .and_then(|captures| captures.get(1))
+            .map(|matched| matched.as_str().to_string())
+            .ok_or_else(|| ApiError::layout("cas/login", "flowExecutionKey"))
     }
 
     async fn login_vpn(
@@ -75,10 +360,13 @@ impl HttpSession {
         username: &str,
         password: &str,
         captcha: &str,
-    ) -> Result<bool, String> {
+    ) -> Result<(), ApiError> {
         let flow_execution_key = self.get_flow_execution_key().await?;
-        let encrypted_password =
-            aes_cbc_encrypt(password).map_err(|e| format!("密码加密失败: {}", e))?;
+        let encrypted_password = aes_cbc_encrypt(password)?;
+        let domain = Url::parse(&self.endpoints.cas)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_default();
 
         let mut form_data = HashMap::new();
         form_data.insert("username", username);
@@ -87,22 +375,31 @@ impl HttpSession {
         form_data.insert("_eventId", "submit");
         form_data.insert("captcha", captcha);
         form_data.insert("rememberMe", "false");
-        form_data.insert("domain", "oa-443.v.hbfu.edu.cn");
+        form_data.insert("domain", &domain);
 
         let res = self
             .client
-            .post("https://oa-443.v.hbfu.edu.cn/backstage/cas/login")
+            .post(self.cas_url("/backstage/cas/login"))
             .form(&form_data)
             .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .await?;
 
-        let text = res.text().await.map_err(|e| e.to_string())?;
+        let text = res.text().await?;
 
-        Ok(text.contains("修改密码"))
+        if text.contains("修改密码") {
+            self.set_login_state(LoginState::VpnAuthenticated);
+            return Ok(());
+        }
+        let captcha_error = Regex::new(r"验证码(错误|不正确|有误|输入错误|已失效)").unwrap();
+        if captcha_error.is_match(&text) {
+            return Err(ApiError::BadCaptcha);
+        }
+        Err(ApiError::BadCredentials {
+            message: "VPN登录失败,请检查账号密码".to_string(),
+        })
     }
 
-    pub async fn access_jwxt(&self) -> Result<bool, String> {
+    pub async fn access_jwxt(&self) -> Result<bool, ApiError> {
         let mut headers = HeaderMap::new();
         headers.insert("Content-Type", "text/html;charset=utf-8".parse().unwrap());
         headers.insert("Vary", "Accept-Encoding".parse().unwrap());
@@ -115,33 +412,35 @@ impl HttpSession {
 
         let res = self
             .client
-            .get("https://jw.v.hbfu.edu.cn/")
+            .get(self.jwxt_url("/"))
             .headers(headers)
             .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .await?;
 
-        let text = res.text().await.map_err(|e| e.to_string())?;
+        let text = res.text().await?;
 
         Ok(text.contains("用户登录"))
     }
 
-    async fn login_jwxt(&self, username: &str, password: &str) -> Result<bool, String> {
+    async fn login_jwxt(&self, username: &str, password: &str) -> Result<bool, ApiError> {
         let encoded_username = encode_inp(username);
         let encoded_password = encode_inp(password);
         let encoded_data = format!("{}%%%{}", encoded_username, encoded_password);
 
         let response = self
             .client
-            .post("https://jw.v.hbfu.edu.cn/jsxsd/xk/LoginToXk")
+            .post(self.jwxt_url("/jsxsd/xk/LoginToXk"))
             .form(&[("encoded", &encoded_data)])
             .send()
-            .await
-            .map_err(|e| e.to_string())?;
+            .await?;
 
-        let text = response.text().await.map_err(|e| e.to_string())?;
+        let text = response.text().await?;
+        let success = text.contains("学生个人中心");
+        if success {
+            self.set_login_state(LoginState::JwxtAuthenticated);
+        }
 
-        Ok(text.contains("学生个人中心"))
+        Ok(success)
     }
 
     pub async fn complete_login(
@@ -150,21 +449,21 @@ impl HttpSession {
         vpn_password: &str,
         oa_password: &str,
         captcha: &str,
-    ) -> Result<String, String> {
-        let vpn_login_result = self.login_vpn(username, vpn_password, captcha).await?;
-        if !vpn_login_result {
-            return Err("VPN登录失败,请检查账号密码".to_string());
-        }
+    ) -> Result<String, ApiError> {
+        self.login_vpn(username, vpn_password, captcha).await?;
 
         let access_jwxt = self.access_jwxt().await?;
         if !access_jwxt {
-            return Err("教务系统访问失败".to_string());
+            return Err(ApiError::layout("jwxt", "用户登录"));
         }
 
         let jwxt_login_result = self.login_jwxt(username, oa_password).await?;
         if !jwxt_login_result {
-            return Err("教务系统登录失败,请检查账号密码".to_string());
+            return Err(ApiError::BadCredentials {
+                message: "教务系统登录失败,请检查账号密码".to_string(),
+            });
         }
+        self.set_credentials(username, vpn_password, oa_password);
         Ok("登录成功".to_string())
     }
 }
@@ -1,28 +1,140 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
 use flutter_rust_bridge::frb;
-use once_cell::sync::OnceCell;
-use tokio::sync::Mutex;
+use once_cell::sync::{Lazy, OnceCell};
 
 use crate::api::{
+    error::ApiError,
+    ics::{beijing_now, course_events, exam_events, parse_date, write_calendar, SectionTime},
     jwxt::{
+        audit::{degree_audit, DegreeAudit},
+        calendar::{parse_school_calendar, SchoolCalendar},
+        classroom::{
+            parse_classroom_filters, parse_free_classrooms, ClassroomFilters, FreeClassroom,
+        },
         course::{parse_course_schedule, CourseSchedule},
         dekt::{parse_dekt, parse_dekt_detail, DEKTDetail, DEKT},
         elective::{parse_elective, ElectiveResponse},
-        exam::{parse_exam, ExamSchedule},
-        info::{parse_student_info, StudentInfo},
+        exam::{exam_countdown, parse_exam, ExamCountdown, ExamSchedule},
+        gpa::{gpa_report, GpaFilter, GpaReport, GpaScheme},
+        grade::GradeScale,
+        graduation::{graduation_check, DektRequirement, GraduationReport},
+        info::{parse_photo_src, parse_student_info, StudentInfo, StudentPhoto},
         plan::{parse_plan, ExecutionPlanResponse},
         score::{parse_score_all, ScoreTotal},
-        semester::{parse_semester, SemesterInfo},
+        selection::{
+            parse_enter_result, parse_selection_courses, parse_selection_outcome,
+            parse_selection_rounds, SelectableCourse, SelectionCategory, SelectionOutcome,
+            SelectionRound, DROP_PATH,
+        },
+        semester::{
+            current_week, mark_current, parse_semester, parse_semester_start, CurrentWeek,
+            SemesterInfo, SemesterStart,
+        },
     },
-    session::HttpSession,
+    seat_watcher::{watch_seats, SeatWatchConfig, SeatWatchEvent, MIN_INTERVAL_MS},
+    session::{Endpoints, HttpSession, LoginState},
 };
-static SESSION: OnceCell<Arc<Mutex<Option<HttpSession>>>> = OnceCell::new();
+use crate::frb_generated::StreamSink;
+/// 当前会话。锁只在登录、登出时替换会话使用，请求前会克隆出会话后立即释放，
+/// `HttpSession` 内部的 Client 与 Cookie 均为共享引用，多个请求可以并发执行
+static SESSION: OnceCell<Arc<RwLock<Option<HttpSession>>>> = OnceCell::new();
+static ENDPOINTS: Lazy<RwLock<Endpoints>> = Lazy::new(|| RwLock::new(Endpoints::default()));
+static GRADE_SCALE: Lazy<RwLock<GradeScale>> = Lazy::new(|| RwLock::new(GradeScale::default()));
+static DEKT_REQUIREMENTS: Lazy<RwLock<Vec<DektRequirement>>> =
+    Lazy::new(|| RwLock::new(Vec::new()));
+static SEMESTER_START: Lazy<RwLock<Option<SemesterStart>>> = Lazy::new(|| RwLock::new(None));
 
 #[frb(init)]
 pub fn init_app() {
     flutter_rust_bridge::setup_default_user_utils();
-    let _ = SESSION.set(Arc::new(Mutex::new(None)));
+    let _ = SESSION.set(Arc::new(RwLock::new(None)));
+}
+
+fn session_slot() -> Result<&'static RwLock<Option<HttpSession>>, ApiError> {
+    SESSION
+        .get()
+        .map(|session| session.as_ref())
+        .ok_or(ApiError::NotInitialized)
+}
+
+fn lock_poisoned<T>(_: T) -> ApiError {
+    ApiError::Internal {
+        cause: "SESSION 锁已损坏".to_string(),
+    }
+}
+
+fn new_session() -> HttpSession {
+    let endpoints = ENDPOINTS
+        .read()
+        .map(|endpoints| endpoints.clone())
+        .unwrap_or_default();
+    HttpSession::with_endpoints(endpoints)
+}
+
+fn replace_session(session: Option<HttpSession>) -> Result<(), ApiError> {
+    *session_slot()?.write().map_err(lock_poisoned)? = session;
+    Ok(())
+}
+
+/// 取出已创建的会话，不检查登录状态
+fn existing_session() -> Result<HttpSession, ApiError> {
+    session_slot()?
+        .read()
+        .map_err(lock_poisoned)?
+        .clone()
+        .ok_or(ApiError::NotLoggedIn)
+}
+
+/// 所有教务接口都通过这里获取会话，未登录时返回 `NotLoggedIn`。
+/// 已过期的会话仍会交给请求层，由其尝试免验证码重新登录
+fn logged_in_session() -> Result<HttpSession, ApiError> {
+    let session = existing_session()?;
+    match session.login_state() {
+        LoginState::JwxtAuthenticated | LoginState::Expired => Ok(session),
+        _ => Err(ApiError::NotLoggedIn),
+    }
+}
+
+/// 设置 VPN 与教务系统地址，之后创建的会话使用新地址
+#[frb(sync)]
+pub fn api_set_endpoints(cas: String, jwxt: String) -> Result<(), ApiError> {
+    *ENDPOINTS.write().map_err(lock_poisoned)? = Endpoints { cas, jwxt };
+    Ok(())
+}
+
+/// 设置等级制成绩（优秀、良好等）换算成百分制的分值，之后查询的成绩按新分值计算
+#[frb(sync)]
+pub fn api_set_grade_scale(scale: GradeScale) -> Result<(), ApiError> {
+    *GRADE_SCALE.write().map_err(lock_poisoned)? = scale;
+    Ok(())
+}
+
+/// 设置第二课堂各类别需要的学分，用于 `api_graduation_check`
+#[frb(sync)]
+pub fn api_set_dekt_requirements(requirements: Vec<DektRequirement>) -> Result<(), ApiError> {
+    *DEKT_REQUIREMENTS.write().map_err(lock_poisoned)? = requirements;
+    Ok(())
+}
+
+/// 手动设置学期的开学日期（YYYY-MM-DD），用于计算教学周；为 None 时恢复从校历获取
+#[frb(sync)]
+pub fn api_set_semester_start(start: Option<SemesterStart>) -> Result<(), ApiError> {
+    if let Some(start) = &start {
+        parse_date(&start.start_date)?;
+    }
+    *SEMESTER_START.write().map_err(lock_poisoned)? = start;
+    Ok(())
+}
+
+#[frb(dart_async)]
+pub async fn api_login_state() -> LoginState {
+    existing_session()
+        .map(|session| session.login_state())
+        .unwrap_or(LoginState::NoSession)
 }
 
 #[frb(dart_async)]
@@ -31,186 +143,415 @@ pub async fn api_login(
     vpn_password: String,
     oa_password: String,
     captcha: String,
-) -> Result<String, String> {
-    let session_arc = SESSION.get().ok_or("SESSION 未初始化")?;
-    let mut guard = session_arc.lock().await;
-
-    let session = guard.as_ref().ok_or("SESSION 锁定失败")?;
-
+) -> Result<String, ApiError> {
+    let session = existing_session()?;
     session
         .complete_login(&username, &vpn_password, &oa_password, &captcha)
-        .await
-        .map_err(|e| format!("登录失败: {}", e))?;
-
-    // 这里通过克隆或移动来更新 guard 中的 session
-    *guard = Some(session.clone()); // 克隆 session（如果需要深拷贝）
+        .await?;
 
     Ok("登录成功".to_string())
 }
 
 #[frb(dart_async)]
-pub async fn api_student_info() -> Result<StudentInfo, String> {
-    let session_arc = SESSION.get().expect("SESSION 未初始化");
-    let guard = session_arc.lock().await;
-    let session = guard.as_ref().unwrap();
-    let res = session
-        .client
-        .get("https://jw.v.hbfu.edu.cn/jsxsd/grxx/xsxx")
-        .send()
-        .await
-        .map_err(|_| "请求失败".to_string())?
-        .text()
-        .await
-        .map_err(|_| "读取响应失败".to_string())?;
+pub async fn api_logout() -> Result<(), ApiError> {
+    replace_session(None)
+}
+
+#[frb(dart_async)]
+pub async fn api_save_session(path: String) -> Result<(), ApiError> {
+    let session = existing_session()?;
+    session.save_cookies(&path)
+}
+
+/// 从 Cookie 文件恢复会话，服务端会话仍有效时返回 true，此时无需再调用 `api_login`。
+/// 会话已过期时仍会保留 Cookie，配合 `api_set_credentials` 可以免验证码重新登录
+#[frb(dart_async)]
+pub async fn api_restore_session(path: String) -> Result<bool, ApiError> {
+    let endpoints = ENDPOINTS.read().map_err(lock_poisoned)?.clone();
+    let session = HttpSession::load_cookies(&path, endpoints)?;
+    let alive = session.is_alive().await?;
+    session.set_login_state(if alive {
+        LoginState::JwxtAuthenticated
+    } else {
+        LoginState::Expired
+    });
+
+    replace_session(Some(session))?;
+    Ok(alive)
+}
+
+#[frb(dart_async)]
+pub async fn api_set_credentials(
+    username: String,
+    vpn_password: String,
+    oa_password: String,
+) -> Result<(), ApiError> {
+    let session = existing_session()?;
+    session.set_credentials(&username, &vpn_password, &oa_password);
+    Ok(())
+}
+
+#[frb(dart_async)]
+pub async fn api_session_alive() -> Result<bool, ApiError> {
+    match existing_session() {
+        Ok(session) => session.is_alive().await,
+        Err(ApiError::NotLoggedIn) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+#[frb(dart_async)]
+pub async fn api_student_info() -> Result<StudentInfo, ApiError> {
+    let session = logged_in_session()?;
+    let res = session.get_page("/jsxsd/grxx/xsxx").await?;
     parse_student_info(&res)
 }
+/// 下载学籍卡片上的照片
 #[frb(dart_async)]
-pub async fn api_semester(is_all: bool) -> Result<Vec<SemesterInfo>, String> {
-    let session_arc = SESSION.get().expect("SESSION 未初始化");
-    let guard = session_arc.lock().await;
-    let session = guard.as_ref().unwrap();
-    let res = session
-        .client
-        .get("https://jw.v.hbfu.edu.cn/jsxsd/xsks/xsksap_query")
-        .send()
-        .await
-        .map_err(|_| "请求失败".to_string())?
-        .text()
-        .await
-        .map_err(|_| "读取响应失败".to_string())?;
-    parse_semester(&res, is_all)
-}
-#[frb(dart_async)]
-pub async fn api_score(semester: String) -> Result<ScoreTotal, String> {
-    let session_arc = SESSION.get().expect("SESSION 未初始化");
-    let guard = session_arc.lock().await;
-    let session = guard.as_ref().unwrap();
+pub async fn api_student_photo() -> Result<StudentPhoto, ApiError> {
+    let session = logged_in_session()?;
+    let path = "/jsxsd/grxx/xsxx";
+    let res = session.get_page(path).await?;
+    let src = parse_photo_src(&res)?;
+    let url = reqwest::Url::parse(&session.jwxt_url(path))
+        .and_then(|page| page.join(&src))
+        .map_err(|e| ApiError::Internal {
+            cause: format!("照片地址无效 {}: {}", src, e),
+        })?;
+    let (bytes, content_type) = session.get_bytes(url.as_str()).await?;
+    StudentPhoto::new(bytes, content_type.as_deref())
+}
+#[frb(dart_async)]
+pub async fn api_semester(is_all: bool) -> Result<Vec<SemesterInfo>, ApiError> {
+    let session = logged_in_session()?;
+    let res = session.get_page("/jsxsd/xsks/xsksap_query").await?;
+    let mut semesters = parse_semester(&res, is_all)?;
+    if !semesters.iter().any(|semester| semester.is_current) {
+        // 校历只用于补充当前学期，获取失败时仍返回学期列表
+        let calendar = session.get_page("/jsxsd/jxzl/jxzl_query").await;
+        if let Ok(start) = calendar.and_then(|page| parse_semester_start(&page)) {
+            mark_current(&mut semesters, &start.semester);
+        }
+    }
+    Ok(semesters)
+}
+/// 今天是当前学期的第几教学周。优先使用 `api_set_semester_start` 设置的开学日期，
+/// 未设置时从校历页面获取
+#[frb(dart_async)]
+pub async fn api_current_week() -> Result<CurrentWeek, ApiError> {
+    let today = beijing_now().date();
+    let overridden = SEMESTER_START.read().map_err(lock_poisoned)?.clone();
+    if let Some(start) = overridden {
+        return current_week(&start, today, true);
+    }
+    let session = logged_in_session()?;
+    let res = session.get_page("/jsxsd/jxzl/jxzl_query").await?;
+    current_week(&parse_semester_start(&res)?, today, false)
+}
+#[frb(dart_async)]
+pub async fn api_score(semester: String) -> Result<ScoreTotal, ApiError> {
+    fetch_scores(&semester).await
+}
+/// 按指定算法计算总体与每学期的绩点，`semester` 为空时统计全部学期
+#[frb(dart_async)]
+pub async fn api_gpa(
+    semester: String,
+    scheme: GpaScheme,
+    filter: GpaFilter,
+) -> Result<GpaReport, ApiError> {
+    let scores = fetch_scores(&semester).await?;
+    Ok(gpa_report(&scores.info, scheme, &filter))
+}
+async fn fetch_scores(semester: &str) -> Result<ScoreTotal, ApiError> {
+    let session = logged_in_session()?;
     let mut form_data = HashMap::new();
-    form_data.insert("kksj", semester.as_str());
+    form_data.insert("kksj", semester);
     form_data.insert("xsfs", "all");
 
     let res = session
-        .client
-        .post("https://jw.v.hbfu.edu.cn/jsxsd/kscj/cjcx_list")
-        .form(&form_data)
-        .send()
-        .await
-        .map_err(|_| "请求失败".to_string())?
-        .text()
-        .await
-        .map_err(|_| "读取响应失败".to_string())?;
-    parse_score_all(&res)
-}
-#[frb(dart_async)]
-pub async fn api_course(semester: String) -> Result<Vec<CourseSchedule>, String> {
-    let session_arc = SESSION.get().expect("SESSION 未初始化");
-    let guard = session_arc.lock().await;
-    let session = guard.as_ref().unwrap();
+        .post_page("/jsxsd/kscj/cjcx_list", &form_data)
+        .await?;
+    let scale = GRADE_SCALE.read().map_err(lock_poisoned)?.clone();
+    parse_score_all(&res, &scale)
+}
+#[frb(dart_async)]
+pub async fn api_course(semester: String) -> Result<Vec<CourseSchedule>, ApiError> {
+    let session = logged_in_session()?;
     let mut form_data = HashMap::new();
     form_data.insert("xnxq01id", semester.as_str());
     form_data.insert("sfFD", "all");
 
     let res = session
-        .client
-        .post("https://jw.v.hbfu.edu.cn/jsxsd/xskb/xskb_list.do")
-        .form(&form_data)
-        .send()
-        .await
-        .map_err(|_| "请求失败".to_string())?
-        .text()
-        .await
-        .map_err(|_| "读取响应失败".to_string())?;
+        .post_page("/jsxsd/xskb/xskb_list.do", &form_data)
+        .await?;
     parse_course_schedule(&res)
 }
+/// 只返回第 `week` 周有课的课程
+#[frb(dart_async)]
+pub async fn api_course_for_week(
+    semester: String,
+    week: u8,
+) -> Result<Vec<CourseSchedule>, ApiError> {
+    let courses = api_course(semester).await?;
+    Ok(courses
+        .into_iter()
+        .filter(|course| course.is_active_in_week(week))
+        .collect())
+}
+/// 导出课表为 iCalendar 文本，`first_monday` 为第一周星期一 "YYYY-MM-DD"，
+/// `section_times` 为空或缺少某节时使用课表中的时间范围。UID 由学期、课程与周次确定，
+/// 重复导入会更新原有日程而不是重复添加；校历中放假的日期不生成日程
+#[frb(dart_async)]
+pub async fn api_export_course_ics(
+    semester: String,
+    first_monday: String,
+    section_times: Vec<SectionTime>,
+) -> Result<String, ApiError> {
+    let first_monday = parse_date(&first_monday)?;
+    let courses = api_course(semester.clone()).await?;
+    let mut events = course_events(&courses, &semester, first_monday, &section_times);
+    // 校历只用于跳过放假的日期，获取失败时照常导出
+    if let Ok(calendar) = api_school_calendar(semester.clone()).await {
+        let holidays = calendar.holiday_dates();
+        events.retain(|event| !holidays.contains(&event.start.date()));
+    }
+    Ok(write_calendar(
+        &format!("{} 课表", semester),
+        &events,
+        chrono::Utc::now(),
+    ))
+}
+/// 教学周历，包含每周的星期一及节假日、调休安排，`semester` 为空时为当前学期
+#[frb(dart_async)]
+pub async fn api_school_calendar(semester: String) -> Result<SchoolCalendar, ApiError> {
+    let session = logged_in_session()?;
+    let res = if semester.is_empty() {
+        session.get_page("/jsxsd/jxzl/jxzl_query").await?
+    } else {
+        let mut form_data = HashMap::new();
+        form_data.insert("xnxq01id", semester.as_str());
+        session
+            .post_page("/jsxsd/jxzl/jxzl_query", &form_data)
+            .await?
+    };
+    parse_school_calendar(&res)
+}
 #[frb(dart_async)]
-pub async fn api_exam(semester: String) -> Result<Vec<ExamSchedule>, String> {
-    let session_arc = SESSION.get().expect("SESSION 未初始化");
-    let guard = session_arc.lock().await;
-    let session = guard.as_ref().unwrap();
+pub async fn api_exam(semester: String) -> Result<Vec<ExamSchedule>, ApiError> {
+    let session = logged_in_session()?;
     let mut form_data = HashMap::new();
     form_data.insert("xnxqid", semester.as_str());
 
     let res = session
-        .client
-        .post("https://jw.v.hbfu.edu.cn/jsxsd/xsks/xsksap_list")
-        .form(&form_data)
-        .send()
-        .await
-        .map_err(|_| "请求失败".to_string())?
-        .text()
-        .await
-        .map_err(|_| "读取响应失败".to_string())?;
+        .post_page("/jsxsd/xsks/xsksap_list", &form_data)
+        .await?;
     parse_exam(&res)
 }
+/// 导出考试安排为 iCalendar 文本，`alarm_minutes` 为提前提醒的分钟数，
+/// 为空时默认提前一天和一小时提醒
+#[frb(dart_async)]
+pub async fn api_export_exam_ics(
+    semester: String,
+    alarm_minutes: Vec<u32>,
+) -> Result<String, ApiError> {
+    let alarms: Vec<i64> = if alarm_minutes.is_empty() {
+        vec![24 * 60, 60]
+    } else {
+        alarm_minutes.into_iter().map(i64::from).collect()
+    };
+    let exams = api_exam(semester.clone()).await?;
+    let events = exam_events(&exams, &semester, &alarms);
+    Ok(write_calendar(
+        &format!("{} 考试", semester),
+        &events,
+        chrono::Utc::now(),
+    ))
+}
+/// 考试倒计时，按距开考时间排序，并标出时间重叠或同一天的考试
+#[frb(dart_async)]
+pub async fn api_exam_countdown(semester: String) -> Result<Vec<ExamCountdown>, ApiError> {
+    let exams = api_exam(semester).await?;
+    Ok(exam_countdown(exams, beijing_now()))
+}
 #[frb(dart_async)]
-pub async fn api_elective(semester: String) -> Result<ElectiveResponse, String> {
-    let session_arc = SESSION.get().expect("SESSION 未初始化");
-    let guard = session_arc.lock().await;
-    let session = guard.as_ref().unwrap();
+pub async fn api_elective(semester: String) -> Result<ElectiveResponse, ApiError> {
+    let session = logged_in_session()?;
     let mut form_data = HashMap::new();
     form_data.insert("xnxqid", semester.as_str());
 
     let res = session
-        .client
-        .post("https://jw.v.hbfu.edu.cn/jsxsd/xkgl/xqxkchList")
-        .form(&form_data)
-        .send()
-        .await
-        .map_err(|_| "请求失败".to_string())?
-        .text()
-        .await
-        .map_err(|_| "读取响应失败".to_string())?;
+        .post_page("/jsxsd/xkgl/xqxkchList", &form_data)
+        .await?;
     parse_elective(&res)
 }
 #[frb(dart_async)]
-pub async fn api_plan() -> Result<ExecutionPlanResponse, String> {
-    let session_arc = SESSION.get().expect("SESSION 未初始化");
-    let guard = session_arc.lock().await;
-    let session = guard.as_ref().unwrap();
-    let res = session
-        .client
-        .get("https://jw.v.hbfu.edu.cn/jsxsd/pyfa/pyfa_query")
-        .send()
-        .await
-        .map_err(|_| "请求失败".to_string())?
-        .text()
-        .await
-        .map_err(|_| "读取响应失败".to_string())?;
+pub async fn api_plan() -> Result<ExecutionPlanResponse, ApiError> {
+    let session = logged_in_session()?;
+    let res = session.get_page("/jsxsd/pyfa/pyfa_query").await?;
     parse_plan(&res)
 }
+/// 对照培养方案与全部成绩，统计各课程的完成情况与已获学分。
+/// `current_semester` 为当前学期，该学期尚未出成绩的课程记为修读中
 #[frb(dart_async)]
-pub async fn api_dekt() -> Result<DEKT, String> {
-    let session_arc = SESSION.get().expect("SESSION 未初始化");
-    let guard = session_arc.lock().await;
-    let session = guard.as_ref().unwrap();
-    let res = session
-        .client
-        .get("https://jw.v.hbfu.edu.cn/jsxsd/pyfa/cxxf07List")
-        .send()
-        .await
-        .map_err(|_| "请求失败".to_string())?
-        .text()
-        .await
-        .map_err(|_| "读取响应失败".to_string())?;
+pub async fn api_degree_audit(current_semester: String) -> Result<DegreeAudit, ApiError> {
+    let (plan, scores) = tokio::try_join!(api_plan(), fetch_scores(""))?;
+    Ok(degree_audit(&plan.plans, &scores.info, &current_semester))
+}
+#[frb(dart_async)]
+pub async fn api_dekt() -> Result<DEKT, ApiError> {
+    let session = logged_in_session()?;
+    let res = session.get_page("/jsxsd/pyfa/cxxf07List").await?;
     parse_dekt(&res)
 }
+/// 检查毕业学分要求，同时获取学分要求表、成绩单与第二课堂学分
 #[frb(dart_async)]
-pub async fn api_dekt_detail(id: String) -> Result<DEKTDetail, String> {
-    let session_arc = SESSION.get().expect("SESSION 未初始化");
-    let guard = session_arc.lock().await;
-    let session = guard.as_ref().unwrap();
+pub async fn api_graduation_check() -> Result<GraduationReport, ApiError> {
+    let (elective, scores, dekt) =
+        tokio::try_join!(api_elective(String::new()), fetch_scores(""), api_dekt())?;
+    let requirements = DEKT_REQUIREMENTS.read().map_err(lock_poisoned)?.clone();
+    graduation_check(&elective.credits, &scores.info, &dekt.total, &requirements)
+}
+/// 空闲教室查询可选的校区与教学楼
+#[frb(dart_async)]
+pub async fn api_classroom_filters() -> Result<ClassroomFilters, ApiError> {
+    let session = logged_in_session()?;
+    let res = session.get_page("/jsxsd/kbxx/jsjy_query").await?;
+    parse_classroom_filters(&res)
+}
+/// 查询第 `week` 周星期 `day`（1 为周一）`sections` 各节都空闲的教室。
+/// `campus`、`building` 为 `api_classroom_filters` 中校区、教学楼的 value，为空时不限
+#[frb(dart_async)]
+pub async fn api_free_classrooms(
+    semester: String,
+    week: u32,
+    day: u32,
+    sections: Vec<u32>,
+    campus: String,
+    building: String,
+) -> Result<Vec<FreeClassroom>, ApiError> {
+    if week == 0 || !(1..=7).contains(&day) {
+        return Err(ApiError::InvalidArgument {
+            message: format!("周次或星期无效: 第{}周 星期{}", week, day),
+        });
+    }
+    let (Some(first), Some(last)) = (sections.iter().min(), sections.iter().max()) else {
+        return Err(ApiError::InvalidArgument {
+            message: "至少需要选择一节课".to_string(),
+        });
+    };
+    let session = logged_in_session()?;
+    let (week, day, first, last) = (
+        week.to_string(),
+        day.to_string(),
+        first.to_string(),
+        last.to_string(),
+    );
+    let mut form_data = HashMap::new();
+    form_data.insert("xnxqh", semester.as_str());
+    form_data.insert("xqbh", campus.as_str());
+    form_data.insert("jzwbh", building.as_str());
+    form_data.insert("zc", week.as_str());
+    form_data.insert("zc2", week.as_str());
+    form_data.insert("xq", day.as_str());
+    form_data.insert("xq2", day.as_str());
+    form_data.insert("jc1", first.as_str());
+    form_data.insert("jc2", last.as_str());
+
+    let res = session
+        .post_page("/jsxsd/kbxx/jsjy_query2", &form_data)
+        .await?;
+    parse_free_classrooms(&res, &sections)
+}
+/// 当前学期的选课轮次
+#[frb(dart_async)]
+pub async fn api_selection_rounds() -> Result<Vec<SelectionRound>, ApiError> {
+    let session = logged_in_session()?;
+    let res = session.get_page("/jsxsd/xsxk/xklc_list").await?;
+    parse_selection_rounds(&res)
+}
+/// 进入选课轮次，之后才能查询可选课程和选课。`round_id` 为 `SelectionRound::id`
+#[frb(dart_async)]
+pub async fn api_enter_selection(round_id: String) -> Result<SelectionOutcome, ApiError> {
+    let session = logged_in_session()?;
+    let res = session
+        .get_page_with_query("/jsxsd/xsxk/xsxk_index", &[("jx0502zbid", &round_id)])
+        .await?;
+    Ok(parse_enter_result(&res))
+}
+/// 用 `session` 查询可选教学班，`api_selection_courses` 与抢课共用
+pub(crate) async fn fetch_selection_courses(
+    session: &HttpSession,
+    category: SelectionCategory,
+    keyword: &str,
+) -> Result<Vec<SelectableCourse>, ApiError> {
+    let mut form_data = HashMap::new();
+    form_data.insert("kcxx", keyword);
+    form_data.insert("sEcho", "1");
+    form_data.insert("iDisplayStart", "0");
+    form_data.insert("iDisplayLength", "1000");
+
+    let res = session.post_page(category.list_path(), &form_data).await?;
+    parse_selection_courses(&res, category)
+}
+/// 用 `session` 提交选课，`api_select_course` 与抢课共用
+pub(crate) async fn submit_selection(
+    session: &HttpSession,
+    category: SelectionCategory,
+    course_id: &str,
+) -> Result<SelectionOutcome, ApiError> {
+    let query = [("jx0404id", course_id), ("xkzy", ""), ("trjf", "")];
     let res = session
-        .client
-        .get(format!(
-            "https://jw.v.hbfu.edu.cn/jsxsd/pyfa/cxxf07View?cxxf07id={}&type=view",
-            id
-        ))
-        .send()
-        .await
-        .map_err(|_| "请求失败".to_string())?
-        .text()
-        .await
-        .map_err(|_| "读取响应失败".to_string())?;
+        .get_page_with_query(category.oper_path(), &query)
+        .await?;
+    parse_selection_outcome(&res)
+}
+/// 某一类别的可选教学班，`keyword` 按课程名称筛选，为空时返回全部
+#[frb(dart_async)]
+pub async fn api_selection_courses(
+    category: SelectionCategory,
+    keyword: String,
+) -> Result<Vec<SelectableCourse>, ApiError> {
+    let session = logged_in_session()?;
+    fetch_selection_courses(&session, category, &keyword).await
+}
+/// 选课，`course_id` 为 `SelectableCourse::id`
+#[frb(dart_async)]
+pub async fn api_select_course(
+    category: SelectionCategory,
+    course_id: String,
+) -> Result<SelectionOutcome, ApiError> {
+    let session = logged_in_session()?;
+    submit_selection(&session, category, &course_id).await
+}
+/// 退课，`course_id` 为已选教学班的 jx0404id
+#[frb(dart_async)]
+pub async fn api_drop_course(course_id: String) -> Result<SelectionOutcome, ApiError> {
+    let session = logged_in_session()?;
+    let res = session
+        .get_page_with_query(DROP_PATH, &[("jx0404id", &course_id)])
+        .await?;
+    parse_selection_outcome(&res)
+}
+/// 监视已满的教学班，出现空位时自动选课，进度与结果通过 `sink` 推送。
+/// 间隔不小于 3 秒；Dart 端取消订阅后在下一次推送时停止
+#[frb(dart_async)]
+pub async fn api_watch_seats(
+    sink: StreamSink<SeatWatchEvent>,
+    config: SeatWatchConfig,
+) -> Result<(), ApiError> {
+    let session = logged_in_session()?;
+    let config = SeatWatchConfig {
+        interval_ms: config.interval_ms.max(MIN_INTERVAL_MS),
+        ..config
+    };
+    watch_seats(&session, &config, |event| sink.add(event).is_ok()).await;
+    Ok(())
+}
+#[frb(dart_async)]
+pub async fn api_dekt_detail(id: String) -> Result<DEKTDetail, ApiError> {
+    let session = logged_in_session()?;
+    let url = format!("/jsxsd/pyfa/cxxf07View?cxxf07id={}&type=view", id);
+    let res = session.get_page(&url).await?;
     parse_dekt_detail(&res)
 }
 // #[frb(dart_async)]
@@ -222,14 +563,11 @@ pub async fn api_dekt_detail(id: String) -> Result<DEKTDetail, String> {
 
 // }
 #[frb(dart_async)]
-pub async fn api_get_captcha() -> Result<Vec<u8>, String> {
-    let session_arc = SESSION.get().ok_or("SESSION 未初始化")?;
-    let mut guard = session_arc.lock().await;
-    if guard.is_none() {
-        let session = HttpSession::new();
-        *guard = Some(session);
-    }
-
-    let session = guard.as_ref().ok_or("SESSION 锁定失败")?;
+pub async fn api_get_captcha() -> Result<Vec<u8>, ApiError> {
+    let session = session_slot()?
+        .write()
+        .map_err(lock_poisoned)?
+        .get_or_insert_with(new_session)
+        .clone();
     session.get_captcha().await
 }
@@ -40,7 +40,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1761466720;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 52187821;
 
 // Section: executor
 
@@ -75,7 +75,7 @@ fn wire__crate__api__session__HttpSession_access_jwxt_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -329,7 +329,7 @@ fn wire__crate__api__session__HttpSession_complete_login_impl(
             let api_captcha = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -364,6 +364,39 @@ fn wire__crate__api__session__HttpSession_complete_login_impl(
         },
     )
 }
+fn wire__crate__api__session__HttpSession_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "HttpSession_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::session::HttpSession::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__session__HttpSession_get_captcha_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -391,7 +424,7 @@ fn wire__crate__api__session__HttpSession_get_captcha_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -447,7 +480,7 @@ fn wire__crate__api__session__HttpSession_get_flow_execution_key_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -478,15 +511,15 @@ fn wire__crate__api__session__HttpSession_get_flow_execution_key_impl(
         },
     )
 }
-fn wire__crate__api__session__HttpSession_new_impl(
+fn wire__crate__api__session__HttpSession_is_alive_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "HttpSession_new",
+            debug_name: "HttpSession_is_alive",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -500,17 +533,41 @@ fn wire__crate__api__session__HttpSession_new_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::session::HttpSession::new())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::session::HttpSession::is_alive(&*api_that_guard).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__aescbc__aes_cbc_encrypt_impl(
+fn wire__crate__api__session__HttpSession_load_cookies_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -518,7 +575,7 @@ fn wire__crate__api__aescbc__aes_cbc_encrypt_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "aes_cbc_encrypt",
+            debug_name: "HttpSession_load_cookies",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -532,26 +589,28 @@ fn wire__crate__api__aescbc__aes_cbc_encrypt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_plain_text = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_endpoints = <crate::api::session::Endpoints>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, CryptoError>((move || {
-                    let output_ok = crate::api::aescbc::aes_cbc_encrypt(&api_plain_text)?;
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok =
+                        crate::api::session::HttpSession::load_cookies(&api_path, api_endpoints)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__api_course_impl(
+fn wire__crate__api__session__HttpSession_login_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_course",
+            debug_name: "HttpSession_login_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -565,29 +624,44 @@ fn wire__crate__api__simple__api_course_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_semester = <String>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::simple::api_course(api_semester).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::session::HttpSession::login_state(&*api_that_guard),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__api_dekt_impl(
+fn wire__crate__api__session__HttpSession_new_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_dekt",
+            debug_name: "HttpSession_new",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -602,27 +676,24 @@ fn wire__crate__api__simple__api_dekt_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::simple::api_dekt().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::session::HttpSession::new())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__api_dekt_detail_impl(
+fn wire__crate__api__session__HttpSession_save_cookies_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_dekt_detail",
+            debug_name: "HttpSession_save_cookies",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -636,29 +707,46 @@ fn wire__crate__api__simple__api_dekt_detail_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>,
+            >>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::simple::api_dekt_detail(api_id).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok = crate::api::session::HttpSession::save_cookies(
+                        &*api_that_guard,
+                        &api_path,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__api_elective_impl(
+fn wire__crate__api__session__HttpSession_set_credentials_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_elective",
+            debug_name: "HttpSession_set_credentials",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -672,29 +760,52 @@ fn wire__crate__api__simple__api_elective_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_semester = <String>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>,
+            >>::sse_decode(&mut deserializer);
+            let api_username = <String>::sse_decode(&mut deserializer);
+            let api_vpn_password = <String>::sse_decode(&mut deserializer);
+            let api_oa_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::simple::api_elective(api_semester).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::session::HttpSession::set_credentials(
+                            &*api_that_guard,
+                            &api_username,
+                            &api_vpn_password,
+                            &api_oa_password,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__api_exam_impl(
+fn wire__crate__api__session__HttpSession_with_endpoints_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_exam",
+            debug_name: "HttpSession_with_endpoints",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -708,29 +819,28 @@ fn wire__crate__api__simple__api_exam_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_semester = <String>::sse_decode(&mut deserializer);
+            let api_endpoints = <crate::api::session::Endpoints>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::simple::api_exam(api_semester).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::session::HttpSession::with_endpoints(api_endpoints),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__api_get_captcha_impl(
+fn wire__crate__api__aescbc__aes_cbc_encrypt_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_get_captcha",
+            debug_name: "aes_cbc_encrypt",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -744,20 +854,18 @@ fn wire__crate__api__simple__api_get_captcha_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_plain_text = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::simple::api_get_captcha().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, CryptoError>((move || {
+                    let output_ok = crate::api::aescbc::aes_cbc_encrypt(&api_plain_text)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__api_login_impl(
+fn wire__crate__api__simple__api_classroom_filters_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -765,7 +873,7 @@ fn wire__crate__api__simple__api_login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_login",
+            debug_name: "api_classroom_filters",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -779,21 +887,11 @@ fn wire__crate__api__simple__api_login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_username = <String>::sse_decode(&mut deserializer);
-            let api_vpn_password = <String>::sse_decode(&mut deserializer);
-            let api_oa_password = <String>::sse_decode(&mut deserializer);
-            let api_captcha = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::simple::api_login(
-                            api_username,
-                            api_vpn_password,
-                            api_oa_password,
-                            api_captcha,
-                        )
-                        .await?;
+                        let output_ok = crate::api::simple::api_classroom_filters().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -802,7 +900,7 @@ fn wire__crate__api__simple__api_login_impl(
         },
     )
 }
-fn wire__crate__api__simple__api_plan_impl(
+fn wire__crate__api__simple__api_course_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -810,7 +908,7 @@ fn wire__crate__api__simple__api_plan_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_plan",
+            debug_name: "api_course",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -824,11 +922,12 @@ fn wire__crate__api__simple__api_plan_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_semester = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::simple::api_plan().await?;
+                        let output_ok = crate::api::simple::api_course(api_semester).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -837,7 +936,7 @@ fn wire__crate__api__simple__api_plan_impl(
         },
     )
 }
-fn wire__crate__api__simple__api_score_impl(
+fn wire__crate__api__simple__api_course_for_week_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -845,7 +944,7 @@ fn wire__crate__api__simple__api_score_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_score",
+            debug_name: "api_course_for_week",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -860,11 +959,13 @@ fn wire__crate__api__simple__api_score_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_semester = <String>::sse_decode(&mut deserializer);
+            let api_week = <u8>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::simple::api_score(api_semester).await?;
+                        let output_ok =
+                            crate::api::simple::api_course_for_week(api_semester, api_week).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -873,7 +974,7 @@ fn wire__crate__api__simple__api_score_impl(
         },
     )
 }
-fn wire__crate__api__simple__api_semester_impl(
+fn wire__crate__api__simple__api_current_week_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -881,7 +982,7 @@ fn wire__crate__api__simple__api_semester_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_semester",
+            debug_name: "api_current_week",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -895,12 +996,11 @@ fn wire__crate__api__simple__api_semester_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_is_all = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::simple::api_semester(api_is_all).await?;
+                        let output_ok = crate::api::simple::api_current_week().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -909,7 +1009,7 @@ fn wire__crate__api__simple__api_semester_impl(
         },
     )
 }
-fn wire__crate__api__simple__api_student_info_impl(
+fn wire__crate__api__simple__api_degree_audit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -917,7 +1017,7 @@ fn wire__crate__api__simple__api_student_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "api_student_info",
+            debug_name: "api_degree_audit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -931,11 +1031,13 @@ fn wire__crate__api__simple__api_student_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_current_semester = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, crate::api::error::ApiError>(
                     (move || async move {
-                        let output_ok = crate::api::simple::api_student_info().await?;
+                        let output_ok =
+                            crate::api::simple::api_degree_audit(api_current_semester).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -944,15 +1046,15 @@ fn wire__crate__api__simple__api_student_info_impl(
         },
     )
 }
-fn wire__crate__api__conwork__encode_inp_impl(
+fn wire__crate__api__simple__api_dekt_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "encode_inp",
+            debug_name: "api_dekt",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -966,27 +1068,28 @@ fn wire__crate__api__conwork__encode_inp_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_input = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::conwork::encode_inp(&api_input))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_dekt().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__simple__api_dekt_detail_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "api_dekt_detail",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1000,27 +1103,29 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_dekt_detail(api_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__course__parse_course_schedule_impl(
+fn wire__crate__api__simple__api_drop_course_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_course_schedule",
+            debug_name: "api_drop_course",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1034,26 +1139,29 @@ fn wire__crate__api__jwxt__course__parse_course_schedule_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_course_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::course::parse_course_schedule(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_drop_course(api_course_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__dekt__parse_dekt_impl(
+fn wire__crate__api__simple__api_elective_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_dekt",
+            debug_name: "api_elective",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1067,26 +1175,29 @@ fn wire__crate__api__jwxt__dekt__parse_dekt_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_semester = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::dekt::parse_dekt(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_elective(api_semester).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__dekt__parse_dekt_detail_impl(
+fn wire__crate__api__simple__api_enter_selection_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_dekt_detail",
+            debug_name: "api_enter_selection",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1100,26 +1211,30 @@ fn wire__crate__api__jwxt__dekt__parse_dekt_detail_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_round_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::dekt::parse_dekt_detail(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::api_enter_selection(api_round_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__dekt__parse_dekt_list_impl(
+fn wire__crate__api__simple__api_exam_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_dekt_list",
+            debug_name: "api_exam",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1133,26 +1248,29 @@ fn wire__crate__api__jwxt__dekt__parse_dekt_list_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_semester = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::dekt::parse_dekt_list(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_exam(api_semester).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__dekt__parse_dekt_total_impl(
+fn wire__crate__api__simple__api_exam_countdown_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_dekt_total",
+            debug_name: "api_exam_countdown",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1166,26 +1284,30 @@ fn wire__crate__api__jwxt__dekt__parse_dekt_total_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_semester = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::dekt::parse_dekt_total(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::api_exam_countdown(api_semester).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__elective__parse_elective_impl(
+fn wire__crate__api__simple__api_export_course_ics_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_elective",
+            debug_name: "api_export_course_ics",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1199,26 +1321,37 @@ fn wire__crate__api__jwxt__elective__parse_elective_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_semester = <String>::sse_decode(&mut deserializer);
+            let api_first_monday = <String>::sse_decode(&mut deserializer);
+            let api_section_times =
+                <Vec<crate::api::ics::SectionTime>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::elective::parse_elective(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_export_course_ics(
+                            api_semester,
+                            api_first_monday,
+                            api_section_times,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__exam__parse_exam_impl(
+fn wire__crate__api__simple__api_export_exam_ics_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_exam",
+            debug_name: "api_export_exam_ics",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1232,26 +1365,34 @@ fn wire__crate__api__jwxt__exam__parse_exam_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_semester = <String>::sse_decode(&mut deserializer);
+            let api_alarm_minutes = <Vec<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::exam::parse_exam(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_export_exam_ics(
+                            api_semester,
+                            api_alarm_minutes,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__plan__parse_plan_impl(
+fn wire__crate__api__simple__api_free_classrooms_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_plan",
+            debug_name: "api_free_classrooms",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1265,26 +1406,42 @@ fn wire__crate__api__jwxt__plan__parse_plan_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_semester = <String>::sse_decode(&mut deserializer);
+            let api_week = <u32>::sse_decode(&mut deserializer);
+            let api_day = <u32>::sse_decode(&mut deserializer);
+            let api_sections = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_campus = <String>::sse_decode(&mut deserializer);
+            let api_building = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::plan::parse_plan(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_free_classrooms(
+                            api_semester,
+                            api_week,
+                            api_day,
+                            api_sections,
+                            api_campus,
+                            api_building,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__score__parse_score_impl(
+fn wire__crate__api__simple__api_get_captcha_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_score",
+            debug_name: "api_get_captcha",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1298,26 +1455,28 @@ fn wire__crate__api__jwxt__score__parse_score_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::score::parse_score(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_get_captcha().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__score__parse_score_all_impl(
+fn wire__crate__api__simple__api_gpa_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_score_all",
+            debug_name: "api_gpa",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1331,26 +1490,33 @@ fn wire__crate__api__jwxt__score__parse_score_all_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_semester = <String>::sse_decode(&mut deserializer);
+            let api_scheme = <crate::api::jwxt::gpa::GpaScheme>::sse_decode(&mut deserializer);
+            let api_filter = <crate::api::jwxt::gpa::GpaFilter>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::score::parse_score_all(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::api_gpa(api_semester, api_scheme, api_filter)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__score__parse_score_summary_impl(
+fn wire__crate__api__simple__api_graduation_check_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_score_summary",
+            debug_name: "api_graduation_check",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1364,26 +1530,28 @@ fn wire__crate__api__jwxt__score__parse_score_summary_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::score::parse_score_summary(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_graduation_check().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__semester__parse_semester_impl(
+fn wire__crate__api__simple__api_login_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_semester",
+            debug_name: "api_login",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1397,28 +1565,38 @@ fn wire__crate__api__jwxt__semester__parse_semester_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
-            let api_is_all = <bool>::sse_decode(&mut deserializer);
+            let api_username = <String>::sse_decode(&mut deserializer);
+            let api_vpn_password = <String>::sse_decode(&mut deserializer);
+            let api_oa_password = <String>::sse_decode(&mut deserializer);
+            let api_captcha = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::jwxt::semester::parse_semester(&api_html, api_is_all)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_login(
+                            api_username,
+                            api_vpn_password,
+                            api_oa_password,
+                            api_captcha,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__jwxt__info__parse_student_info_impl(
+fn wire__crate__api__simple__api_login_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_student_info",
+            debug_name: "api_login_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1432,1514 +1610,5757 @@ fn wire__crate__api__jwxt__info__parse_student_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_html = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::jwxt::info::parse_student_info(&api_html)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::simple::api_login_state().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<CookieStoreMutex>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Client>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CryptoError>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+fn wire__crate__api__simple__api_logout_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_logout",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_logout().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_plan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_plan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_plan().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_restore_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_restore_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_restore_session(api_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_save_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_save_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_save_session(api_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_school_calendar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_school_calendar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_semester = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::api_school_calendar(api_semester).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_score_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_score",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_semester = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_score(api_semester).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_select_course_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_select_course",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_category =
+                <crate::api::jwxt::selection::SelectionCategory>::sse_decode(&mut deserializer);
+            let api_course_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::api_select_course(api_category, api_course_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_selection_courses_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_selection_courses",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_category =
+                <crate::api::jwxt::selection::SelectionCategory>::sse_decode(&mut deserializer);
+            let api_keyword = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::api_selection_courses(api_category, api_keyword)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_selection_rounds_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_selection_rounds",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_selection_rounds().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_semester_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_semester",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_is_all = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_semester(api_is_all).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_session_alive_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_session_alive",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_session_alive().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_set_credentials_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_set_credentials",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_username = <String>::sse_decode(&mut deserializer);
+            let api_vpn_password = <String>::sse_decode(&mut deserializer);
+            let api_oa_password = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_set_credentials(
+                            api_username,
+                            api_vpn_password,
+                            api_oa_password,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_set_dekt_requirements_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_set_dekt_requirements",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_requirements =
+                <Vec<crate::api::jwxt::graduation::DektRequirement>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                let output_ok = crate::api::simple::api_set_dekt_requirements(api_requirements)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__api_set_endpoints_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_set_endpoints",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_cas = <String>::sse_decode(&mut deserializer);
+            let api_jwxt = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                let output_ok = crate::api::simple::api_set_endpoints(api_cas, api_jwxt)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__api_set_grade_scale_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_set_grade_scale",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scale = <crate::api::jwxt::grade::GradeScale>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                let output_ok = crate::api::simple::api_set_grade_scale(api_scale)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__api_set_semester_start_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_set_semester_start",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_start =
+                <Option<crate::api::jwxt::semester::SemesterStart>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                let output_ok = crate::api::simple::api_set_semester_start(api_start)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__api_student_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_student_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_student_info().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_student_photo_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_student_photo",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok = crate::api::simple::api_student_photo().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__api_watch_seats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "api_watch_seats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::seat_watcher::SeatWatchEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::seat_watcher::SeatWatchConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::ApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::simple::api_watch_seats(api_sink, api_config).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__conwork__encode_inp_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "encode_inp",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::conwork::encode_inp(&api_input))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__session__endpoints_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "endpoints_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::session::Endpoints::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__gpa__gpa_filter_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gpa_filter_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::jwxt::gpa::GpaFilter::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__grade__grade_scale_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "grade_scale_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::jwxt::grade::GradeScale::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__classroom__parse_classroom_filters_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_classroom_filters",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok =
+                        crate::api::jwxt::classroom::parse_classroom_filters(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__course__parse_course_schedule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_course_schedule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::course::parse_course_schedule(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__graduation__parse_credit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_credit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::jwxt::graduation::parse_credit(&api_text))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__dekt__parse_dekt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_dekt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::dekt::parse_dekt(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__dekt__parse_dekt_detail_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_dekt_detail",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::dekt::parse_dekt_detail(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__dekt__parse_dekt_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_dekt_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::dekt::parse_dekt_list(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__dekt__parse_dekt_total_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_dekt_total",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::dekt::parse_dekt_total(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__elective__parse_elective_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_elective",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::elective::parse_elective(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__selection__parse_enter_result_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_enter_result",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::jwxt::selection::parse_enter_result(&api_html),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__exam__parse_exam_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_exam",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::exam::parse_exam(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__classroom__parse_free_classrooms_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_free_classrooms",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_sections = <Vec<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::classroom::parse_free_classrooms(
+                        &api_html,
+                        &api_sections,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__info__parse_photo_src_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_photo_src",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::info::parse_photo_src(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__plan__parse_plan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_plan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::plan::parse_plan(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__calendar__parse_school_calendar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_school_calendar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::calendar::parse_school_calendar(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__score__parse_score_summary_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_score_summary",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::score::parse_score_summary(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__selection__parse_selection_courses_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_selection_courses",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_json = <String>::sse_decode(&mut deserializer);
+            let api_category =
+                <crate::api::jwxt::selection::SelectionCategory>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::selection::parse_selection_courses(
+                        &api_json,
+                        api_category,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__selection__parse_selection_outcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_selection_outcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok =
+                        crate::api::jwxt::selection::parse_selection_outcome(&api_text)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__selection__parse_selection_rounds_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_selection_rounds",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::selection::parse_selection_rounds(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__semester__parse_semester_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_semester",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            let api_is_all = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok =
+                        crate::api::jwxt::semester::parse_semester(&api_html, api_is_all)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__semester__parse_semester_start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_semester_start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::semester::parse_semester_start(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__info__parse_student_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_student_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_html = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::ApiError>((move || {
+                    let output_ok = crate::api::jwxt::info::parse_student_info(&api_html)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__course__parse_weeks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_weeks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::jwxt::course::parse_weeks(&api_text))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__jwxt__semester__split_semester_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "split_semester",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_semester = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::jwxt::semester::split_semester(&api_semester),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<CookieStoreMutex>>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Client>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CryptoError>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>
 );
 
-// Section: dart2rust
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for Arc<CookieStoreMutex> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<CookieStoreMutex>>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for Client {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Client>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for CryptoError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CryptoError>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for HttpSession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for std::collections::HashMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<CookieStoreMutex>>,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Client>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CryptoError>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::seat_watcher::SeatWatchEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::error::ApiError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_cause = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::Network { cause: var_cause };
+            }
+            1 => {
+                return crate::api::error::ApiError::SessionExpired;
+            }
+            2 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::BadCredentials {
+                    message: var_message,
+                };
+            }
+            3 => {
+                return crate::api::error::ApiError::BadCaptcha;
+            }
+            4 => {
+                let mut var_page = <String>::sse_decode(deserializer);
+                let mut var_selector = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::ParseLayoutChanged {
+                    page: var_page,
+                    selector: var_selector,
+                };
+            }
+            5 => {
+                return crate::api::error::ApiError::NotInitialized;
+            }
+            6 => {
+                return crate::api::error::ApiError::NotLoggedIn;
+            }
+            7 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::InvalidArgument {
+                    message: var_message,
+                };
+            }
+            8 => {
+                let mut var_cause = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::Io { cause: var_cause };
+            }
+            9 => {
+                let mut var_cause = <String>::sse_decode(deserializer);
+                return crate::api::error::ApiError::Internal { cause: var_cause };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::jwxt::audit::AuditCourse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_courseCode = <String>::sse_decode(deserializer);
+        let mut var_courseName = <String>::sse_decode(deserializer);
+        let mut var_semester = <String>::sse_decode(deserializer);
+        let mut var_courseType = <String>::sse_decode(deserializer);
+        let mut var_credits = <f32>::sse_decode(deserializer);
+        let mut var_status = <crate::api::jwxt::audit::CourseStatus>::sse_decode(deserializer);
+        let mut var_bestScore = <Option<crate::api::jwxt::grade::Grade>>::sse_decode(deserializer);
+        let mut var_attempts = <u32>::sse_decode(deserializer);
+        return crate::api::jwxt::audit::AuditCourse {
+            course_code: var_courseCode,
+            course_name: var_courseName,
+            semester: var_semester,
+            course_type: var_courseType,
+            credits: var_credits,
+            status: var_status,
+            best_score: var_bestScore,
+            attempts: var_attempts,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::jwxt::calendar::CalendarDay {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_date = <String>::sse_decode(deserializer);
+        let mut var_weekday = <u32>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::jwxt::calendar::CalendarDayKind>::sse_decode(deserializer);
+        let mut var_note = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::calendar::CalendarDay {
+            date: var_date,
+            weekday: var_weekday,
+            kind: var_kind,
+            note: var_note,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::calendar::CalendarDayKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::jwxt::calendar::CalendarDayKind::Holiday,
+            1 => crate::api::jwxt::calendar::CalendarDayKind::Workday,
+            _ => unreachable!("Invalid variant for CalendarDayKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::classroom::ClassroomFilters {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_campuses =
+            <Vec<crate::api::jwxt::classroom::FilterOption>>::sse_decode(deserializer);
+        let mut var_buildings =
+            <Vec<crate::api::jwxt::classroom::FilterOption>>::sse_decode(deserializer);
+        return crate::api::jwxt::classroom::ClassroomFilters {
+            campuses: var_campuses,
+            buildings: var_buildings,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::course::CourseSchedule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_teacher = <String>::sse_decode(deserializer);
+        let mut var_timeRange = <String>::sse_decode(deserializer);
+        let mut var_dayOfWeek = <String>::sse_decode(deserializer);
+        let mut var_section = <usize>::sse_decode(deserializer);
+        let mut var_weeks = <String>::sse_decode(deserializer);
+        let mut var_weekList = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_classroom = <String>::sse_decode(deserializer);
+        let mut var_duration = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::course::CourseSchedule {
+            id: var_id,
+            name: var_name,
+            teacher: var_teacher,
+            time_range: var_timeRange,
+            day_of_week: var_dayOfWeek,
+            section: var_section,
+            weeks: var_weeks,
+            week_list: var_weekList,
+            classroom: var_classroom,
+            duration: var_duration,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::audit::CourseStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::jwxt::audit::CourseStatus::Passed,
+            1 => crate::api::jwxt::audit::CourseStatus::Failed,
+            2 => crate::api::jwxt::audit::CourseStatus::InProgress,
+            3 => crate::api::jwxt::audit::CourseStatus::NotTaken,
+            _ => unreachable!("Invalid variant for CourseStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::graduation::CreditBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <crate::api::jwxt::graduation::CreditSource>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_earned = <f32>::sse_decode(deserializer);
+        let mut var_required_ = <f32>::sse_decode(deserializer);
+        let mut var_missing = <f32>::sse_decode(deserializer);
+        let mut var_passed = <bool>::sse_decode(deserializer);
+        return crate::api::jwxt::graduation::CreditBucket {
+            source: var_source,
+            name: var_name,
+            earned: var_earned,
+            required: var_required_,
+            missing: var_missing,
+            passed: var_passed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::elective::CreditInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_category = <String>::sse_decode(deserializer);
+        let mut var_required_ = <f32>::sse_decode(deserializer);
+        let mut var_limited = <f32>::sse_decode(deserializer);
+        let mut var_elective = <f32>::sse_decode(deserializer);
+        let mut var_public = <f32>::sse_decode(deserializer);
+        let mut var_total = <f32>::sse_decode(deserializer);
+        return crate::api::jwxt::elective::CreditInfo {
+            category: var_category,
+            required: var_required_,
+            limited: var_limited,
+            elective: var_elective,
+            public: var_public,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::audit::CreditProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_earned = <f32>::sse_decode(deserializer);
+        let mut var_required_ = <f32>::sse_decode(deserializer);
+        let mut var_passedCount = <u32>::sse_decode(deserializer);
+        let mut var_courseCount = <u32>::sse_decode(deserializer);
+        return crate::api::jwxt::audit::CreditProgress {
+            key: var_key,
+            earned: var_earned,
+            required: var_required_,
+            passed_count: var_passedCount,
+            course_count: var_courseCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::graduation::CreditSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::jwxt::graduation::CreditSource::Course,
+            1 => crate::api::jwxt::graduation::CreditSource::SecondClassroom,
+            _ => unreachable!("Invalid variant for CreditSource: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::semester::CurrentWeek {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_semester = <String>::sse_decode(deserializer);
+        let mut var_startDate = <String>::sse_decode(deserializer);
+        let mut var_week = <i32>::sse_decode(deserializer);
+        let mut var_weekday = <u32>::sse_decode(deserializer);
+        let mut var_isOverridden = <bool>::sse_decode(deserializer);
+        return crate::api::jwxt::semester::CurrentWeek {
+            semester: var_semester,
+            start_date: var_startDate,
+            week: var_week,
+            weekday: var_weekday,
+            is_overridden: var_isOverridden,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::audit::DegreeAudit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_courses = <Vec<crate::api::jwxt::audit::AuditCourse>>::sse_decode(deserializer);
+        let mut var_byCourseType =
+            <Vec<crate::api::jwxt::audit::CreditProgress>>::sse_decode(deserializer);
+        let mut var_bySemester =
+            <Vec<crate::api::jwxt::audit::CreditProgress>>::sse_decode(deserializer);
+        let mut var_earned = <f32>::sse_decode(deserializer);
+        let mut var_required_ = <f32>::sse_decode(deserializer);
+        let mut var_outsidePlan =
+            <Vec<crate::api::jwxt::score::ScoreInfo>>::sse_decode(deserializer);
+        let mut var_outsidePlanEarned = <f32>::sse_decode(deserializer);
+        return crate::api::jwxt::audit::DegreeAudit {
+            courses: var_courses,
+            by_course_type: var_byCourseType,
+            by_semester: var_bySemester,
+            earned: var_earned,
+            required: var_required_,
+            outside_plan: var_outsidePlan,
+            outside_plan_earned: var_outsidePlanEarned,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::dekt::DEKT {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_list = <Vec<crate::api::jwxt::dekt::DEKTList>>::sse_decode(deserializer);
+        let mut var_total = <Vec<crate::api::jwxt::dekt::DEKTTotal>>::sse_decode(deserializer);
+        return crate::api::jwxt::dekt::DEKT {
+            list: var_list,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::dekt::DEKTDetail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <std::collections::HashMap<String, String>>::sse_decode(deserializer);
+        return crate::api::jwxt::dekt::DEKTDetail(var_field0);
+    }
+}
+
+impl SseDecode for crate::api::jwxt::dekt::DEKTList {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_semester = <String>::sse_decode(deserializer);
+        let mut var_category = <String>::sse_decode(deserializer);
+        let mut var_subCategory = <String>::sse_decode(deserializer);
+        let mut var_activityName = <String>::sse_decode(deserializer);
+        let mut var_credit = <String>::sse_decode(deserializer);
+        let mut var_operationId = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::dekt::DEKTList {
+            id: var_id,
+            semester: var_semester,
+            category: var_category,
+            sub_category: var_subCategory,
+            activity_name: var_activityName,
+            credit: var_credit,
+            operation_id: var_operationId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::graduation::DektRequirement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_category = <String>::sse_decode(deserializer);
+        let mut var_credits = <f32>::sse_decode(deserializer);
+        return crate::api::jwxt::graduation::DektRequirement {
+            category: var_category,
+            credits: var_credits,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::dekt::DEKTTotal {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_category = <String>::sse_decode(deserializer);
+        let mut var_totalCredit = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::dekt::DEKTTotal {
+            category: var_category,
+            total_credit: var_totalCredit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::elective::ElectiveResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_credits =
+            <Vec<crate::api::jwxt::elective::CreditInfo>>::sse_decode(deserializer);
+        let mut var_courses =
+            <Vec<crate::api::jwxt::elective::XqxkchInfo>>::sse_decode(deserializer);
+        return crate::api::jwxt::elective::ElectiveResponse {
+            credits: var_credits,
+            courses: var_courses,
+        };
+    }
+}
+
+impl SseDecode for crate::api::session::Endpoints {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cas = <String>::sse_decode(deserializer);
+        let mut var_jwxt = <String>::sse_decode(deserializer);
+        return crate::api::session::Endpoints {
+            cas: var_cas,
+            jwxt: var_jwxt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::exam::ExamCountdown {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_exam = <crate::api::jwxt::exam::ExamSchedule>::sse_decode(deserializer);
+        let mut var_remainingSeconds = <Option<i64>>::sse_decode(deserializer);
+        let mut var_isFinished = <bool>::sse_decode(deserializer);
+        let mut var_overlapsWith = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_sameDayWith = <Vec<u32>>::sse_decode(deserializer);
+        return crate::api::jwxt::exam::ExamCountdown {
+            exam: var_exam,
+            remaining_seconds: var_remainingSeconds,
+            is_finished: var_isFinished,
+            overlaps_with: var_overlapsWith,
+            same_day_with: var_sameDayWith,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::exam::ExamSchedule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_campus = <String>::sse_decode(deserializer);
+        let mut var_courseCode = <String>::sse_decode(deserializer);
+        let mut var_courseName = <String>::sse_decode(deserializer);
+        let mut var_examTime = <String>::sse_decode(deserializer);
+        let mut var_startTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_endTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_examLocation = <String>::sse_decode(deserializer);
+        let mut var_seatNumber = <String>::sse_decode(deserializer);
+        let mut var_examMethod = <String>::sse_decode(deserializer);
+        let mut var_remark = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::exam::ExamSchedule {
+            id: var_id,
+            campus: var_campus,
+            course_code: var_courseCode,
+            course_name: var_courseName,
+            exam_time: var_examTime,
+            start_time: var_startTime,
+            end_time: var_endTime,
+            exam_location: var_examLocation,
+            seat_number: var_seatNumber,
+            exam_method: var_examMethod,
+            remark: var_remark,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::plan::ExecutionPlan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_semester = <String>::sse_decode(deserializer);
+        let mut var_academicYear = <String>::sse_decode(deserializer);
+        let mut var_term = <u8>::sse_decode(deserializer);
+        let mut var_courseCode = <String>::sse_decode(deserializer);
+        let mut var_courseName = <String>::sse_decode(deserializer);
+        let mut var_department = <String>::sse_decode(deserializer);
+        let mut var_credits = <f32>::sse_decode(deserializer);
+        let mut var_totalHours = <f32>::sse_decode(deserializer);
+        let mut var_assessmentMethod = <String>::sse_decode(deserializer);
+        let mut var_courseType = <String>::sse_decode(deserializer);
+        let mut var_isExam = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::plan::ExecutionPlan {
+            id: var_id,
+            semester: var_semester,
+            academic_year: var_academicYear,
+            term: var_term,
+            course_code: var_courseCode,
+            course_name: var_courseName,
+            department: var_department,
+            credits: var_credits,
+            total_hours: var_totalHours,
+            assessment_method: var_assessmentMethod,
+            course_type: var_courseType,
+            is_exam: var_isExam,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::plan::ExecutionPlanResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_plans = <Vec<crate::api::jwxt::plan::ExecutionPlan>>::sse_decode(deserializer);
+        let mut var_semesters = <Vec<String>>::sse_decode(deserializer);
+        let mut var_groups = <Vec<crate::api::jwxt::plan::PlanSemester>>::sse_decode(deserializer);
+        return crate::api::jwxt::plan::ExecutionPlanResponse {
+            plans: var_plans,
+            semesters: var_semesters,
+            groups: var_groups,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::jwxt::classroom::FilterOption {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::classroom::FilterOption {
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::classroom::FreeClassroom {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_campus = <String>::sse_decode(deserializer);
+        let mut var_building = <String>::sse_decode(deserializer);
+        let mut var_capacity = <u32>::sse_decode(deserializer);
+        let mut var_roomType = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::classroom::FreeClassroom {
+            name: var_name,
+            campus: var_campus,
+            building: var_building,
+            capacity: var_capacity,
+            room_type: var_roomType,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::gpa::GpaFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_courseNatures = <Vec<String>>::sse_decode(deserializer);
+        let mut var_courseAttrs = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::jwxt::gpa::GpaFilter {
+            course_natures: var_courseNatures,
+            course_attrs: var_courseAttrs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::gpa::GpaReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_scheme = <crate::api::jwxt::gpa::GpaScheme>::sse_decode(deserializer);
+        let mut var_overall = <crate::api::jwxt::gpa::GpaResult>::sse_decode(deserializer);
+        let mut var_semesters = <Vec<crate::api::jwxt::gpa::SemesterGpa>>::sse_decode(deserializer);
+        return crate::api::jwxt::gpa::GpaReport {
+            scheme: var_scheme,
+            overall: var_overall,
+            semesters: var_semesters,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::gpa::GpaResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_gpa = <Option<f32>>::sse_decode(deserializer);
+        let mut var_averageScore = <Option<f32>>::sse_decode(deserializer);
+        let mut var_credits = <f32>::sse_decode(deserializer);
+        let mut var_courseCount = <u32>::sse_decode(deserializer);
+        return crate::api::jwxt::gpa::GpaResult {
+            gpa: var_gpa,
+            average_score: var_averageScore,
+            credits: var_credits,
+            course_count: var_courseCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::gpa::GpaScheme {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::jwxt::gpa::GpaScheme::School,
+            1 => crate::api::jwxt::gpa::GpaScheme::Standard4,
+            2 => crate::api::jwxt::gpa::GpaScheme::Improved4,
+            3 => crate::api::jwxt::gpa::GpaScheme::Pku4,
+            4 => crate::api::jwxt::gpa::GpaScheme::Wes,
+            _ => unreachable!("Invalid variant for GpaScheme: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::grade::Grade {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_raw = <String>::sse_decode(deserializer);
+        let mut var_value = <Option<f32>>::sse_decode(deserializer);
+        let mut var_isAbsent = <bool>::sse_decode(deserializer);
+        let mut var_isExempt = <bool>::sse_decode(deserializer);
+        let mut var_isRetake = <bool>::sse_decode(deserializer);
+        let mut var_isMakeup = <bool>::sse_decode(deserializer);
+        return crate::api::jwxt::grade::Grade {
+            raw: var_raw,
+            value: var_value,
+            is_absent: var_isAbsent,
+            is_exempt: var_isExempt,
+            is_retake: var_isRetake,
+            is_makeup: var_isMakeup,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::grade::GradeScale {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_excellent = <f32>::sse_decode(deserializer);
+        let mut var_good = <f32>::sse_decode(deserializer);
+        let mut var_medium = <f32>::sse_decode(deserializer);
+        let mut var_pass = <f32>::sse_decode(deserializer);
+        let mut var_fail = <f32>::sse_decode(deserializer);
+        let mut var_qualified = <f32>::sse_decode(deserializer);
+        let mut var_unqualified = <f32>::sse_decode(deserializer);
+        return crate::api::jwxt::grade::GradeScale {
+            excellent: var_excellent,
+            good: var_good,
+            medium: var_medium,
+            pass: var_pass,
+            fail: var_fail,
+            qualified: var_qualified,
+            unqualified: var_unqualified,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::graduation::GraduationReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_buckets =
+            <Vec<crate::api::jwxt::graduation::CreditBucket>>::sse_decode(deserializer);
+        let mut var_passed = <bool>::sse_decode(deserializer);
+        return crate::api::jwxt::graduation::GraduationReport {
+            buckets: var_buckets,
+            passed: var_passed,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::audit::AuditCourse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::audit::AuditCourse>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::calendar::CalendarDay> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::calendar::CalendarDay>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::course::CourseSchedule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::course::CourseSchedule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::graduation::CreditBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::graduation::CreditBucket>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::elective::CreditInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::elective::CreditInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::audit::CreditProgress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::audit::CreditProgress>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::dekt::DEKTList> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::dekt::DEKTList>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::graduation::DektRequirement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::graduation::DektRequirement>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::dekt::DEKTTotal> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::dekt::DEKTTotal>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::exam::ExamCountdown> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::exam::ExamCountdown>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::exam::ExamSchedule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::exam::ExamSchedule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::plan::ExecutionPlan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::plan::ExecutionPlan>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::classroom::FilterOption> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::classroom::FilterOption>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::classroom::FreeClassroom> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::classroom::FreeClassroom>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::plan::PlanSemester> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::plan::PlanSemester>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, String)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::calendar::SchoolWeek> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::calendar::SchoolWeek>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::score::ScoreInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::score::ScoreInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ics::SectionTime> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ics::SectionTime>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::selection::SelectableCourse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::selection::SelectableCourse>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::selection::SelectionRound> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::selection::SelectionRound>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::gpa::SemesterGpa> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::gpa::SemesterGpa>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::semester::SemesterInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::semester::SemesterInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::jwxt::elective::XqxkchInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::jwxt::elective::XqxkchInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::session::LoginState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::session::LoginState::NoSession,
+            1 => crate::api::session::LoginState::CaptchaFetched,
+            2 => crate::api::session::LoginState::VpnAuthenticated,
+            3 => crate::api::session::LoginState::JwxtAuthenticated,
+            4 => crate::api::session::LoginState::Expired,
+            _ => unreachable!("Invalid variant for LoginState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::jwxt::grade::Grade> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::jwxt::grade::Grade>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<(String, u8)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<(String, u8)>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::jwxt::semester::SemesterStart> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::jwxt::semester::SemesterStart>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::jwxt::plan::PlanSemester {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_semester = <String>::sse_decode(deserializer);
+        let mut var_academicYear = <String>::sse_decode(deserializer);
+        let mut var_term = <u8>::sse_decode(deserializer);
+        let mut var_credits = <f32>::sse_decode(deserializer);
+        let mut var_totalHours = <f32>::sse_decode(deserializer);
+        let mut var_plans = <Vec<crate::api::jwxt::plan::ExecutionPlan>>::sse_decode(deserializer);
+        return crate::api::jwxt::plan::PlanSemester {
+            semester: var_semester,
+            academic_year: var_academicYear,
+            term: var_term,
+            credits: var_credits,
+            total_hours: var_totalHours,
+            plans: var_plans,
+        };
+    }
+}
+
+impl SseDecode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (String, u8) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <u8>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::api::jwxt::calendar::SchoolCalendar {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_semester = <String>::sse_decode(deserializer);
+        let mut var_startDate = <String>::sse_decode(deserializer);
+        let mut var_weeks = <Vec<crate::api::jwxt::calendar::SchoolWeek>>::sse_decode(deserializer);
+        return crate::api::jwxt::calendar::SchoolCalendar {
+            semester: var_semester,
+            start_date: var_startDate,
+            weeks: var_weeks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::calendar::SchoolWeek {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_week = <u32>::sse_decode(deserializer);
+        let mut var_monday = <String>::sse_decode(deserializer);
+        let mut var_holidays =
+            <Vec<crate::api::jwxt::calendar::CalendarDay>>::sse_decode(deserializer);
+        let mut var_swapDays =
+            <Vec<crate::api::jwxt::calendar::CalendarDay>>::sse_decode(deserializer);
+        return crate::api::jwxt::calendar::SchoolWeek {
+            week: var_week,
+            monday: var_monday,
+            holidays: var_holidays,
+            swap_days: var_swapDays,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::score::ScoreInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_term = <String>::sse_decode(deserializer);
+        let mut var_courseId = <String>::sse_decode(deserializer);
+        let mut var_courseName = <String>::sse_decode(deserializer);
+        let mut var_score = <crate::api::jwxt::grade::Grade>::sse_decode(deserializer);
+        let mut var_credit = <f32>::sse_decode(deserializer);
+        let mut var_hours = <f32>::sse_decode(deserializer);
+        let mut var_gpa = <f32>::sse_decode(deserializer);
+        let mut var_examType = <String>::sse_decode(deserializer);
+        let mut var_courseAttr = <String>::sse_decode(deserializer);
+        let mut var_courseNature = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::score::ScoreInfo {
+            id: var_id,
+            term: var_term,
+            course_id: var_courseId,
+            course_name: var_courseName,
+            score: var_score,
+            credit: var_credit,
+            hours: var_hours,
+            gpa: var_gpa,
+            exam_type: var_examType,
+            course_attr: var_courseAttr,
+            course_nature: var_courseNature,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::score::ScoreSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_creditTotal = <f32>::sse_decode(deserializer);
+        let mut var_gpaAverage = <f32>::sse_decode(deserializer);
+        let mut var_averageScore = <Option<f32>>::sse_decode(deserializer);
+        return crate::api::jwxt::score::ScoreSummary {
+            credit_total: var_creditTotal,
+            gpa_average: var_gpaAverage,
+            average_score: var_averageScore,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::score::ScoreTotal {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_info = <Vec<crate::api::jwxt::score::ScoreInfo>>::sse_decode(deserializer);
+        let mut var_summary = <crate::api::jwxt::score::ScoreSummary>::sse_decode(deserializer);
+        return crate::api::jwxt::score::ScoreTotal {
+            info: var_info,
+            summary: var_summary,
+        };
+    }
+}
+
+impl SseDecode for crate::api::seat_watcher::SeatWatchConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_category =
+            <crate::api::jwxt::selection::SelectionCategory>::sse_decode(deserializer);
+        let mut var_courseIds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_intervalMs = <u32>::sse_decode(deserializer);
+        let mut var_jitterMs = <u32>::sse_decode(deserializer);
+        let mut var_maxRounds = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::seat_watcher::SeatWatchConfig {
+            category: var_category,
+            course_ids: var_courseIds,
+            interval_ms: var_intervalMs,
+            jitter_ms: var_jitterMs,
+            max_rounds: var_maxRounds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::seat_watcher::SeatWatchEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_round = <u32>::sse_decode(deserializer);
+                let mut var_courses =
+                    <Vec<crate::api::jwxt::selection::SelectableCourse>>::sse_decode(deserializer);
+                return crate::api::seat_watcher::SeatWatchEvent::Polled {
+                    round: var_round,
+                    courses: var_courses,
+                };
+            }
+            1 => {
+                let mut var_course =
+                    <crate::api::jwxt::selection::SelectableCourse>::sse_decode(deserializer);
+                let mut var_outcome =
+                    <crate::api::jwxt::selection::SelectionOutcome>::sse_decode(deserializer);
+                return crate::api::seat_watcher::SeatWatchEvent::Attempted {
+                    course: var_course,
+                    outcome: var_outcome,
+                };
+            }
+            2 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::seat_watcher::SeatWatchEvent::Error {
+                    message: var_message,
+                };
+            }
+            3 => {
+                let mut var_selected = <Vec<String>>::sse_decode(deserializer);
+                let mut var_pending = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::seat_watcher::SeatWatchEvent::Finished {
+                    selected: var_selected,
+                    pending: var_pending,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::ics::SectionTime {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_section = <usize>::sse_decode(deserializer);
+        let mut var_start = <String>::sse_decode(deserializer);
+        let mut var_end = <String>::sse_decode(deserializer);
+        return crate::api::ics::SectionTime {
+            section: var_section,
+            start: var_start,
+            end: var_end,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::selection::SelectableCourse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_category =
+            <crate::api::jwxt::selection::SelectionCategory>::sse_decode(deserializer);
+        let mut var_courseCode = <String>::sse_decode(deserializer);
+        let mut var_courseName = <String>::sse_decode(deserializer);
+        let mut var_credits = <f32>::sse_decode(deserializer);
+        let mut var_teacher = <String>::sse_decode(deserializer);
+        let mut var_time = <String>::sse_decode(deserializer);
+        let mut var_location = <String>::sse_decode(deserializer);
+        let mut var_campus = <String>::sse_decode(deserializer);
+        let mut var_capacity = <u32>::sse_decode(deserializer);
+        let mut var_selected = <u32>::sse_decode(deserializer);
+        let mut var_remaining = <u32>::sse_decode(deserializer);
+        let mut var_conflict = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::selection::SelectableCourse {
+            id: var_id,
+            category: var_category,
+            course_code: var_courseCode,
+            course_name: var_courseName,
+            credits: var_credits,
+            teacher: var_teacher,
+            time: var_time,
+            location: var_location,
+            campus: var_campus,
+            capacity: var_capacity,
+            selected: var_selected,
+            remaining: var_remaining,
+            conflict: var_conflict,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::selection::SelectionCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::jwxt::selection::SelectionCategory::PublicElective,
+            1 => crate::api::jwxt::selection::SelectionCategory::MajorElective,
+            2 => crate::api::jwxt::selection::SelectionCategory::CrossMajor,
+            _ => unreachable!("Invalid variant for SelectionCategory: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::selection::SelectionOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_result =
+            <crate::api::jwxt::selection::SelectionResult>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::selection::SelectionOutcome {
+            result: var_result,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::selection::SelectionResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::jwxt::selection::SelectionResult::Success,
+            1 => crate::api::jwxt::selection::SelectionResult::TimeConflict,
+            2 => crate::api::jwxt::selection::SelectionResult::Full,
+            3 => crate::api::jwxt::selection::SelectionResult::OutsideWindow,
+            4 => crate::api::jwxt::selection::SelectionResult::Failed,
+            _ => unreachable!("Invalid variant for SelectionResult: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::selection::SelectionRound {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_semester = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_startTime = <String>::sse_decode(deserializer);
+        let mut var_endTime = <String>::sse_decode(deserializer);
+        let mut var_canEnter = <bool>::sse_decode(deserializer);
+        return crate::api::jwxt::selection::SelectionRound {
+            id: var_id,
+            semester: var_semester,
+            name: var_name,
+            start_time: var_startTime,
+            end_time: var_endTime,
+            can_enter: var_canEnter,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::gpa::SemesterGpa {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_term = <String>::sse_decode(deserializer);
+        let mut var_result = <crate::api::jwxt::gpa::GpaResult>::sse_decode(deserializer);
+        return crate::api::jwxt::gpa::SemesterGpa {
+            term: var_term,
+            result: var_result,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::semester::SemesterInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        let mut var_academicYear = <String>::sse_decode(deserializer);
+        let mut var_term = <u8>::sse_decode(deserializer);
+        let mut var_isCurrent = <bool>::sse_decode(deserializer);
+        return crate::api::jwxt::semester::SemesterInfo {
+            key: var_key,
+            value: var_value,
+            academic_year: var_academicYear,
+            term: var_term,
+            is_current: var_isCurrent,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::semester::SemesterStart {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_semester = <String>::sse_decode(deserializer);
+        let mut var_startDate = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::semester::SemesterStart {
+            semester: var_semester,
+            start_date: var_startDate,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::info::StudentInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_gender = <Option<String>>::sse_decode(deserializer);
+        let mut var_studentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_department = <Option<String>>::sse_decode(deserializer);
+        let mut var_major = <Option<String>>::sse_decode(deserializer);
+        let mut var_className = <Option<String>>::sse_decode(deserializer);
+        let mut var_admissionDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_admissionNumber = <Option<String>>::sse_decode(deserializer);
+        let mut var_idNumber = <Option<String>>::sse_decode(deserializer);
+        let mut var_birthDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_ethnicity = <Option<String>>::sse_decode(deserializer);
+        let mut var_politicalStatus = <Option<String>>::sse_decode(deserializer);
+        let mut var_hometown = <Option<String>>::sse_decode(deserializer);
+        let mut var_educationLevel = <Option<String>>::sse_decode(deserializer);
+        let mut var_lengthOfSchooling = <Option<String>>::sse_decode(deserializer);
+        let mut var_contact = <Option<String>>::sse_decode(deserializer);
+        let mut var_highSchool = <Option<String>>::sse_decode(deserializer);
+        return crate::api::jwxt::info::StudentInfo {
+            name: var_name,
+            gender: var_gender,
+            student_id: var_studentId,
+            department: var_department,
+            major: var_major,
+            class_name: var_className,
+            admission_date: var_admissionDate,
+            admission_number: var_admissionNumber,
+            id_number: var_idNumber,
+            birth_date: var_birthDate,
+            ethnicity: var_ethnicity,
+            political_status: var_politicalStatus,
+            hometown: var_hometown,
+            education_level: var_educationLevel,
+            length_of_schooling: var_lengthOfSchooling,
+            contact: var_contact,
+            high_school: var_highSchool,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jwxt::info::StudentPhoto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bytes = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::info::StudentPhoto {
+            bytes: var_bytes,
+            mime_type: var_mimeType,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::jwxt::elective::XqxkchInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_courseId = <String>::sse_decode(deserializer);
+        let mut var_courseName = <String>::sse_decode(deserializer);
+        let mut var_department = <String>::sse_decode(deserializer);
+        let mut var_hours = <i32>::sse_decode(deserializer);
+        let mut var_credits = <f32>::sse_decode(deserializer);
+        let mut var_courseAttribute = <String>::sse_decode(deserializer);
+        let mut var_selectionType = <String>::sse_decode(deserializer);
+        let mut var_selected = <String>::sse_decode(deserializer);
+        return crate::api::jwxt::elective::XqxkchInfo {
+            course_id: var_courseId,
+            course_name: var_courseName,
+            department: var_department,
+            hours: var_hours,
+            credits: var_credits,
+            course_attribute: var_courseAttribute,
+            selection_type: var_selectionType,
+            selected: var_selected,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__session__HttpSession_access_jwxt_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__session__HttpSession_complete_login_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__session__HttpSession_default_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__session__HttpSession_get_captcha_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__session__HttpSession_get_flow_execution_key_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => {
+            wire__crate__api__session__HttpSession_is_alive_impl(port, ptr, rust_vec_len, data_len)
+        }
+        11 => wire__crate__api__session__HttpSession_load_cookies_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__session__HttpSession_login_state_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__session__HttpSession_new_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__session__HttpSession_save_cookies_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => wire__crate__api__session__HttpSession_set_credentials_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__session__HttpSession_with_endpoints_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__aescbc__aes_cbc_encrypt_impl(port, ptr, rust_vec_len, data_len),
+        18 => {
+            wire__crate__api__simple__api_classroom_filters_impl(port, ptr, rust_vec_len, data_len)
+        }
+        19 => wire__crate__api__simple__api_course_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__simple__api_course_for_week_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__simple__api_current_week_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__simple__api_degree_audit_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__simple__api_dekt_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__simple__api_dekt_detail_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__simple__api_drop_course_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__simple__api_elective_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__simple__api_enter_selection_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__simple__api_exam_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__simple__api_exam_countdown_impl(port, ptr, rust_vec_len, data_len),
+        30 => {
+            wire__crate__api__simple__api_export_course_ics_impl(port, ptr, rust_vec_len, data_len)
+        }
+        31 => wire__crate__api__simple__api_export_exam_ics_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__simple__api_free_classrooms_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__simple__api_get_captcha_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__simple__api_gpa_impl(port, ptr, rust_vec_len, data_len),
+        35 => {
+            wire__crate__api__simple__api_graduation_check_impl(port, ptr, rust_vec_len, data_len)
+        }
+        36 => wire__crate__api__simple__api_login_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__simple__api_login_state_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__simple__api_logout_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__simple__api_plan_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__simple__api_restore_session_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__simple__api_save_session_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__simple__api_school_calendar_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__simple__api_score_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__simple__api_select_course_impl(port, ptr, rust_vec_len, data_len),
+        45 => {
+            wire__crate__api__simple__api_selection_courses_impl(port, ptr, rust_vec_len, data_len)
+        }
+        46 => {
+            wire__crate__api__simple__api_selection_rounds_impl(port, ptr, rust_vec_len, data_len)
+        }
+        47 => wire__crate__api__simple__api_semester_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__simple__api_session_alive_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__simple__api_set_credentials_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__simple__api_student_info_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__simple__api_student_photo_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__simple__api_watch_seats_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__conwork__encode_inp_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__session__endpoints_default_impl(port, ptr, rust_vec_len, data_len),
+        59 => {
+            wire__crate__api__jwxt__gpa__gpa_filter_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        60 => wire__crate__api__jwxt__grade__grade_scale_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        61 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__jwxt__classroom__parse_classroom_filters_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        63 => wire__crate__api__jwxt__course__parse_course_schedule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => {
+            wire__crate__api__jwxt__graduation__parse_credit_impl(port, ptr, rust_vec_len, data_len)
+        }
+        65 => wire__crate__api__jwxt__dekt__parse_dekt_impl(port, ptr, rust_vec_len, data_len),
+        66 => {
+            wire__crate__api__jwxt__dekt__parse_dekt_detail_impl(port, ptr, rust_vec_len, data_len)
+        }
+        67 => wire__crate__api__jwxt__dekt__parse_dekt_list_impl(port, ptr, rust_vec_len, data_len),
+        68 => {
+            wire__crate__api__jwxt__dekt__parse_dekt_total_impl(port, ptr, rust_vec_len, data_len)
+        }
+        69 => {
+            wire__crate__api__jwxt__elective__parse_elective_impl(port, ptr, rust_vec_len, data_len)
+        }
+        70 => wire__crate__api__jwxt__selection__parse_enter_result_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        71 => wire__crate__api__jwxt__exam__parse_exam_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__jwxt__classroom__parse_free_classrooms_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => wire__crate__api__jwxt__info__parse_photo_src_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__jwxt__plan__parse_plan_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__jwxt__calendar__parse_school_calendar_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        76 => wire__crate__api__jwxt__score__parse_score_summary_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        77 => wire__crate__api__jwxt__selection__parse_selection_courses_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        78 => wire__crate__api__jwxt__selection__parse_selection_outcome_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        79 => wire__crate__api__jwxt__selection__parse_selection_rounds_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        80 => {
+            wire__crate__api__jwxt__semester__parse_semester_impl(port, ptr, rust_vec_len, data_len)
+        }
+        81 => wire__crate__api__jwxt__semester__parse_semester_start_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        82 => {
+            wire__crate__api__jwxt__info__parse_student_info_impl(port, ptr, rust_vec_len, data_len)
+        }
+        83 => wire__crate__api__jwxt__course__parse_weeks_impl(port, ptr, rust_vec_len, data_len),
+        84 => {
+            wire__crate__api__jwxt__semester__split_semester_impl(port, ptr, rust_vec_len, data_len)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        2 => wire__crate__api__session__HttpSession_auto_accessor_get__cookie_store_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__session__HttpSession_auto_accessor_get_client_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__session__HttpSession_auto_accessor_set__cookie_store_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__session__HttpSession_auto_accessor_set_client_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__api__simple__api_set_dekt_requirements_impl(ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__simple__api_set_endpoints_impl(ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__simple__api_set_grade_scale_impl(ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__simple__api_set_semester_start_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Arc<CookieStoreMutex>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<Arc<CookieStoreMutex>>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Arc<CookieStoreMutex>>>
+    for Arc<CookieStoreMutex>
+{
+    fn into_into_dart(self) -> FrbWrapper<Arc<CookieStoreMutex>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Client> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Client> {}
 
-impl SseDecode for Arc<CookieStoreMutex> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<CookieStoreMutex>>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Client>> for Client {
+    fn into_into_dart(self) -> FrbWrapper<Client> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<CryptoError> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<CryptoError> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<CryptoError>> for CryptoError {
+    fn into_into_dart(self) -> FrbWrapper<CryptoError> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<HttpSession> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<HttpSession> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<HttpSession>> for HttpSession {
+    fn into_into_dart(self) -> FrbWrapper<HttpSession> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::error::ApiError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::error::ApiError::Network { cause } => {
+                [0.into_dart(), cause.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::SessionExpired => [1.into_dart()].into_dart(),
+            crate::api::error::ApiError::BadCredentials { message } => {
+                [2.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::BadCaptcha => [3.into_dart()].into_dart(),
+            crate::api::error::ApiError::ParseLayoutChanged { page, selector } => [
+                4.into_dart(),
+                page.into_into_dart().into_dart(),
+                selector.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::error::ApiError::NotInitialized => [5.into_dart()].into_dart(),
+            crate::api::error::ApiError::NotLoggedIn => [6.into_dart()].into_dart(),
+            crate::api::error::ApiError::InvalidArgument { message } => {
+                [7.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::Io { cause } => {
+                [8.into_dart(), cause.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::ApiError::Internal { cause } => {
+                [9.into_dart(), cause.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::error::ApiError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::error::ApiError>
+    for crate::api::error::ApiError
+{
+    fn into_into_dart(self) -> crate::api::error::ApiError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::audit::AuditCourse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.course_code.into_into_dart().into_dart(),
+            self.course_name.into_into_dart().into_dart(),
+            self.semester.into_into_dart().into_dart(),
+            self.course_type.into_into_dart().into_dart(),
+            self.credits.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.best_score.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::audit::AuditCourse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::audit::AuditCourse>
+    for crate::api::jwxt::audit::AuditCourse
+{
+    fn into_into_dart(self) -> crate::api::jwxt::audit::AuditCourse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::calendar::CalendarDay {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.date.into_into_dart().into_dart(),
+            self.weekday.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::calendar::CalendarDay
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::calendar::CalendarDay>
+    for crate::api::jwxt::calendar::CalendarDay
+{
+    fn into_into_dart(self) -> crate::api::jwxt::calendar::CalendarDay {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::calendar::CalendarDayKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Holiday => 0.into_dart(),
+            Self::Workday => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::calendar::CalendarDayKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::calendar::CalendarDayKind>
+    for crate::api::jwxt::calendar::CalendarDayKind
+{
+    fn into_into_dart(self) -> crate::api::jwxt::calendar::CalendarDayKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::classroom::ClassroomFilters {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.campuses.into_into_dart().into_dart(),
+            self.buildings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::classroom::ClassroomFilters
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::classroom::ClassroomFilters>
+    for crate::api::jwxt::classroom::ClassroomFilters
+{
+    fn into_into_dart(self) -> crate::api::jwxt::classroom::ClassroomFilters {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::course::CourseSchedule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.teacher.into_into_dart().into_dart(),
+            self.time_range.into_into_dart().into_dart(),
+            self.day_of_week.into_into_dart().into_dart(),
+            self.section.into_into_dart().into_dart(),
+            self.weeks.into_into_dart().into_dart(),
+            self.week_list.into_into_dart().into_dart(),
+            self.classroom.into_into_dart().into_dart(),
+            self.duration.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::course::CourseSchedule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::course::CourseSchedule>
+    for crate::api::jwxt::course::CourseSchedule
+{
+    fn into_into_dart(self) -> crate::api::jwxt::course::CourseSchedule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::audit::CourseStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Passed => 0.into_dart(),
+            Self::Failed => 1.into_dart(),
+            Self::InProgress => 2.into_dart(),
+            Self::NotTaken => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::audit::CourseStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::audit::CourseStatus>
+    for crate::api::jwxt::audit::CourseStatus
+{
+    fn into_into_dart(self) -> crate::api::jwxt::audit::CourseStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::graduation::CreditBucket {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.earned.into_into_dart().into_dart(),
+            self.required.into_into_dart().into_dart(),
+            self.missing.into_into_dart().into_dart(),
+            self.passed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::graduation::CreditBucket
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::graduation::CreditBucket>
+    for crate::api::jwxt::graduation::CreditBucket
+{
+    fn into_into_dart(self) -> crate::api::jwxt::graduation::CreditBucket {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::elective::CreditInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.category.into_into_dart().into_dart(),
+            self.required.into_into_dart().into_dart(),
+            self.limited.into_into_dart().into_dart(),
+            self.elective.into_into_dart().into_dart(),
+            self.public.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::elective::CreditInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::elective::CreditInfo>
+    for crate::api::jwxt::elective::CreditInfo
+{
+    fn into_into_dart(self) -> crate::api::jwxt::elective::CreditInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::audit::CreditProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.earned.into_into_dart().into_dart(),
+            self.required.into_into_dart().into_dart(),
+            self.passed_count.into_into_dart().into_dart(),
+            self.course_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::audit::CreditProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::audit::CreditProgress>
+    for crate::api::jwxt::audit::CreditProgress
+{
+    fn into_into_dart(self) -> crate::api::jwxt::audit::CreditProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::graduation::CreditSource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Course => 0.into_dart(),
+            Self::SecondClassroom => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::graduation::CreditSource
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::graduation::CreditSource>
+    for crate::api::jwxt::graduation::CreditSource
+{
+    fn into_into_dart(self) -> crate::api::jwxt::graduation::CreditSource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::semester::CurrentWeek {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.semester.into_into_dart().into_dart(),
+            self.start_date.into_into_dart().into_dart(),
+            self.week.into_into_dart().into_dart(),
+            self.weekday.into_into_dart().into_dart(),
+            self.is_overridden.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::semester::CurrentWeek
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::semester::CurrentWeek>
+    for crate::api::jwxt::semester::CurrentWeek
+{
+    fn into_into_dart(self) -> crate::api::jwxt::semester::CurrentWeek {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::audit::DegreeAudit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.courses.into_into_dart().into_dart(),
+            self.by_course_type.into_into_dart().into_dart(),
+            self.by_semester.into_into_dart().into_dart(),
+            self.earned.into_into_dart().into_dart(),
+            self.required.into_into_dart().into_dart(),
+            self.outside_plan.into_into_dart().into_dart(),
+            self.outside_plan_earned.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Client {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Client>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::audit::DegreeAudit
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::audit::DegreeAudit>
+    for crate::api::jwxt::audit::DegreeAudit
+{
+    fn into_into_dart(self) -> crate::api::jwxt::audit::DegreeAudit {
+        self
     }
 }
-
-impl SseDecode for CryptoError {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CryptoError>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::dekt::DEKT {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.list.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for HttpSession {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::jwxt::dekt::DEKT {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::dekt::DEKT>
+    for crate::api::jwxt::dekt::DEKT
+{
+    fn into_into_dart(self) -> crate::api::jwxt::dekt::DEKT {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::dekt::DEKTDetail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.0.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::dekt::DEKTDetail
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::dekt::DEKTDetail>
+    for crate::api::jwxt::dekt::DEKTDetail
+{
+    fn into_into_dart(self) -> crate::api::jwxt::dekt::DEKTDetail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::dekt::DEKTList {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.semester.into_into_dart().into_dart(),
+            self.category.into_into_dart().into_dart(),
+            self.sub_category.into_into_dart().into_dart(),
+            self.activity_name.into_into_dart().into_dart(),
+            self.credit.into_into_dart().into_dart(),
+            self.operation_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::dekt::DEKTList
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::dekt::DEKTList>
+    for crate::api::jwxt::dekt::DEKTList
+{
+    fn into_into_dart(self) -> crate::api::jwxt::dekt::DEKTList {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::graduation::DektRequirement {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.category.into_into_dart().into_dart(),
+            self.credits.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::graduation::DektRequirement
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::graduation::DektRequirement>
+    for crate::api::jwxt::graduation::DektRequirement
+{
+    fn into_into_dart(self) -> crate::api::jwxt::graduation::DektRequirement {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::dekt::DEKTTotal {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.category.into_into_dart().into_dart(),
+            self.total_credit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::dekt::DEKTTotal
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::dekt::DEKTTotal>
+    for crate::api::jwxt::dekt::DEKTTotal
+{
+    fn into_into_dart(self) -> crate::api::jwxt::dekt::DEKTTotal {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::elective::ElectiveResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.credits.into_into_dart().into_dart(),
+            self.courses.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::elective::ElectiveResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::elective::ElectiveResponse>
+    for crate::api::jwxt::elective::ElectiveResponse
+{
+    fn into_into_dart(self) -> crate::api::jwxt::elective::ElectiveResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::session::Endpoints {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.cas.into_into_dart().into_dart(),
+            self.jwxt.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::session::Endpoints
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::session::Endpoints>
+    for crate::api::session::Endpoints
+{
+    fn into_into_dart(self) -> crate::api::session::Endpoints {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::exam::ExamCountdown {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.exam.into_into_dart().into_dart(),
+            self.remaining_seconds.into_into_dart().into_dart(),
+            self.is_finished.into_into_dart().into_dart(),
+            self.overlaps_with.into_into_dart().into_dart(),
+            self.same_day_with.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::exam::ExamCountdown
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::exam::ExamCountdown>
+    for crate::api::jwxt::exam::ExamCountdown
+{
+    fn into_into_dart(self) -> crate::api::jwxt::exam::ExamCountdown {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::exam::ExamSchedule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.campus.into_into_dart().into_dart(),
+            self.course_code.into_into_dart().into_dart(),
+            self.course_name.into_into_dart().into_dart(),
+            self.exam_time.into_into_dart().into_dart(),
+            self.start_time.into_into_dart().into_dart(),
+            self.end_time.into_into_dart().into_dart(),
+            self.exam_location.into_into_dart().into_dart(),
+            self.seat_number.into_into_dart().into_dart(),
+            self.exam_method.into_into_dart().into_dart(),
+            self.remark.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::exam::ExamSchedule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::exam::ExamSchedule>
+    for crate::api::jwxt::exam::ExamSchedule
+{
+    fn into_into_dart(self) -> crate::api::jwxt::exam::ExamSchedule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::plan::ExecutionPlan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.semester.into_into_dart().into_dart(),
+            self.academic_year.into_into_dart().into_dart(),
+            self.term.into_into_dart().into_dart(),
+            self.course_code.into_into_dart().into_dart(),
+            self.course_name.into_into_dart().into_dart(),
+            self.department.into_into_dart().into_dart(),
+            self.credits.into_into_dart().into_dart(),
+            self.total_hours.into_into_dart().into_dart(),
+            self.assessment_method.into_into_dart().into_dart(),
+            self.course_type.into_into_dart().into_dart(),
+            self.is_exam.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::plan::ExecutionPlan
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::plan::ExecutionPlan>
+    for crate::api::jwxt::plan::ExecutionPlan
+{
+    fn into_into_dart(self) -> crate::api::jwxt::plan::ExecutionPlan {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::plan::ExecutionPlanResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.plans.into_into_dart().into_dart(),
+            self.semesters.into_into_dart().into_dart(),
+            self.groups.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::plan::ExecutionPlanResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::plan::ExecutionPlanResponse>
+    for crate::api::jwxt::plan::ExecutionPlanResponse
+{
+    fn into_into_dart(self) -> crate::api::jwxt::plan::ExecutionPlanResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::classroom::FilterOption {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for std::collections::HashMap<String, String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::classroom::FilterOption
+{
 }
-
-impl SseDecode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<CookieStoreMutex>>,
-    >
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::classroom::FilterOption>
+    for crate::api::jwxt::classroom::FilterOption
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn into_into_dart(self) -> crate::api::jwxt::classroom::FilterOption {
+        self
     }
 }
-
-impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Client>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::classroom::FreeClassroom {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.campus.into_into_dart().into_dart(),
+            self.building.into_into_dart().into_dart(),
+            self.capacity.into_into_dart().into_dart(),
+            self.room_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CryptoError>>
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::classroom::FreeClassroom
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
-    }
 }
-
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>>
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::classroom::FreeClassroom>
+    for crate::api::jwxt::classroom::FreeClassroom
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn into_into_dart(self) -> crate::api::jwxt::classroom::FreeClassroom {
+        self
     }
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::gpa::GpaFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.course_natures.into_into_dart().into_dart(),
+            self.course_attrs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::gpa::GpaFilter
+{
 }
-
-impl SseDecode for crate::api::jwxt::course::CourseSchedule {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_teacher = <String>::sse_decode(deserializer);
-        let mut var_timeRange = <String>::sse_decode(deserializer);
-        let mut var_dayOfWeek = <String>::sse_decode(deserializer);
-        let mut var_section = <usize>::sse_decode(deserializer);
-        let mut var_weeks = <String>::sse_decode(deserializer);
-        let mut var_classroom = <String>::sse_decode(deserializer);
-        let mut var_duration = <String>::sse_decode(deserializer);
-        return crate::api::jwxt::course::CourseSchedule {
-            id: var_id,
-            name: var_name,
-            teacher: var_teacher,
-            time_range: var_timeRange,
-            day_of_week: var_dayOfWeek,
-            section: var_section,
-            weeks: var_weeks,
-            classroom: var_classroom,
-            duration: var_duration,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::gpa::GpaFilter>
+    for crate::api::jwxt::gpa::GpaFilter
+{
+    fn into_into_dart(self) -> crate::api::jwxt::gpa::GpaFilter {
+        self
     }
 }
-
-impl SseDecode for crate::api::jwxt::elective::CreditInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_category = <String>::sse_decode(deserializer);
-        let mut var_required_ = <i32>::sse_decode(deserializer);
-        let mut var_limited = <i32>::sse_decode(deserializer);
-        let mut var_elective = <i32>::sse_decode(deserializer);
-        let mut var_public = <i32>::sse_decode(deserializer);
-        let mut var_total = <i32>::sse_decode(deserializer);
-        return crate::api::jwxt::elective::CreditInfo {
-            category: var_category,
-            required: var_required_,
-            limited: var_limited,
-            elective: var_elective,
-            public: var_public,
-            total: var_total,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::gpa::GpaReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.scheme.into_into_dart().into_dart(),
+            self.overall.into_into_dart().into_dart(),
+            self.semesters.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::jwxt::dekt::DEKT {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_list = <Vec<crate::api::jwxt::dekt::DEKTList>>::sse_decode(deserializer);
-        let mut var_total = <Vec<crate::api::jwxt::dekt::DEKTTotal>>::sse_decode(deserializer);
-        return crate::api::jwxt::dekt::DEKT {
-            list: var_list,
-            total: var_total,
-        };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::gpa::GpaReport
+{
 }
-
-impl SseDecode for crate::api::jwxt::dekt::DEKTDetail {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <std::collections::HashMap<String, String>>::sse_decode(deserializer);
-        return crate::api::jwxt::dekt::DEKTDetail(var_field0);
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::gpa::GpaReport>
+    for crate::api::jwxt::gpa::GpaReport
+{
+    fn into_into_dart(self) -> crate::api::jwxt::gpa::GpaReport {
+        self
     }
 }
-
-impl SseDecode for crate::api::jwxt::dekt::DEKTList {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_semester = <String>::sse_decode(deserializer);
-        let mut var_category = <String>::sse_decode(deserializer);
-        let mut var_subCategory = <String>::sse_decode(deserializer);
-        let mut var_activityName = <String>::sse_decode(deserializer);
-        let mut var_credit = <String>::sse_decode(deserializer);
-        let mut var_operationId = <String>::sse_decode(deserializer);
-        return crate::api::jwxt::dekt::DEKTList {
-            id: var_id,
-            semester: var_semester,
-            category: var_category,
-            sub_category: var_subCategory,
-            activity_name: var_activityName,
-            credit: var_credit,
-            operation_id: var_operationId,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::gpa::GpaResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.gpa.into_into_dart().into_dart(),
+            self.average_score.into_into_dart().into_dart(),
+            self.credits.into_into_dart().into_dart(),
+            self.course_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::jwxt::dekt::DEKTTotal {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_category = <String>::sse_decode(deserializer);
-        let mut var_totalCredit = <String>::sse_decode(deserializer);
-        return crate::api::jwxt::dekt::DEKTTotal {
-            category: var_category,
-            total_credit: var_totalCredit,
-        };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::gpa::GpaResult
+{
 }
-
-impl SseDecode for crate::api::jwxt::elective::ElectiveResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_credits =
-            <Vec<crate::api::jwxt::elective::CreditInfo>>::sse_decode(deserializer);
-        let mut var_courses =
-            <Vec<crate::api::jwxt::elective::XqxkchInfo>>::sse_decode(deserializer);
-        return crate::api::jwxt::elective::ElectiveResponse {
-            credits: var_credits,
-            courses: var_courses,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::gpa::GpaResult>
+    for crate::api::jwxt::gpa::GpaResult
+{
+    fn into_into_dart(self) -> crate::api::jwxt::gpa::GpaResult {
+        self
     }
 }
-
-impl SseDecode for crate::api::jwxt::exam::ExamSchedule {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <u32>::sse_decode(deserializer);
-        let mut var_courseCode = <String>::sse_decode(deserializer);
-        let mut var_courseName = <String>::sse_decode(deserializer);
-        let mut var_examTime = <String>::sse_decode(deserializer);
-        let mut var_examLocation = <String>::sse_decode(deserializer);
-        return crate::api::jwxt::exam::ExamSchedule {
-            id: var_id,
-            course_code: var_courseCode,
-            course_name: var_courseName,
-            exam_time: var_examTime,
-            exam_location: var_examLocation,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::gpa::GpaScheme {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::School => 0.into_dart(),
+            Self::Standard4 => 1.into_dart(),
+            Self::Improved4 => 2.into_dart(),
+            Self::Pku4 => 3.into_dart(),
+            Self::Wes => 4.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-
-impl SseDecode for crate::api::jwxt::plan::ExecutionPlan {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <u32>::sse_decode(deserializer);
-        let mut var_semester = <String>::sse_decode(deserializer);
-        let mut var_courseCode = <String>::sse_decode(deserializer);
-        let mut var_courseName = <String>::sse_decode(deserializer);
-        let mut var_department = <String>::sse_decode(deserializer);
-        let mut var_credits = <f32>::sse_decode(deserializer);
-        let mut var_totalHours = <f32>::sse_decode(deserializer);
-        let mut var_assessmentMethod = <String>::sse_decode(deserializer);
-        let mut var_courseType = <String>::sse_decode(deserializer);
-        let mut var_isExam = <String>::sse_decode(deserializer);
-        return crate::api::jwxt::plan::ExecutionPlan {
-            id: var_id,
-            semester: var_semester,
-            course_code: var_courseCode,
-            course_name: var_courseName,
-            department: var_department,
-            credits: var_credits,
-            total_hours: var_totalHours,
-            assessment_method: var_assessmentMethod,
-            course_type: var_courseType,
-            is_exam: var_isExam,
-        };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::gpa::GpaScheme
+{
 }
-
-impl SseDecode for crate::api::jwxt::plan::ExecutionPlanResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_plans = <Vec<crate::api::jwxt::plan::ExecutionPlan>>::sse_decode(deserializer);
-        let mut var_semesters = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::jwxt::plan::ExecutionPlanResponse {
-            plans: var_plans,
-            semesters: var_semesters,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::gpa::GpaScheme>
+    for crate::api::jwxt::gpa::GpaScheme
+{
+    fn into_into_dart(self) -> crate::api::jwxt::gpa::GpaScheme {
+        self
     }
 }
-
-impl SseDecode for f32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::grade::Grade {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.raw.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.is_absent.into_into_dart().into_dart(),
+            self.is_exempt.into_into_dart().into_dart(),
+            self.is_retake.into_into_dart().into_dart(),
+            self.is_makeup.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::grade::Grade
+{
 }
-
-impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::grade::Grade>
+    for crate::api::jwxt::grade::Grade
+{
+    fn into_into_dart(self) -> crate::api::jwxt::grade::Grade {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::jwxt::course::CourseSchedule> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::jwxt::course::CourseSchedule>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::grade::GradeScale {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.excellent.into_into_dart().into_dart(),
+            self.good.into_into_dart().into_dart(),
+            self.medium.into_into_dart().into_dart(),
+            self.pass.into_into_dart().into_dart(),
+            self.fail.into_into_dart().into_dart(),
+            self.qualified.into_into_dart().into_dart(),
+            self.unqualified.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::api::jwxt::elective::CreditInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::jwxt::elective::CreditInfo>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::grade::GradeScale
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::grade::GradeScale>
+    for crate::api::jwxt::grade::GradeScale
+{
+    fn into_into_dart(self) -> crate::api::jwxt::grade::GradeScale {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::jwxt::dekt::DEKTList> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::jwxt::dekt::DEKTList>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::graduation::GraduationReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.buckets.into_into_dart().into_dart(),
+            self.passed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::api::jwxt::dekt::DEKTTotal> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::jwxt::dekt::DEKTTotal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::graduation::GraduationReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::graduation::GraduationReport>
+    for crate::api::jwxt::graduation::GraduationReport
+{
+    fn into_into_dart(self) -> crate::api::jwxt::graduation::GraduationReport {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::jwxt::exam::ExamSchedule> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::jwxt::exam::ExamSchedule>::sse_decode(
-                deserializer,
-            ));
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::session::LoginState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NoSession => 0.into_dart(),
+            Self::CaptchaFetched => 1.into_dart(),
+            Self::VpnAuthenticated => 2.into_dart(),
+            Self::JwxtAuthenticated => 3.into_dart(),
+            Self::Expired => 4.into_dart(),
+            _ => unreachable!(),
         }
-        return ans_;
     }
 }
-
-impl SseDecode for Vec<crate::api::jwxt::plan::ExecutionPlan> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::jwxt::plan::ExecutionPlan>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::session::LoginState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::session::LoginState>
+    for crate::api::session::LoginState
+{
+    fn into_into_dart(self) -> crate::api::session::LoginState {
+        self
     }
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::plan::PlanSemester {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.semester.into_into_dart().into_dart(),
+            self.academic_year.into_into_dart().into_dart(),
+            self.term.into_into_dart().into_dart(),
+            self.credits.into_into_dart().into_dart(),
+            self.total_hours.into_into_dart().into_dart(),
+            self.plans.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<(String, String)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::plan::PlanSemester
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::plan::PlanSemester>
+    for crate::api::jwxt::plan::PlanSemester
+{
+    fn into_into_dart(self) -> crate::api::jwxt::plan::PlanSemester {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::jwxt::score::ScoreInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::jwxt::score::ScoreInfo>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::calendar::SchoolCalendar {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.semester.into_into_dart().into_dart(),
+            self.start_date.into_into_dart().into_dart(),
+            self.weeks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::api::jwxt::semester::SemesterInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::jwxt::semester::SemesterInfo>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::calendar::SchoolCalendar
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::calendar::SchoolCalendar>
+    for crate::api::jwxt::calendar::SchoolCalendar
+{
+    fn into_into_dart(self) -> crate::api::jwxt::calendar::SchoolCalendar {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::jwxt::elective::XqxkchInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::jwxt::elective::XqxkchInfo>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::calendar::SchoolWeek {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.week.into_into_dart().into_dart(),
+            self.monday.into_into_dart().into_dart(),
+            self.holidays.into_into_dart().into_dart(),
+            self.swap_days.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for (String, String) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <String>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::calendar::SchoolWeek
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::calendar::SchoolWeek>
+    for crate::api::jwxt::calendar::SchoolWeek
+{
+    fn into_into_dart(self) -> crate::api::jwxt::calendar::SchoolWeek {
+        self
     }
 }
-
-impl SseDecode for crate::api::jwxt::score::ScoreInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <u32>::sse_decode(deserializer);
-        let mut var_term = <String>::sse_decode(deserializer);
-        let mut var_courseId = <String>::sse_decode(deserializer);
-        let mut var_courseName = <String>::sse_decode(deserializer);
-        let mut var_score = <f32>::sse_decode(deserializer);
-        let mut var_credit = <f32>::sse_decode(deserializer);
-        let mut var_hours = <f32>::sse_decode(deserializer);
-        let mut var_gpa = <f32>::sse_decode(deserializer);
-        let mut var_examType = <String>::sse_decode(deserializer);
-        let mut var_courseAttr = <String>::sse_decode(deserializer);
-        let mut var_courseNature = <String>::sse_decode(deserializer);
-        return crate::api::jwxt::score::ScoreInfo {
-            id: var_id,
-            term: var_term,
-            course_id: var_courseId,
-            course_name: var_courseName,
-            score: var_score,
-            credit: var_credit,
-            hours: var_hours,
-            gpa: var_gpa,
-            exam_type: var_examType,
-            course_attr: var_courseAttr,
-            course_nature: var_courseNature,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::score::ScoreInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.term.into_into_dart().into_dart(),
+            self.course_id.into_into_dart().into_dart(),
+            self.course_name.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.credit.into_into_dart().into_dart(),
+            self.hours.into_into_dart().into_dart(),
+            self.gpa.into_into_dart().into_dart(),
+            self.exam_type.into_into_dart().into_dart(),
+            self.course_attr.into_into_dart().into_dart(),
+            self.course_nature.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::jwxt::score::ScoreSummary {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_creditTotal = <f32>::sse_decode(deserializer);
-        let mut var_gpaAverage = <f32>::sse_decode(deserializer);
-        return crate::api::jwxt::score::ScoreSummary {
-            credit_total: var_creditTotal,
-            gpa_average: var_gpaAverage,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::score::ScoreInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::score::ScoreInfo>
+    for crate::api::jwxt::score::ScoreInfo
+{
+    fn into_into_dart(self) -> crate::api::jwxt::score::ScoreInfo {
+        self
     }
 }
-
-impl SseDecode for crate::api::jwxt::score::ScoreTotal {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_info = <Vec<crate::api::jwxt::score::ScoreInfo>>::sse_decode(deserializer);
-        let mut var_summary = <crate::api::jwxt::score::ScoreSummary>::sse_decode(deserializer);
-        return crate::api::jwxt::score::ScoreTotal {
-            info: var_info,
-            summary: var_summary,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::score::ScoreSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.credit_total.into_into_dart().into_dart(),
+            self.gpa_average.into_into_dart().into_dart(),
+            self.average_score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::jwxt::semester::SemesterInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_key = <String>::sse_decode(deserializer);
-        let mut var_value = <String>::sse_decode(deserializer);
-        return crate::api::jwxt::semester::SemesterInfo {
-            key: var_key,
-            value: var_value,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::score::ScoreSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::score::ScoreSummary>
+    for crate::api::jwxt::score::ScoreSummary
+{
+    fn into_into_dart(self) -> crate::api::jwxt::score::ScoreSummary {
+        self
     }
 }
-
-impl SseDecode for crate::api::jwxt::info::StudentInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_gender = <String>::sse_decode(deserializer);
-        let mut var_studentId = <String>::sse_decode(deserializer);
-        let mut var_department = <String>::sse_decode(deserializer);
-        let mut var_major = <String>::sse_decode(deserializer);
-        let mut var_className = <String>::sse_decode(deserializer);
-        let mut var_admissionDate = <String>::sse_decode(deserializer);
-        let mut var_admissionNumber = <String>::sse_decode(deserializer);
-        let mut var_idNumber = <String>::sse_decode(deserializer);
-        return crate::api::jwxt::info::StudentInfo {
-            name: var_name,
-            gender: var_gender,
-            student_id: var_studentId,
-            department: var_department,
-            major: var_major,
-            class_name: var_className,
-            admission_date: var_admissionDate,
-            admission_number: var_admissionNumber,
-            id_number: var_idNumber,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::score::ScoreTotal {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.info.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::score::ScoreTotal
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::score::ScoreTotal>
+    for crate::api::jwxt::score::ScoreTotal
+{
+    fn into_into_dart(self) -> crate::api::jwxt::score::ScoreTotal {
+        self
     }
 }
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::seat_watcher::SeatWatchConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.category.into_into_dart().into_dart(),
+            self.course_ids.into_into_dart().into_dart(),
+            self.interval_ms.into_into_dart().into_dart(),
+            self.jitter_ms.into_into_dart().into_dart(),
+            self.max_rounds.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::seat_watcher::SeatWatchConfig
+{
 }
-
-impl SseDecode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+impl flutter_rust_bridge::IntoIntoDart<crate::api::seat_watcher::SeatWatchConfig>
+    for crate::api::seat_watcher::SeatWatchConfig
+{
+    fn into_into_dart(self) -> crate::api::seat_watcher::SeatWatchConfig {
+        self
     }
 }
-
-impl SseDecode for crate::api::jwxt::elective::XqxkchInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_courseId = <String>::sse_decode(deserializer);
-        let mut var_courseName = <String>::sse_decode(deserializer);
-        let mut var_department = <String>::sse_decode(deserializer);
-        let mut var_hours = <i32>::sse_decode(deserializer);
-        let mut var_credits = <f32>::sse_decode(deserializer);
-        let mut var_courseAttribute = <String>::sse_decode(deserializer);
-        let mut var_selectionType = <String>::sse_decode(deserializer);
-        let mut var_selected = <String>::sse_decode(deserializer);
-        return crate::api::jwxt::elective::XqxkchInfo {
-            course_id: var_courseId,
-            course_name: var_courseName,
-            department: var_department,
-            hours: var_hours,
-            credits: var_credits,
-            course_attribute: var_courseAttribute,
-            selection_type: var_selectionType,
-            selected: var_selected,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::seat_watcher::SeatWatchEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::seat_watcher::SeatWatchEvent::Polled { round, courses } => [
+                0.into_dart(),
+                round.into_into_dart().into_dart(),
+                courses.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::seat_watcher::SeatWatchEvent::Attempted { course, outcome } => [
+                1.into_dart(),
+                course.into_into_dart().into_dart(),
+                outcome.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::seat_watcher::SeatWatchEvent::Error { message } => {
+                [2.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::seat_watcher::SeatWatchEvent::Finished { selected, pending } => [
+                3.into_dart(),
+                selected.into_into_dart().into_dart(),
+                pending.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__session__HttpSession_access_jwxt_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        6 => wire__crate__api__session__HttpSession_complete_login_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        7 => wire__crate__api__session__HttpSession_get_captcha_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        8 => wire__crate__api__session__HttpSession_get_flow_execution_key_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__api__session__HttpSession_new_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__aescbc__aes_cbc_encrypt_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__simple__api_course_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__simple__api_dekt_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__simple__api_dekt_detail_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__simple__api_elective_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__simple__api_exam_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__simple__api_get_captcha_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__simple__api_login_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__simple__api_plan_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__simple__api_score_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__simple__api_semester_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__simple__api_student_info_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__conwork__encode_inp_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__jwxt__course__parse_course_schedule_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        25 => wire__crate__api__jwxt__dekt__parse_dekt_impl(port, ptr, rust_vec_len, data_len),
-        26 => {
-            wire__crate__api__jwxt__dekt__parse_dekt_detail_impl(port, ptr, rust_vec_len, data_len)
-        }
-        27 => wire__crate__api__jwxt__dekt__parse_dekt_list_impl(port, ptr, rust_vec_len, data_len),
-        28 => {
-            wire__crate__api__jwxt__dekt__parse_dekt_total_impl(port, ptr, rust_vec_len, data_len)
-        }
-        29 => {
-            wire__crate__api__jwxt__elective__parse_elective_impl(port, ptr, rust_vec_len, data_len)
-        }
-        30 => wire__crate__api__jwxt__exam__parse_exam_impl(port, ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__jwxt__plan__parse_plan_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__jwxt__score__parse_score_impl(port, ptr, rust_vec_len, data_len),
-        33 => {
-            wire__crate__api__jwxt__score__parse_score_all_impl(port, ptr, rust_vec_len, data_len)
-        }
-        34 => wire__crate__api__jwxt__score__parse_score_summary_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        35 => {
-            wire__crate__api__jwxt__semester__parse_semester_impl(port, ptr, rust_vec_len, data_len)
-        }
-        36 => {
-            wire__crate__api__jwxt__info__parse_student_info_impl(port, ptr, rust_vec_len, data_len)
-        }
-        _ => unreachable!(),
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::seat_watcher::SeatWatchEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::seat_watcher::SeatWatchEvent>
+    for crate::api::seat_watcher::SeatWatchEvent
+{
+    fn into_into_dart(self) -> crate::api::seat_watcher::SeatWatchEvent {
+        self
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        2 => wire__crate__api__session__HttpSession_auto_accessor_get__cookie_store_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__api__session__HttpSession_auto_accessor_get_client_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__api__session__HttpSession_auto_accessor_set__cookie_store_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__session__HttpSession_auto_accessor_set_client_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ics::SectionTime {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.section.into_into_dart().into_dart(),
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ics::SectionTime {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ics::SectionTime>
+    for crate::api::ics::SectionTime
+{
+    fn into_into_dart(self) -> crate::api::ics::SectionTime {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Arc<CookieStoreMutex>> {
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::selection::SelectableCourse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.id.into_into_dart().into_dart(),
+            self.category.into_into_dart().into_dart(),
+            self.course_code.into_into_dart().into_dart(),
+            self.course_name.into_into_dart().into_dart(),
+            self.credits.into_into_dart().into_dart(),
+            self.teacher.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+            self.location.into_into_dart().into_dart(),
+            self.campus.into_into_dart().into_dart(),
+            self.capacity.into_into_dart().into_dart(),
+            self.selected.into_into_dart().into_dart(),
+            self.remaining.into_into_dart().into_dart(),
+            self.conflict.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<Arc<CookieStoreMutex>>
+    for crate::api::jwxt::selection::SelectableCourse
 {
 }
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Arc<CookieStoreMutex>>>
-    for Arc<CookieStoreMutex>
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::selection::SelectableCourse>
+    for crate::api::jwxt::selection::SelectableCourse
 {
-    fn into_into_dart(self) -> FrbWrapper<Arc<CookieStoreMutex>> {
-        self.into()
+    fn into_into_dart(self) -> crate::api::jwxt::selection::SelectableCourse {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Client> {
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::selection::SelectionCategory {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            Self::PublicElective => 0.into_dart(),
+            Self::MajorElective => 1.into_dart(),
+            Self::CrossMajor => 2.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Client> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Client>> for Client {
-    fn into_into_dart(self) -> FrbWrapper<Client> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::selection::SelectionCategory
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::selection::SelectionCategory>
+    for crate::api::jwxt::selection::SelectionCategory
+{
+    fn into_into_dart(self) -> crate::api::jwxt::selection::SelectionCategory {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<CryptoError> {
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::selection::SelectionOutcome {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        [
+            self.result.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<CryptoError> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<CryptoError>> for CryptoError {
-    fn into_into_dart(self) -> FrbWrapper<CryptoError> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::selection::SelectionOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::selection::SelectionOutcome>
+    for crate::api::jwxt::selection::SelectionOutcome
+{
+    fn into_into_dart(self) -> crate::api::jwxt::selection::SelectionOutcome {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<HttpSession> {
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::selection::SelectionResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+        match self {
+            Self::Success => 0.into_dart(),
+            Self::TimeConflict => 1.into_dart(),
+            Self::Full => 2.into_dart(),
+            Self::OutsideWindow => 3.into_dart(),
+            Self::Failed => 4.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<HttpSession> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<HttpSession>> for HttpSession {
-    fn into_into_dart(self) -> FrbWrapper<HttpSession> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::selection::SelectionResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::selection::SelectionResult>
+    for crate::api::jwxt::selection::SelectionResult
+{
+    fn into_into_dart(self) -> crate::api::jwxt::selection::SelectionResult {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::course::CourseSchedule {
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::selection::SelectionRound {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.id.into_into_dart().into_dart(),
+            self.semester.into_into_dart().into_dart(),
             self.name.into_into_dart().into_dart(),
-            self.teacher.into_into_dart().into_dart(),
-            self.time_range.into_into_dart().into_dart(),
-            self.day_of_week.into_into_dart().into_dart(),
-            self.section.into_into_dart().into_dart(),
-            self.weeks.into_into_dart().into_dart(),
-            self.classroom.into_into_dart().into_dart(),
-            self.duration.into_into_dart().into_dart(),
+            self.start_time.into_into_dart().into_dart(),
+            self.end_time.into_into_dart().into_dart(),
+            self.can_enter.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::course::CourseSchedule
+    for crate::api::jwxt::selection::SelectionRound
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::course::CourseSchedule>
-    for crate::api::jwxt::course::CourseSchedule
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::selection::SelectionRound>
+    for crate::api::jwxt::selection::SelectionRound
 {
-    fn into_into_dart(self) -> crate::api::jwxt::course::CourseSchedule {
+    fn into_into_dart(self) -> crate::api::jwxt::selection::SelectionRound {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::elective::CreditInfo {
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::gpa::SemesterGpa {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.category.into_into_dart().into_dart(),
-            self.required.into_into_dart().into_dart(),
-            self.limited.into_into_dart().into_dart(),
-            self.elective.into_into_dart().into_dart(),
-            self.public.into_into_dart().into_dart(),
-            self.total.into_into_dart().into_dart(),
+            self.term.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::elective::CreditInfo
+    for crate::api::jwxt::gpa::SemesterGpa
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::elective::CreditInfo>
-    for crate::api::jwxt::elective::CreditInfo
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::gpa::SemesterGpa>
+    for crate::api::jwxt::gpa::SemesterGpa
 {
-    fn into_into_dart(self) -> crate::api::jwxt::elective::CreditInfo {
+    fn into_into_dart(self) -> crate::api::jwxt::gpa::SemesterGpa {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::dekt::DEKT {
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::semester::SemesterInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.list.into_into_dart().into_dart(),
-            self.total.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.academic_year.into_into_dart().into_dart(),
+            self.term.into_into_dart().into_dart(),
+            self.is_current.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::jwxt::dekt::DEKT {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::dekt::DEKT>
-    for crate::api::jwxt::dekt::DEKT
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::semester::SemesterInfo
 {
-    fn into_into_dart(self) -> crate::api::jwxt::dekt::DEKT {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::semester::SemesterInfo>
+    for crate::api::jwxt::semester::SemesterInfo
+{
+    fn into_into_dart(self) -> crate::api::jwxt::semester::SemesterInfo {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::dekt::DEKTDetail {
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::semester::SemesterStart {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.0.into_into_dart().into_dart()].into_dart()
+        [
+            self.semester.into_into_dart().into_dart(),
+            self.start_date.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::dekt::DEKTDetail
+    for crate::api::jwxt::semester::SemesterStart
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::dekt::DEKTDetail>
-    for crate::api::jwxt::dekt::DEKTDetail
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::semester::SemesterStart>
+    for crate::api::jwxt::semester::SemesterStart
 {
-    fn into_into_dart(self) -> crate::api::jwxt::dekt::DEKTDetail {
+    fn into_into_dart(self) -> crate::api::jwxt::semester::SemesterStart {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::dekt::DEKTList {
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::info::StudentInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.semester.into_into_dart().into_dart(),
-            self.category.into_into_dart().into_dart(),
-            self.sub_category.into_into_dart().into_dart(),
-            self.activity_name.into_into_dart().into_dart(),
-            self.credit.into_into_dart().into_dart(),
-            self.operation_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.gender.into_into_dart().into_dart(),
+            self.student_id.into_into_dart().into_dart(),
+            self.department.into_into_dart().into_dart(),
+            self.major.into_into_dart().into_dart(),
+            self.class_name.into_into_dart().into_dart(),
+            self.admission_date.into_into_dart().into_dart(),
+            self.admission_number.into_into_dart().into_dart(),
+            self.id_number.into_into_dart().into_dart(),
+            self.birth_date.into_into_dart().into_dart(),
+            self.ethnicity.into_into_dart().into_dart(),
+            self.political_status.into_into_dart().into_dart(),
+            self.hometown.into_into_dart().into_dart(),
+            self.education_level.into_into_dart().into_dart(),
+            self.length_of_schooling.into_into_dart().into_dart(),
+            self.contact.into_into_dart().into_dart(),
+            self.high_school.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::dekt::DEKTList
+    for crate::api::jwxt::info::StudentInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::info::StudentInfo>
+    for crate::api::jwxt::info::StudentInfo
+{
+    fn into_into_dart(self) -> crate::api::jwxt::info::StudentInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::info::StudentPhoto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bytes.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::info::StudentPhoto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::info::StudentPhoto>
+    for crate::api::jwxt::info::StudentPhoto
+{
+    fn into_into_dart(self) -> crate::api::jwxt::info::StudentPhoto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::jwxt::elective::XqxkchInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.course_id.into_into_dart().into_dart(),
+            self.course_name.into_into_dart().into_dart(),
+            self.department.into_into_dart().into_dart(),
+            self.hours.into_into_dart().into_dart(),
+            self.credits.into_into_dart().into_dart(),
+            self.course_attribute.into_into_dart().into_dart(),
+            self.selection_type.into_into_dart().into_dart(),
+            self.selected.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jwxt::elective::XqxkchInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::elective::XqxkchInfo>
+    for crate::api::jwxt::elective::XqxkchInfo
+{
+    fn into_into_dart(self) -> crate::api::jwxt::elective::XqxkchInfo {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for Arc<CookieStoreMutex> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<CookieStoreMutex>>,
+        >>::sse_encode(
+            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Client {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Client>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for CryptoError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CryptoError>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for HttpSession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for std::collections::HashMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<CookieStoreMutex>>,
+    >
 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Client>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::dekt::DEKTList>
-    for crate::api::jwxt::dekt::DEKTList
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CryptoError>>
 {
-    fn into_into_dart(self) -> crate::api::jwxt::dekt::DEKTList {
-        self
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::dekt::DEKTTotal {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.category.into_into_dart().into_dart(),
-            self.total_credit.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::dekt::DEKTTotal
+
+impl SseEncode
+    for StreamSink<
+        crate::api::seat_watcher::SeatWatchEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::dekt::DEKTTotal>
-    for crate::api::jwxt::dekt::DEKTTotal
-{
-    fn into_into_dart(self) -> crate::api::jwxt::dekt::DEKTTotal {
-        self
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::elective::ElectiveResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.credits.into_into_dart().into_dart(),
-            self.courses.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::error::ApiError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::error::ApiError::Network { cause } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(cause, serializer);
+            }
+            crate::api::error::ApiError::SessionExpired => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::error::ApiError::BadCredentials { message } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::ApiError::BadCaptcha => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::error::ApiError::ParseLayoutChanged { page, selector } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(page, serializer);
+                <String>::sse_encode(selector, serializer);
+            }
+            crate::api::error::ApiError::NotInitialized => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::api::error::ApiError::NotLoggedIn => {
+                <i32>::sse_encode(6, serializer);
+            }
+            crate::api::error::ApiError::InvalidArgument { message } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::error::ApiError::Io { cause } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(cause, serializer);
+            }
+            crate::api::error::ApiError::Internal { cause } => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(cause, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::elective::ElectiveResponse
-{
+
+impl SseEncode for crate::api::jwxt::audit::AuditCourse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.course_code, serializer);
+        <String>::sse_encode(self.course_name, serializer);
+        <String>::sse_encode(self.semester, serializer);
+        <String>::sse_encode(self.course_type, serializer);
+        <f32>::sse_encode(self.credits, serializer);
+        <crate::api::jwxt::audit::CourseStatus>::sse_encode(self.status, serializer);
+        <Option<crate::api::jwxt::grade::Grade>>::sse_encode(self.best_score, serializer);
+        <u32>::sse_encode(self.attempts, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::elective::ElectiveResponse>
-    for crate::api::jwxt::elective::ElectiveResponse
-{
-    fn into_into_dart(self) -> crate::api::jwxt::elective::ElectiveResponse {
-        self
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::exam::ExamSchedule {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.course_code.into_into_dart().into_dart(),
-            self.course_name.into_into_dart().into_dart(),
-            self.exam_time.into_into_dart().into_dart(),
-            self.exam_location.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::jwxt::calendar::CalendarDay {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.date, serializer);
+        <u32>::sse_encode(self.weekday, serializer);
+        <crate::api::jwxt::calendar::CalendarDayKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.note, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::exam::ExamSchedule
-{
+
+impl SseEncode for crate::api::jwxt::calendar::CalendarDayKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::jwxt::calendar::CalendarDayKind::Holiday => 0,
+                crate::api::jwxt::calendar::CalendarDayKind::Workday => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::exam::ExamSchedule>
-    for crate::api::jwxt::exam::ExamSchedule
-{
-    fn into_into_dart(self) -> crate::api::jwxt::exam::ExamSchedule {
-        self
+
+impl SseEncode for crate::api::jwxt::classroom::ClassroomFilters {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::jwxt::classroom::FilterOption>>::sse_encode(self.campuses, serializer);
+        <Vec<crate::api::jwxt::classroom::FilterOption>>::sse_encode(self.buildings, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::plan::ExecutionPlan {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.semester.into_into_dart().into_dart(),
-            self.course_code.into_into_dart().into_dart(),
-            self.course_name.into_into_dart().into_dart(),
-            self.department.into_into_dart().into_dart(),
-            self.credits.into_into_dart().into_dart(),
-            self.total_hours.into_into_dart().into_dart(),
-            self.assessment_method.into_into_dart().into_dart(),
-            self.course_type.into_into_dart().into_dart(),
-            self.is_exam.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::jwxt::course::CourseSchedule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.teacher, serializer);
+        <String>::sse_encode(self.time_range, serializer);
+        <String>::sse_encode(self.day_of_week, serializer);
+        <usize>::sse_encode(self.section, serializer);
+        <String>::sse_encode(self.weeks, serializer);
+        <Vec<u8>>::sse_encode(self.week_list, serializer);
+        <String>::sse_encode(self.classroom, serializer);
+        <String>::sse_encode(self.duration, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::plan::ExecutionPlan
-{
+
+impl SseEncode for crate::api::jwxt::audit::CourseStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::jwxt::audit::CourseStatus::Passed => 0,
+                crate::api::jwxt::audit::CourseStatus::Failed => 1,
+                crate::api::jwxt::audit::CourseStatus::InProgress => 2,
+                crate::api::jwxt::audit::CourseStatus::NotTaken => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::plan::ExecutionPlan>
-    for crate::api::jwxt::plan::ExecutionPlan
-{
-    fn into_into_dart(self) -> crate::api::jwxt::plan::ExecutionPlan {
-        self
+
+impl SseEncode for crate::api::jwxt::graduation::CreditBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::jwxt::graduation::CreditSource>::sse_encode(self.source, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <f32>::sse_encode(self.earned, serializer);
+        <f32>::sse_encode(self.required, serializer);
+        <f32>::sse_encode(self.missing, serializer);
+        <bool>::sse_encode(self.passed, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::plan::ExecutionPlanResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.plans.into_into_dart().into_dart(),
-            self.semesters.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::jwxt::elective::CreditInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.category, serializer);
+        <f32>::sse_encode(self.required, serializer);
+        <f32>::sse_encode(self.limited, serializer);
+        <f32>::sse_encode(self.elective, serializer);
+        <f32>::sse_encode(self.public, serializer);
+        <f32>::sse_encode(self.total, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::plan::ExecutionPlanResponse
-{
+
+impl SseEncode for crate::api::jwxt::audit::CreditProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <f32>::sse_encode(self.earned, serializer);
+        <f32>::sse_encode(self.required, serializer);
+        <u32>::sse_encode(self.passed_count, serializer);
+        <u32>::sse_encode(self.course_count, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::plan::ExecutionPlanResponse>
-    for crate::api::jwxt::plan::ExecutionPlanResponse
-{
-    fn into_into_dart(self) -> crate::api::jwxt::plan::ExecutionPlanResponse {
-        self
+
+impl SseEncode for crate::api::jwxt::graduation::CreditSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::jwxt::graduation::CreditSource::Course => 0,
+                crate::api::jwxt::graduation::CreditSource::SecondClassroom => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::score::ScoreInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.term.into_into_dart().into_dart(),
-            self.course_id.into_into_dart().into_dart(),
-            self.course_name.into_into_dart().into_dart(),
-            self.score.into_into_dart().into_dart(),
-            self.credit.into_into_dart().into_dart(),
-            self.hours.into_into_dart().into_dart(),
-            self.gpa.into_into_dart().into_dart(),
-            self.exam_type.into_into_dart().into_dart(),
-            self.course_attr.into_into_dart().into_dart(),
-            self.course_nature.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::jwxt::semester::CurrentWeek {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.semester, serializer);
+        <String>::sse_encode(self.start_date, serializer);
+        <i32>::sse_encode(self.week, serializer);
+        <u32>::sse_encode(self.weekday, serializer);
+        <bool>::sse_encode(self.is_overridden, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::score::ScoreInfo
-{
+
+impl SseEncode for crate::api::jwxt::audit::DegreeAudit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::jwxt::audit::AuditCourse>>::sse_encode(self.courses, serializer);
+        <Vec<crate::api::jwxt::audit::CreditProgress>>::sse_encode(self.by_course_type, serializer);
+        <Vec<crate::api::jwxt::audit::CreditProgress>>::sse_encode(self.by_semester, serializer);
+        <f32>::sse_encode(self.earned, serializer);
+        <f32>::sse_encode(self.required, serializer);
+        <Vec<crate::api::jwxt::score::ScoreInfo>>::sse_encode(self.outside_plan, serializer);
+        <f32>::sse_encode(self.outside_plan_earned, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::score::ScoreInfo>
-    for crate::api::jwxt::score::ScoreInfo
-{
-    fn into_into_dart(self) -> crate::api::jwxt::score::ScoreInfo {
-        self
+
+impl SseEncode for crate::api::jwxt::dekt::DEKT {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::jwxt::dekt::DEKTList>>::sse_encode(self.list, serializer);
+        <Vec<crate::api::jwxt::dekt::DEKTTotal>>::sse_encode(self.total, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::score::ScoreSummary {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.credit_total.into_into_dart().into_dart(),
-            self.gpa_average.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::jwxt::dekt::DEKTDetail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <std::collections::HashMap<String, String>>::sse_encode(self.0, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::score::ScoreSummary
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::score::ScoreSummary>
-    for crate::api::jwxt::score::ScoreSummary
-{
-    fn into_into_dart(self) -> crate::api::jwxt::score::ScoreSummary {
-        self
+
+impl SseEncode for crate::api::jwxt::dekt::DEKTList {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.semester, serializer);
+        <String>::sse_encode(self.category, serializer);
+        <String>::sse_encode(self.sub_category, serializer);
+        <String>::sse_encode(self.activity_name, serializer);
+        <String>::sse_encode(self.credit, serializer);
+        <String>::sse_encode(self.operation_id, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::score::ScoreTotal {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.info.into_into_dart().into_dart(),
-            self.summary.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::jwxt::graduation::DektRequirement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.category, serializer);
+        <f32>::sse_encode(self.credits, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::score::ScoreTotal
-{
+
+impl SseEncode for crate::api::jwxt::dekt::DEKTTotal {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.category, serializer);
+        <String>::sse_encode(self.total_credit, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::score::ScoreTotal>
-    for crate::api::jwxt::score::ScoreTotal
-{
-    fn into_into_dart(self) -> crate::api::jwxt::score::ScoreTotal {
-        self
+
+impl SseEncode for crate::api::jwxt::elective::ElectiveResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::jwxt::elective::CreditInfo>>::sse_encode(self.credits, serializer);
+        <Vec<crate::api::jwxt::elective::XqxkchInfo>>::sse_encode(self.courses, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::semester::SemesterInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.key.into_into_dart().into_dart(),
-            self.value.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::session::Endpoints {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.cas, serializer);
+        <String>::sse_encode(self.jwxt, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::semester::SemesterInfo
-{
+
+impl SseEncode for crate::api::jwxt::exam::ExamCountdown {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::jwxt::exam::ExamSchedule>::sse_encode(self.exam, serializer);
+        <Option<i64>>::sse_encode(self.remaining_seconds, serializer);
+        <bool>::sse_encode(self.is_finished, serializer);
+        <Vec<u32>>::sse_encode(self.overlaps_with, serializer);
+        <Vec<u32>>::sse_encode(self.same_day_with, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::semester::SemesterInfo>
-    for crate::api::jwxt::semester::SemesterInfo
-{
-    fn into_into_dart(self) -> crate::api::jwxt::semester::SemesterInfo {
-        self
+
+impl SseEncode for crate::api::jwxt::exam::ExamSchedule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.campus, serializer);
+        <String>::sse_encode(self.course_code, serializer);
+        <String>::sse_encode(self.course_name, serializer);
+        <String>::sse_encode(self.exam_time, serializer);
+        <Option<String>>::sse_encode(self.start_time, serializer);
+        <Option<String>>::sse_encode(self.end_time, serializer);
+        <String>::sse_encode(self.exam_location, serializer);
+        <String>::sse_encode(self.seat_number, serializer);
+        <String>::sse_encode(self.exam_method, serializer);
+        <String>::sse_encode(self.remark, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::info::StudentInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.name.into_into_dart().into_dart(),
-            self.gender.into_into_dart().into_dart(),
-            self.student_id.into_into_dart().into_dart(),
-            self.department.into_into_dart().into_dart(),
-            self.major.into_into_dart().into_dart(),
-            self.class_name.into_into_dart().into_dart(),
-            self.admission_date.into_into_dart().into_dart(),
-            self.admission_number.into_into_dart().into_dart(),
-            self.id_number.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::jwxt::plan::ExecutionPlan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.semester, serializer);
+        <String>::sse_encode(self.academic_year, serializer);
+        <u8>::sse_encode(self.term, serializer);
+        <String>::sse_encode(self.course_code, serializer);
+        <String>::sse_encode(self.course_name, serializer);
+        <String>::sse_encode(self.department, serializer);
+        <f32>::sse_encode(self.credits, serializer);
+        <f32>::sse_encode(self.total_hours, serializer);
+        <String>::sse_encode(self.assessment_method, serializer);
+        <String>::sse_encode(self.course_type, serializer);
+        <String>::sse_encode(self.is_exam, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::info::StudentInfo
-{
+
+impl SseEncode for crate::api::jwxt::plan::ExecutionPlanResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::jwxt::plan::ExecutionPlan>>::sse_encode(self.plans, serializer);
+        <Vec<String>>::sse_encode(self.semesters, serializer);
+        <Vec<crate::api::jwxt::plan::PlanSemester>>::sse_encode(self.groups, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::info::StudentInfo>
-    for crate::api::jwxt::info::StudentInfo
-{
-    fn into_into_dart(self) -> crate::api::jwxt::info::StudentInfo {
-        self
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::jwxt::elective::XqxkchInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.course_id.into_into_dart().into_dart(),
-            self.course_name.into_into_dart().into_dart(),
-            self.department.into_into_dart().into_dart(),
-            self.hours.into_into_dart().into_dart(),
-            self.credits.into_into_dart().into_dart(),
-            self.course_attribute.into_into_dart().into_dart(),
-            self.selection_type.into_into_dart().into_dart(),
-            self.selected.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::jwxt::classroom::FilterOption {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::jwxt::elective::XqxkchInfo
-{
+
+impl SseEncode for crate::api::jwxt::classroom::FreeClassroom {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.campus, serializer);
+        <String>::sse_encode(self.building, serializer);
+        <u32>::sse_encode(self.capacity, serializer);
+        <String>::sse_encode(self.room_type, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::jwxt::elective::XqxkchInfo>
-    for crate::api::jwxt::elective::XqxkchInfo
-{
-    fn into_into_dart(self) -> crate::api::jwxt::elective::XqxkchInfo {
-        self
+
+impl SseEncode for crate::api::jwxt::gpa::GpaFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.course_natures, serializer);
+        <Vec<String>>::sse_encode(self.course_attrs, serializer);
     }
 }
 
-impl SseEncode for Arc<CookieStoreMutex> {
+impl SseEncode for crate::api::jwxt::gpa::GpaReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<CookieStoreMutex>>,
-        >>::sse_encode(
-            flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self),
-            serializer,
-        );
+        <crate::api::jwxt::gpa::GpaScheme>::sse_encode(self.scheme, serializer);
+        <crate::api::jwxt::gpa::GpaResult>::sse_encode(self.overall, serializer);
+        <Vec<crate::api::jwxt::gpa::SemesterGpa>>::sse_encode(self.semesters, serializer);
     }
 }
 
-impl SseEncode for Client {
+impl SseEncode for crate::api::jwxt::gpa::GpaResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Client>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <Option<f32>>::sse_encode(self.gpa, serializer);
+        <Option<f32>>::sse_encode(self.average_score, serializer);
+        <f32>::sse_encode(self.credits, serializer);
+        <u32>::sse_encode(self.course_count, serializer);
     }
 }
 
-impl SseEncode for CryptoError {
+impl SseEncode for crate::api::jwxt::gpa::GpaScheme {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CryptoError>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::jwxt::gpa::GpaScheme::School => 0,
+                crate::api::jwxt::gpa::GpaScheme::Standard4 => 1,
+                crate::api::jwxt::gpa::GpaScheme::Improved4 => 2,
+                crate::api::jwxt::gpa::GpaScheme::Pku4 => 3,
+                crate::api::jwxt::gpa::GpaScheme::Wes => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for HttpSession {
+impl SseEncode for crate::api::jwxt::grade::Grade {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.raw, serializer);
+        <Option<f32>>::sse_encode(self.value, serializer);
+        <bool>::sse_encode(self.is_absent, serializer);
+        <bool>::sse_encode(self.is_exempt, serializer);
+        <bool>::sse_encode(self.is_retake, serializer);
+        <bool>::sse_encode(self.is_makeup, serializer);
     }
 }
 
-impl SseEncode for std::collections::HashMap<String, String> {
+impl SseEncode for crate::api::jwxt::grade::GradeScale {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
+        <f32>::sse_encode(self.excellent, serializer);
+        <f32>::sse_encode(self.good, serializer);
+        <f32>::sse_encode(self.medium, serializer);
+        <f32>::sse_encode(self.pass, serializer);
+        <f32>::sse_encode(self.fail, serializer);
+        <f32>::sse_encode(self.qualified, serializer);
+        <f32>::sse_encode(self.unqualified, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<
-        flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Arc<CookieStoreMutex>>,
-    >
-{
+impl SseEncode for crate::api::jwxt::graduation::GraduationReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <Vec<crate::api::jwxt::graduation::CreditBucket>>::sse_encode(self.buckets, serializer);
+        <bool>::sse_encode(self.passed, serializer);
     }
 }
 
-impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Client>> {
+impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CryptoError>>
-{
+impl SseEncode for i64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<HttpSession>>
-{
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<crate::api::jwxt::audit::AuditCourse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::audit::AuditCourse>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Vec<crate::api::jwxt::calendar::CalendarDay> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::calendar::CalendarDay>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::jwxt::course::CourseSchedule {
+impl SseEncode for Vec<crate::api::jwxt::course::CourseSchedule> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <String>::sse_encode(self.teacher, serializer);
-        <String>::sse_encode(self.time_range, serializer);
-        <String>::sse_encode(self.day_of_week, serializer);
-        <usize>::sse_encode(self.section, serializer);
-        <String>::sse_encode(self.weeks, serializer);
-        <String>::sse_encode(self.classroom, serializer);
-        <String>::sse_encode(self.duration, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::course::CourseSchedule>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::jwxt::elective::CreditInfo {
+impl SseEncode for Vec<crate::api::jwxt::graduation::CreditBucket> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.category, serializer);
-        <i32>::sse_encode(self.required, serializer);
-        <i32>::sse_encode(self.limited, serializer);
-        <i32>::sse_encode(self.elective, serializer);
-        <i32>::sse_encode(self.public, serializer);
-        <i32>::sse_encode(self.total, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::graduation::CreditBucket>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::jwxt::dekt::DEKT {
+impl SseEncode for Vec<crate::api::jwxt::elective::CreditInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::api::jwxt::dekt::DEKTList>>::sse_encode(self.list, serializer);
-        <Vec<crate::api::jwxt::dekt::DEKTTotal>>::sse_encode(self.total, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::elective::CreditInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::jwxt::dekt::DEKTDetail {
+impl SseEncode for Vec<crate::api::jwxt::audit::CreditProgress> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <std::collections::HashMap<String, String>>::sse_encode(self.0, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::audit::CreditProgress>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::jwxt::dekt::DEKTList {
+impl SseEncode for Vec<crate::api::jwxt::dekt::DEKTList> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.semester, serializer);
-        <String>::sse_encode(self.category, serializer);
-        <String>::sse_encode(self.sub_category, serializer);
-        <String>::sse_encode(self.activity_name, serializer);
-        <String>::sse_encode(self.credit, serializer);
-        <String>::sse_encode(self.operation_id, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::dekt::DEKTList>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::jwxt::dekt::DEKTTotal {
+impl SseEncode for Vec<crate::api::jwxt::graduation::DektRequirement> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.category, serializer);
-        <String>::sse_encode(self.total_credit, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::graduation::DektRequirement>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::jwxt::elective::ElectiveResponse {
+impl SseEncode for Vec<crate::api::jwxt::dekt::DEKTTotal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::api::jwxt::elective::CreditInfo>>::sse_encode(self.credits, serializer);
-        <Vec<crate::api::jwxt::elective::XqxkchInfo>>::sse_encode(self.courses, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::dekt::DEKTTotal>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::jwxt::exam::ExamSchedule {
+impl SseEncode for Vec<crate::api::jwxt::exam::ExamCountdown> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.course_code, serializer);
-        <String>::sse_encode(self.course_name, serializer);
-        <String>::sse_encode(self.exam_time, serializer);
-        <String>::sse_encode(self.exam_location, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::exam::ExamCountdown>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::jwxt::plan::ExecutionPlan {
+impl SseEncode for Vec<crate::api::jwxt::exam::ExamSchedule> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.semester, serializer);
-        <String>::sse_encode(self.course_code, serializer);
-        <String>::sse_encode(self.course_name, serializer);
-        <String>::sse_encode(self.department, serializer);
-        <f32>::sse_encode(self.credits, serializer);
-        <f32>::sse_encode(self.total_hours, serializer);
-        <String>::sse_encode(self.assessment_method, serializer);
-        <String>::sse_encode(self.course_type, serializer);
-        <String>::sse_encode(self.is_exam, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::exam::ExamSchedule>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::jwxt::plan::ExecutionPlanResponse {
+impl SseEncode for Vec<crate::api::jwxt::plan::ExecutionPlan> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::api::jwxt::plan::ExecutionPlan>>::sse_encode(self.plans, serializer);
-        <Vec<String>>::sse_encode(self.semesters, serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::plan::ExecutionPlan>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for Vec<crate::api::jwxt::classroom::FilterOption> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::classroom::FilterOption>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Vec<crate::api::jwxt::classroom::FreeClassroom> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::jwxt::classroom::FreeClassroom>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Vec<crate::api::jwxt::plan::PlanSemester> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <String>::sse_encode(item, serializer);
+            <crate::api::jwxt::plan::PlanSemester>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::jwxt::course::CourseSchedule> {
+impl SseEncode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::jwxt::course::CourseSchedule>::sse_encode(item, serializer);
+            <u32>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::jwxt::elective::CreditInfo> {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::jwxt::elective::CreditInfo>::sse_encode(item, serializer);
+            <u8>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::jwxt::dekt::DEKTList> {
+impl SseEncode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::jwxt::dekt::DEKTList>::sse_encode(item, serializer);
+            <(String, String)>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::jwxt::dekt::DEKTTotal> {
+impl SseEncode for Vec<crate::api::jwxt::calendar::SchoolWeek> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::jwxt::dekt::DEKTTotal>::sse_encode(item, serializer);
+            <crate::api::jwxt::calendar::SchoolWeek>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::jwxt::exam::ExamSchedule> {
+impl SseEncode for Vec<crate::api::jwxt::score::ScoreInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::jwxt::exam::ExamSchedule>::sse_encode(item, serializer);
+            <crate::api::jwxt::score::ScoreInfo>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::jwxt::plan::ExecutionPlan> {
+impl SseEncode for Vec<crate::api::ics::SectionTime> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::jwxt::plan::ExecutionPlan>::sse_encode(item, serializer);
+            <crate::api::ics::SectionTime>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Vec<crate::api::jwxt::selection::SelectableCourse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <u8>::sse_encode(item, serializer);
+            <crate::api::jwxt::selection::SelectableCourse>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<(String, String)> {
+impl SseEncode for Vec<crate::api::jwxt::selection::SelectionRound> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <(String, String)>::sse_encode(item, serializer);
+            <crate::api::jwxt::selection::SelectionRound>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::jwxt::score::ScoreInfo> {
+impl SseEncode for Vec<crate::api::jwxt::gpa::SemesterGpa> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::jwxt::score::ScoreInfo>::sse_encode(item, serializer);
+            <crate::api::jwxt::gpa::SemesterGpa>::sse_encode(item, serializer);
         }
     }
 }
@@ -2964,6 +7385,107 @@ impl SseEncode for Vec<crate::api::jwxt::elective::XqxkchInfo> {
     }
 }
 
+impl SseEncode for crate::api::session::LoginState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::session::LoginState::NoSession => 0,
+                crate::api::session::LoginState::CaptchaFetched => 1,
+                crate::api::session::LoginState::VpnAuthenticated => 2,
+                crate::api::session::LoginState::JwxtAuthenticated => 3,
+                crate::api::session::LoginState::Expired => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::jwxt::grade::Grade> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::jwxt::grade::Grade>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<(String, u8)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <(String, u8)>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::jwxt::semester::SemesterStart> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::jwxt::semester::SemesterStart>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::jwxt::plan::PlanSemester {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.semester, serializer);
+        <String>::sse_encode(self.academic_year, serializer);
+        <u8>::sse_encode(self.term, serializer);
+        <f32>::sse_encode(self.credits, serializer);
+        <f32>::sse_encode(self.total_hours, serializer);
+        <Vec<crate::api::jwxt::plan::ExecutionPlan>>::sse_encode(self.plans, serializer);
+    }
+}
+
 impl SseEncode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2972,6 +7494,33 @@ impl SseEncode for (String, String) {
     }
 }
 
+impl SseEncode for (String, u8) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.0, serializer);
+        <u8>::sse_encode(self.1, serializer);
+    }
+}
+
+impl SseEncode for crate::api::jwxt::calendar::SchoolCalendar {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.semester, serializer);
+        <String>::sse_encode(self.start_date, serializer);
+        <Vec<crate::api::jwxt::calendar::SchoolWeek>>::sse_encode(self.weeks, serializer);
+    }
+}
+
+impl SseEncode for crate::api::jwxt::calendar::SchoolWeek {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.week, serializer);
+        <String>::sse_encode(self.monday, serializer);
+        <Vec<crate::api::jwxt::calendar::CalendarDay>>::sse_encode(self.holidays, serializer);
+        <Vec<crate::api::jwxt::calendar::CalendarDay>>::sse_encode(self.swap_days, serializer);
+    }
+}
+
 impl SseEncode for crate::api::jwxt::score::ScoreInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2979,7 +7528,7 @@ impl SseEncode for crate::api::jwxt::score::ScoreInfo {
         <String>::sse_encode(self.term, serializer);
         <String>::sse_encode(self.course_id, serializer);
         <String>::sse_encode(self.course_name, serializer);
-        <f32>::sse_encode(self.score, serializer);
+        <crate::api::jwxt::grade::Grade>::sse_encode(self.score, serializer);
         <f32>::sse_encode(self.credit, serializer);
         <f32>::sse_encode(self.hours, serializer);
         <f32>::sse_encode(self.gpa, serializer);
@@ -2994,6 +7543,7 @@ impl SseEncode for crate::api::jwxt::score::ScoreSummary {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <f32>::sse_encode(self.credit_total, serializer);
         <f32>::sse_encode(self.gpa_average, serializer);
+        <Option<f32>>::sse_encode(self.average_score, serializer);
     }
 }
 
@@ -3005,26 +7555,188 @@ impl SseEncode for crate::api::jwxt::score::ScoreTotal {
     }
 }
 
+impl SseEncode for crate::api::seat_watcher::SeatWatchConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::jwxt::selection::SelectionCategory>::sse_encode(self.category, serializer);
+        <Vec<String>>::sse_encode(self.course_ids, serializer);
+        <u32>::sse_encode(self.interval_ms, serializer);
+        <u32>::sse_encode(self.jitter_ms, serializer);
+        <Option<u32>>::sse_encode(self.max_rounds, serializer);
+    }
+}
+
+impl SseEncode for crate::api::seat_watcher::SeatWatchEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::seat_watcher::SeatWatchEvent::Polled { round, courses } => {
+                <i32>::sse_encode(0, serializer);
+                <u32>::sse_encode(round, serializer);
+                <Vec<crate::api::jwxt::selection::SelectableCourse>>::sse_encode(
+                    courses, serializer,
+                );
+            }
+            crate::api::seat_watcher::SeatWatchEvent::Attempted { course, outcome } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::jwxt::selection::SelectableCourse>::sse_encode(course, serializer);
+                <crate::api::jwxt::selection::SelectionOutcome>::sse_encode(outcome, serializer);
+            }
+            crate::api::seat_watcher::SeatWatchEvent::Error { message } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::seat_watcher::SeatWatchEvent::Finished { selected, pending } => {
+                <i32>::sse_encode(3, serializer);
+                <Vec<String>>::sse_encode(selected, serializer);
+                <Vec<String>>::sse_encode(pending, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::ics::SectionTime {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.section, serializer);
+        <String>::sse_encode(self.start, serializer);
+        <String>::sse_encode(self.end, serializer);
+    }
+}
+
+impl SseEncode for crate::api::jwxt::selection::SelectableCourse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <crate::api::jwxt::selection::SelectionCategory>::sse_encode(self.category, serializer);
+        <String>::sse_encode(self.course_code, serializer);
+        <String>::sse_encode(self.course_name, serializer);
+        <f32>::sse_encode(self.credits, serializer);
+        <String>::sse_encode(self.teacher, serializer);
+        <String>::sse_encode(self.time, serializer);
+        <String>::sse_encode(self.location, serializer);
+        <String>::sse_encode(self.campus, serializer);
+        <u32>::sse_encode(self.capacity, serializer);
+        <u32>::sse_encode(self.selected, serializer);
+        <u32>::sse_encode(self.remaining, serializer);
+        <String>::sse_encode(self.conflict, serializer);
+    }
+}
+
+impl SseEncode for crate::api::jwxt::selection::SelectionCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::jwxt::selection::SelectionCategory::PublicElective => 0,
+                crate::api::jwxt::selection::SelectionCategory::MajorElective => 1,
+                crate::api::jwxt::selection::SelectionCategory::CrossMajor => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::jwxt::selection::SelectionOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::jwxt::selection::SelectionResult>::sse_encode(self.result, serializer);
+        <String>::sse_encode(self.message, serializer);
+    }
+}
+
+impl SseEncode for crate::api::jwxt::selection::SelectionResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::jwxt::selection::SelectionResult::Success => 0,
+                crate::api::jwxt::selection::SelectionResult::TimeConflict => 1,
+                crate::api::jwxt::selection::SelectionResult::Full => 2,
+                crate::api::jwxt::selection::SelectionResult::OutsideWindow => 3,
+                crate::api::jwxt::selection::SelectionResult::Failed => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::jwxt::selection::SelectionRound {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.semester, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.start_time, serializer);
+        <String>::sse_encode(self.end_time, serializer);
+        <bool>::sse_encode(self.can_enter, serializer);
+    }
+}
+
+impl SseEncode for crate::api::jwxt::gpa::SemesterGpa {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.term, serializer);
+        <crate::api::jwxt::gpa::GpaResult>::sse_encode(self.result, serializer);
+    }
+}
+
 impl SseEncode for crate::api::jwxt::semester::SemesterInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.key, serializer);
         <String>::sse_encode(self.value, serializer);
+        <String>::sse_encode(self.academic_year, serializer);
+        <u8>::sse_encode(self.term, serializer);
+        <bool>::sse_encode(self.is_current, serializer);
+    }
+}
+
+impl SseEncode for crate::api::jwxt::semester::SemesterStart {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.semester, serializer);
+        <String>::sse_encode(self.start_date, serializer);
     }
 }
 
 impl SseEncode for crate::api::jwxt::info::StudentInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.name, serializer);
-        <String>::sse_encode(self.gender, serializer);
-        <String>::sse_encode(self.student_id, serializer);
-        <String>::sse_encode(self.department, serializer);
-        <String>::sse_encode(self.major, serializer);
-        <String>::sse_encode(self.class_name, serializer);
-        <String>::sse_encode(self.admission_date, serializer);
-        <String>::sse_encode(self.admission_number, serializer);
-        <String>::sse_encode(self.id_number, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.gender, serializer);
+        <Option<String>>::sse_encode(self.student_id, serializer);
+        <Option<String>>::sse_encode(self.department, serializer);
+        <Option<String>>::sse_encode(self.major, serializer);
+        <Option<String>>::sse_encode(self.class_name, serializer);
+        <Option<String>>::sse_encode(self.admission_date, serializer);
+        <Option<String>>::sse_encode(self.admission_number, serializer);
+        <Option<String>>::sse_encode(self.id_number, serializer);
+        <Option<String>>::sse_encode(self.birth_date, serializer);
+        <Option<String>>::sse_encode(self.ethnicity, serializer);
+        <Option<String>>::sse_encode(self.political_status, serializer);
+        <Option<String>>::sse_encode(self.hometown, serializer);
+        <Option<String>>::sse_encode(self.education_level, serializer);
+        <Option<String>>::sse_encode(self.length_of_schooling, serializer);
+        <Option<String>>::sse_encode(self.contact, serializer);
+        <Option<String>>::sse_encode(self.high_school, serializer);
+    }
+}
+
+impl SseEncode for crate::api::jwxt::info::StudentPhoto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.bytes, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
     }
 }
 
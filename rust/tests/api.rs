@@ -0,0 +1,616 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{MockServer, CAPTCHA, OPEN_ROUND, PASSWORD, PHOTO_IMAGE, USERNAME};
+use rust_lib_hbfu_alex::api::{
+    error::ApiError,
+    ics::beijing_now,
+    jwxt::{
+        classroom::FreeClassroom,
+        gpa::{GpaFilter, GpaScheme},
+        selection::{SelectionCategory, SelectionResult},
+        semester::{teaching_week, SemesterStart},
+    },
+    seat_watcher::{watch_seats, SeatWatchConfig, SeatWatchEvent},
+    session::{Endpoints, HttpSession, LoginState},
+    simple::{
+        api_classroom_filters, api_course, api_course_for_week, api_current_week, api_degree_audit,
+        api_dekt, api_dekt_detail, api_drop_course, api_elective, api_enter_selection, api_exam,
+        api_exam_countdown, api_export_course_ics, api_export_exam_ics, api_free_classrooms,
+        api_get_captcha, api_gpa, api_graduation_check, api_login, api_login_state, api_logout,
+        api_plan, api_restore_session, api_save_session, api_school_calendar, api_score,
+        api_select_course, api_selection_courses, api_selection_rounds, api_semester,
+        api_session_alive, api_set_credentials, api_set_endpoints, api_set_semester_start,
+        api_student_info, api_student_photo, init_app,
+    },
+};
+use tokio::sync::{Mutex, MutexGuard};
+
+/// `api_*` 共用全局会话，同一进程内的测试需要逐个执行
+static SERIAL: Mutex<()> = Mutex::const_new(());
+
+async fn start() -> (MutexGuard<'static, ()>, MockServer) {
+    let guard = SERIAL.lock().await;
+    let server = MockServer::start(Duration::ZERO).await;
+    init_app();
+    api_logout().await.unwrap();
+    api_set_endpoints(server.base_url.clone(), server.base_url.clone()).unwrap();
+    (guard, server)
+}
+
+async fn login() {
+    api_get_captcha().await.unwrap();
+    api_login(
+        USERNAME.to_string(),
+        PASSWORD.to_string(),
+        PASSWORD.to_string(),
+        CAPTCHA.to_string(),
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn apis_require_login() {
+    let (_guard, _server) = start().await;
+    assert_eq!(api_login_state().await, LoginState::NoSession);
+    assert!(matches!(
+        api_student_info().await,
+        Err(ApiError::NotLoggedIn)
+    ));
+    assert!(matches!(api_plan().await, Err(ApiError::NotLoggedIn)));
+    assert!(!api_session_alive().await.unwrap());
+
+    api_get_captcha().await.unwrap();
+    assert_eq!(api_login_state().await, LoginState::CaptchaFetched);
+    assert!(matches!(api_dekt().await, Err(ApiError::NotLoggedIn)));
+}
+
+#[tokio::test]
+async fn every_api_parses_recorded_pages() {
+    let (_guard, _server) = start().await;
+    login().await;
+    assert_eq!(api_login_state().await, LoginState::JwxtAuthenticated);
+
+    let info = api_student_info().await.unwrap();
+    assert_eq!(info.name.as_deref(), Some("张三"));
+    assert_eq!(info.student_id.as_deref(), Some("20230001"));
+    assert_eq!(info.admission_date.as_deref(), Some("2023-09-01"));
+
+    let photo = api_student_photo().await.unwrap();
+    assert_eq!(photo.mime_type, "image/jpeg");
+    assert_eq!(photo.bytes, PHOTO_IMAGE);
+
+    let semesters = api_semester(true).await.unwrap();
+    assert_eq!(semesters[0].key, "全部学期");
+    assert_eq!(semesters[1].value, "2025-2026-1");
+    assert_eq!(semesters.len(), 6);
+    let current: Vec<_> = semesters.iter().filter(|s| s.is_current).collect();
+    assert_eq!(current.len(), 1);
+    assert_eq!(current[0].value, "2024-2025-2");
+
+    let score = api_score(String::new()).await.unwrap();
+    assert_eq!(score.info.len(), 6);
+    assert_eq!(score.summary.gpa_average, 2.93);
+
+    let gpa = api_gpa(String::new(), GpaScheme::Standard4, GpaFilter::default())
+        .await
+        .unwrap();
+    assert_eq!(gpa.semesters.len(), 2);
+
+    let exams = api_exam("2023-2024-1".to_string()).await.unwrap();
+    assert_eq!(exams.len(), 3);
+    let exam_ics = api_export_exam_ics("2023-2024-1".to_string(), vec![30])
+        .await
+        .unwrap();
+    assert_eq!(exam_ics.matches("TRIGGER:-PT30M").count(), 3);
+    let countdown = api_exam_countdown("2023-2024-1".to_string()).await.unwrap();
+    assert_eq!(countdown.len(), 3);
+
+    let courses = api_course("2023-2024-1".to_string()).await.unwrap();
+    assert!(!courses.is_empty());
+    let week_two = api_course_for_week("2023-2024-1".to_string(), 2)
+        .await
+        .unwrap();
+    assert!(week_two.iter().all(|course| course.week_list.contains(&2)));
+    assert!(week_two.len() < courses.len());
+    let ics = api_export_course_ics("2023-2024-1".to_string(), "2023-09-04".to_string(), vec![])
+        .await
+        .unwrap();
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.contains("DTSTART;TZID=Asia/Shanghai:20230904T080000"));
+    // 校历中放假的日期不生成日程
+    let ics = api_export_course_ics("2024-2025-2".to_string(), "2025-02-24".to_string(), vec![])
+        .await
+        .unwrap();
+    assert!(ics.contains("DTSTART;TZID=Asia/Shanghai:20250407T"));
+    assert!(!ics.contains("DTSTART;TZID=Asia/Shanghai:20250404T"));
+    assert!(!ics.contains("DTSTART;TZID=Asia/Shanghai:20250505T"));
+    assert!(matches!(
+        api_export_course_ics("2023-2024-1".to_string(), "9/4".to_string(), vec![]).await,
+        Err(ApiError::InvalidArgument { .. })
+    ));
+
+    let calendar = api_school_calendar("2024-2025-2".to_string())
+        .await
+        .unwrap();
+    assert_eq!(calendar.start_date, "2025-02-24");
+    assert_eq!(calendar.weeks.len(), 20);
+
+    let filters = api_classroom_filters().await.unwrap();
+    assert_eq!(filters.buildings.len(), 3);
+    let free_rooms = |campus: &str, building: &str| {
+        api_free_classrooms(
+            "2024-2025-2".to_string(),
+            5,
+            2,
+            vec![3, 4],
+            campus.to_string(),
+            building.to_string(),
+        )
+    };
+    let names = |rooms: Vec<FreeClassroom>| -> Vec<String> {
+        rooms.into_iter().map(|room| room.name).collect()
+    };
+    assert_eq!(
+        names(free_rooms("", "").await.unwrap()),
+        ["A101", "A201", "B301", "E105"]
+    );
+    assert_eq!(
+        names(free_rooms("01", "").await.unwrap()),
+        ["A101", "A201", "B301"]
+    );
+    assert_eq!(names(free_rooms("02", "").await.unwrap()), ["E105"]);
+    assert_eq!(
+        names(free_rooms("01", "0101").await.unwrap()),
+        ["A101", "A201"]
+    );
+    assert!(matches!(
+        api_free_classrooms(
+            "2024-2025-2".to_string(),
+            5,
+            8,
+            vec![3, 4],
+            String::new(),
+            String::new()
+        )
+        .await,
+        Err(ApiError::InvalidArgument { .. })
+    ));
+    assert!(matches!(
+        api_free_classrooms(
+            "2024-2025-2".to_string(),
+            5,
+            2,
+            vec![],
+            String::new(),
+            String::new()
+        )
+        .await,
+        Err(ApiError::InvalidArgument { .. })
+    ));
+
+    let elective = api_elective("2024-2025-2".to_string()).await.unwrap();
+    assert_eq!(elective.credits.len(), 3);
+    assert_eq!(elective.courses.len(), 3);
+
+    let plan = api_plan().await.unwrap();
+    assert_eq!(plan.plans.len(), 15);
+    let audit = api_degree_audit("2023-2024-2".to_string()).await.unwrap();
+    assert_eq!(audit.courses.len(), 15);
+    assert_eq!(audit.earned, 15.5);
+
+    let graduation = api_graduation_check().await.unwrap();
+    assert_eq!(graduation.buckets.len(), 8);
+    assert!(!graduation.passed);
+
+    let dekt = api_dekt().await.unwrap();
+    assert_eq!(dekt.list.len(), 3);
+    assert_eq!(dekt.list[1].operation_id, "D4E5F6");
+
+    let detail = api_dekt_detail(dekt.list[1].operation_id.clone())
+        .await
+        .unwrap();
+    assert_eq!(detail.0["活动名称"], "程序设计竞赛校赛");
+}
+
+#[tokio::test]
+async fn current_week_from_calendar_or_override() {
+    let (_guard, _server) = start().await;
+    api_set_semester_start(None).unwrap();
+    assert!(matches!(
+        api_current_week().await,
+        Err(ApiError::NotLoggedIn)
+    ));
+    login().await;
+
+    let today = beijing_now().date();
+    let week = api_current_week().await.unwrap();
+    assert_eq!(week.semester, "2024-2025-2");
+    assert_eq!(week.start_date, "2025-02-24");
+    assert!(!week.is_overridden);
+    let start = chrono::NaiveDate::from_ymd_opt(2025, 2, 24).unwrap();
+    assert_eq!(week.week, teaching_week(start, today));
+
+    let start_date = (today - chrono::Duration::days(14))
+        .format("%Y-%m-%d")
+        .to_string();
+    api_set_semester_start(Some(SemesterStart {
+        semester: "2025-2026-1".to_string(),
+        start_date,
+    }))
+    .unwrap();
+    let week = api_current_week().await.unwrap();
+    assert_eq!((week.semester.as_str(), week.week), ("2025-2026-1", 3));
+    assert!(week.is_overridden);
+
+    assert!(matches!(
+        api_set_semester_start(Some(SemesterStart {
+            semester: "2025-2026-1".to_string(),
+            start_date: "9月1日".to_string(),
+        })),
+        Err(ApiError::InvalidArgument { .. })
+    ));
+    api_set_semester_start(None).unwrap();
+}
+
+#[tokio::test]
+async fn course_selection_workflow() {
+    let (_guard, _server) = start().await;
+    login().await;
+    let category = SelectionCategory::PublicElective;
+
+    // 进入选课轮次之前不能选课
+    let outcome = api_select_course(category, "202420252004711".to_string())
+        .await
+        .unwrap();
+    assert_eq!(outcome.result, SelectionResult::OutsideWindow);
+
+    let rounds = api_selection_rounds().await.unwrap();
+    assert_eq!(rounds[0].id, OPEN_ROUND);
+    let closed = api_enter_selection("0000".to_string()).await.unwrap();
+    assert_eq!(closed.result, SelectionResult::OutsideWindow);
+    let entered = api_enter_selection(rounds[0].id.clone()).await.unwrap();
+    assert_eq!(entered.result, SelectionResult::Success);
+
+    let courses = api_selection_courses(category, String::new())
+        .await
+        .unwrap();
+    assert_eq!(courses.len(), 3);
+    assert_eq!(courses[0].remaining, 25);
+
+    let select = |id: &str| api_select_course(category, id.to_string());
+    assert_eq!(
+        select("202420252004711").await.unwrap().result,
+        SelectionResult::Success
+    );
+    assert_eq!(
+        select("202420252004712").await.unwrap().result,
+        SelectionResult::Full
+    );
+    assert_eq!(
+        select("202420252004713").await.unwrap().result,
+        SelectionResult::TimeConflict
+    );
+    let courses = api_selection_courses(category, String::new())
+        .await
+        .unwrap();
+    assert_eq!(courses[0].remaining, 24);
+
+    let dropped = api_drop_course("202420252004711".to_string())
+        .await
+        .unwrap();
+    assert_eq!(dropped.result, SelectionResult::Success);
+    let dropped = api_drop_course("202420252004711".to_string())
+        .await
+        .unwrap();
+    assert_eq!(dropped.result, SelectionResult::Failed);
+}
+
+#[tokio::test]
+async fn selection_ids_are_url_encoded() {
+    let (_guard, _server) = start().await;
+    login().await;
+    let category = SelectionCategory::PublicElective;
+
+    // 编号中的 & 和 = 不能拼出额外的参数
+    let round = format!("0000&jx0502zbid={}", OPEN_ROUND);
+    let closed = api_enter_selection(round).await.unwrap();
+    assert_eq!(closed.result, SelectionResult::OutsideWindow);
+    api_enter_selection(OPEN_ROUND.to_string()).await.unwrap();
+
+    let injected = "0&jx0404id=202420252004711".to_string();
+    let outcome = api_select_course(category, injected.clone()).await.unwrap();
+    assert_eq!(outcome.result, SelectionResult::Failed);
+    let courses = api_selection_courses(category, String::new())
+        .await
+        .unwrap();
+    assert_eq!(courses[0].remaining, 25);
+
+    api_select_course(category, "202420252004711".to_string())
+        .await
+        .unwrap();
+    let dropped = api_drop_course(injected).await.unwrap();
+    assert_eq!(dropped.result, SelectionResult::Failed);
+}
+
+#[tokio::test]
+async fn selection_in_every_category() {
+    let (_guard, _server) = start().await;
+    login().await;
+    api_enter_selection(OPEN_ROUND.to_string()).await.unwrap();
+
+    // 每个类别使用各自的课程列表与选课地址，只能选本类别的教学班
+    for (category, first_id, count) in [
+        (SelectionCategory::PublicElective, "202420252004711", 3),
+        (SelectionCategory::MajorElective, "202420252003105", 2),
+        (SelectionCategory::CrossMajor, "202420252006208", 1),
+    ] {
+        let courses = api_selection_courses(category, String::new())
+            .await
+            .unwrap();
+        assert_eq!(courses.len(), count, "{:?}", category);
+        assert_eq!(courses[0].id, first_id);
+        assert!(courses.iter().all(|course| course.category == category));
+        let remaining = courses[0].remaining;
+
+        let outcome = api_select_course(category, first_id.to_string())
+            .await
+            .unwrap();
+        assert_eq!(outcome.result, SelectionResult::Success, "{:?}", category);
+        let courses = api_selection_courses(category, String::new())
+            .await
+            .unwrap();
+        assert_eq!(courses[0].remaining, remaining - 1);
+    }
+
+    let outcome = api_select_course(SelectionCategory::CrossMajor, "202420252003106".to_string())
+        .await
+        .unwrap();
+    assert_eq!(outcome.result, SelectionResult::Failed);
+
+    let full = api_select_course(
+        SelectionCategory::MajorElective,
+        "202420252003106".to_string(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(full.result, SelectionResult::Full);
+}
+
+fn watch_config(course_ids: &[&str], max_rounds: u32) -> SeatWatchConfig {
+    SeatWatchConfig {
+        category: SelectionCategory::PublicElective,
+        course_ids: course_ids.iter().map(|id| id.to_string()).collect(),
+        interval_ms: 10,
+        jitter_ms: 5,
+        max_rounds: Some(max_rounds),
+    }
+}
+
+/// 抢课使用传入的会话，与全局会话无关
+async fn watcher_session(server: &MockServer) -> HttpSession {
+    let session = HttpSession::with_endpoints(Endpoints {
+        cas: server.base_url.clone(),
+        jwxt: server.base_url.clone(),
+    });
+    session.get_captcha().await.unwrap();
+    session
+        .complete_login(USERNAME, PASSWORD, PASSWORD, CAPTCHA)
+        .await
+        .unwrap();
+    session
+}
+
+#[tokio::test]
+async fn seat_watcher_selects_when_a_seat_frees_up() {
+    let (_guard, server) = start().await;
+    login().await;
+    api_enter_selection(OPEN_ROUND.to_string()).await.unwrap();
+    let session = watcher_session(&server).await;
+
+    // 已满、时间冲突、不存在的教学班各一个，第 2 轮查询后有人退课
+    let full = "202420252004712";
+    let config = watch_config(&[full, "202420252004713", "404", full], 10);
+    let mut events = Vec::new();
+    watch_seats(&session, &config, |event| {
+        if let SeatWatchEvent::Polled { round: 2, .. } = event {
+            server.set_seats(full, 1);
+        }
+        events.push(event);
+        true
+    })
+    .await;
+
+    let polled: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            SeatWatchEvent::Polled { round, courses } => Some((*round, courses.len())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(polled, [(1, 2), (2, 1), (3, 1)]);
+    let attempts: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            SeatWatchEvent::Attempted { course, outcome } => {
+                Some((course.id.as_str(), outcome.result))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        attempts,
+        [
+            ("202420252004713", SelectionResult::TimeConflict),
+            (full, SelectionResult::Success),
+        ]
+    );
+    assert!(matches!(
+        &events[0],
+        SeatWatchEvent::Error { message } if message.contains("404")
+    ));
+    match events.last().unwrap() {
+        SeatWatchEvent::Finished { selected, pending } => {
+            assert_eq!(selected, &[full.to_string()]);
+            assert!(pending.is_empty());
+        }
+        event => panic!("unexpected last event: {:?}", event),
+    }
+}
+
+#[tokio::test]
+async fn seat_watcher_stops_after_max_rounds_or_when_cancelled() {
+    let (_guard, server) = start().await;
+    login().await;
+    api_enter_selection(OPEN_ROUND.to_string()).await.unwrap();
+    let session = watcher_session(&server).await;
+    let full = "202420252004712";
+
+    let mut events = Vec::new();
+    watch_seats(&session, &watch_config(&[full], 2), |event| {
+        events.push(event);
+        true
+    })
+    .await;
+    assert_eq!(events.len(), 3);
+    match &events[2] {
+        SeatWatchEvent::Finished { selected, pending } => {
+            assert!(selected.is_empty());
+            assert_eq!(pending, &[full.to_string()]);
+        }
+        event => panic!("unexpected last event: {:?}", event),
+    }
+
+    // emit 返回 false 时立即停止，不再发送 Finished
+    let mut count = 0;
+    watch_seats(&session, &watch_config(&[full], 10), |_| {
+        count += 1;
+        false
+    })
+    .await;
+    assert_eq!(count, 1);
+}
+
+#[tokio::test]
+async fn seat_watcher_stops_when_selection_is_closed() {
+    let (_guard, server) = start().await;
+    let session = watcher_session(&server).await;
+    let full = "202420252004712";
+
+    // 没有进入选课轮次时课程列表是提示页面，不再重试
+    let mut events = Vec::new();
+    watch_seats(&session, &watch_config(&[full], 10), |event| {
+        events.push(event);
+        true
+    })
+    .await;
+    assert_eq!(events.len(), 2);
+    assert!(matches!(&events[0], SeatWatchEvent::Error { .. }));
+    match &events[1] {
+        SeatWatchEvent::Finished { selected, pending } => {
+            assert!(selected.is_empty());
+            assert_eq!(pending, &[full.to_string()]);
+        }
+        event => panic!("unexpected last event: {:?}", event),
+    }
+}
+
+#[tokio::test]
+async fn expired_jwxt_session_relogs_in_transparently() {
+    let (_guard, server) = start().await;
+    login().await;
+
+    server.expire_jwxt();
+    assert!(!api_session_alive().await.unwrap());
+
+    let score = api_score(String::new()).await.unwrap();
+    assert_eq!(score.info.len(), 6);
+    assert_eq!(api_login_state().await, LoginState::JwxtAuthenticated);
+}
+
+#[tokio::test]
+async fn expired_vpn_relogs_in_when_captcha_is_not_required() {
+    let (_guard, server) = start().await;
+    login().await;
+
+    server.set_captcha_required(false);
+    server.expire_vpn();
+
+    let exams = api_exam(String::new()).await.unwrap();
+    assert_eq!(exams.len(), 3);
+    assert_eq!(api_login_state().await, LoginState::JwxtAuthenticated);
+}
+
+#[tokio::test]
+async fn expired_vpn_requiring_captcha_reports_session_expired() {
+    let (_guard, server) = start().await;
+    login().await;
+
+    server.expire_vpn();
+
+    let err = api_exam(String::new()).await.unwrap_err();
+    assert!(matches!(err, ApiError::SessionExpired), "{:?}", err);
+    assert_eq!(api_login_state().await, LoginState::Expired);
+}
+
+#[tokio::test]
+async fn network_error_during_relogin_is_not_session_expired() {
+    let (_guard, server) = start().await;
+    login().await;
+    let path = std::env::temp_dir().join(format!("hbfu-relogin-{}.json", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    api_save_session(path.clone()).await.unwrap();
+
+    // 恢复的会话使用连不上的 CAS 地址，VPN 失效后重新登录时出现网络错误
+    let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let cas = format!("http://{}", closed.local_addr().unwrap());
+    drop(closed);
+    api_set_endpoints(cas, server.base_url.clone()).unwrap();
+    assert!(api_restore_session(path.clone()).await.unwrap());
+    api_set_credentials(
+        USERNAME.to_string(),
+        PASSWORD.to_string(),
+        PASSWORD.to_string(),
+    )
+    .await
+    .unwrap();
+    server.set_captcha_required(false);
+    server.expire_vpn();
+
+    let err = api_exam(String::new()).await.unwrap_err();
+    assert!(matches!(err, ApiError::Network { .. }), "{:?}", err);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn saved_session_is_restored() {
+    let (_guard, server) = start().await;
+    login().await;
+
+    let path = std::env::temp_dir().join(format!("hbfu-cookies-{}.json", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    api_save_session(path.clone()).await.unwrap();
+
+    api_logout().await.unwrap();
+    assert!(api_restore_session(path.clone()).await.unwrap());
+    assert_eq!(api_login_state().await, LoginState::JwxtAuthenticated);
+    assert_eq!(api_plan().await.unwrap().plans.len(), 15);
+
+    server.expire_jwxt();
+    assert!(!api_restore_session(path.clone()).await.unwrap());
+    assert_eq!(api_login_state().await, LoginState::Expired);
+
+    api_set_credentials(
+        USERNAME.to_string(),
+        PASSWORD.to_string(),
+        PASSWORD.to_string(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(api_plan().await.unwrap().plans.len(), 15);
+
+    std::fs::remove_file(path).unwrap();
+}
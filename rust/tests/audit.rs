@@ -0,0 +1,89 @@
+use rust_lib_hbfu_alex::api::jwxt::{
+    audit::{degree_audit, CourseStatus, DegreeAudit},
+    grade::GradeScale,
+    plan::parse_plan,
+    score::parse_score,
+};
+
+fn audit(scores: &str, current_semester: &str) -> DegreeAudit {
+    let plan = parse_plan(include_str!("fixtures/pyfa_query.html")).unwrap();
+    let scores = parse_score(scores, &GradeScale::default()).unwrap();
+    degree_audit(&plan.plans, &scores, current_semester)
+}
+
+fn status(audit: &DegreeAudit, code: &str) -> CourseStatus {
+    audit
+        .courses
+        .iter()
+        .find(|course| course.course_code == code)
+        .unwrap()
+        .status
+}
+
+#[test]
+fn course_status_by_code() {
+    let audit = audit(include_str!("fixtures/cjcx_list.html"), "2023-2024-2");
+    assert_eq!(audit.courses.len(), 15);
+
+    assert_eq!(status(&audit, "B0110001"), CourseStatus::Passed);
+    assert_eq!(status(&audit, "B0150002"), CourseStatus::Passed);
+    assert_eq!(status(&audit, "B0310021"), CourseStatus::Failed);
+    assert_eq!(status(&audit, "B0110002"), CourseStatus::InProgress);
+    assert_eq!(status(&audit, "B0390011"), CourseStatus::NotTaken);
+    assert!(audit.outside_plan.is_empty());
+}
+
+#[test]
+fn credits_per_type_and_semester() {
+    let audit = audit(include_str!("fixtures/cjcx_list.html"), "");
+
+    // 5 + 3 + 3 + 1 + 3.5
+    assert_eq!(audit.earned, 15.5);
+    assert_eq!(audit.required, 52.5);
+
+    let required = audit
+        .by_course_type
+        .iter()
+        .find(|group| group.key == "必修")
+        .unwrap();
+    assert_eq!(required.earned, 15.5);
+    assert_eq!(required.course_count, 14);
+    let elective = audit
+        .by_course_type
+        .iter()
+        .find(|group| group.key == "选修")
+        .unwrap();
+    assert_eq!((elective.earned, elective.required), (0.0, 2.0));
+
+    let semesters: Vec<_> = audit.by_semester.iter().map(|s| s.key.as_str()).collect();
+    assert_eq!(semesters.len(), 8);
+    assert_eq!(semesters[0], "2023-2024-1");
+    let first = &audit.by_semester[0];
+    assert_eq!((first.earned, first.required), (11.0, 13.0));
+    assert_eq!((first.passed_count, first.course_count), (3, 4));
+}
+
+#[test]
+fn retakes_and_courses_outside_plan() {
+    let audit = audit(include_str!("fixtures/cjcx_list_special_grades.html"), "");
+
+    // 缺考后补考通过、重修通过、免修均视为通过
+    assert_eq!(status(&audit, "B0110001"), CourseStatus::Passed);
+    assert_eq!(status(&audit, "B0310021"), CourseStatus::Passed);
+    assert_eq!(status(&audit, "B0120003"), CourseStatus::Passed);
+    let retake = audit
+        .courses
+        .iter()
+        .find(|course| course.course_code == "B0310021")
+        .unwrap();
+    assert_eq!(retake.attempts, 2);
+    assert_eq!(retake.best_score.as_ref().unwrap().raw, "75(重修)");
+
+    let outside: Vec<_> = audit
+        .outside_plan
+        .iter()
+        .map(|score| score.course_id.as_str())
+        .collect();
+    assert_eq!(outside, ["B0170002", "B0180001"]);
+    assert!(audit.outside_plan_earned > 0.0);
+}
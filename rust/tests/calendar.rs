@@ -0,0 +1,84 @@
+use chrono::NaiveDate;
+use rust_lib_hbfu_alex::api::jwxt::calendar::{parse_school_calendar, CalendarDayKind};
+
+#[test]
+fn weeks_with_holidays_and_swap_days() {
+    let calendar = parse_school_calendar(include_str!("fixtures/jxzl_query.html")).unwrap();
+    assert_eq!(calendar.semester, "2024-2025-2");
+    assert_eq!(calendar.start_date, "2025-02-24");
+    assert_eq!(calendar.weeks.len(), 20);
+    assert_eq!(calendar.weeks[1].monday, "2025-03-03");
+    assert_eq!(calendar.weeks[19].week, 20);
+
+    let week6 = &calendar.weeks[5];
+    assert_eq!(week6.monday, "2025-03-31");
+    assert_eq!(week6.holidays.len(), 1);
+    assert_eq!(week6.holidays[0].date, "2025-04-04");
+    assert_eq!(week6.holidays[0].weekday, 5);
+    assert_eq!(week6.holidays[0].note, "清明节");
+
+    // 劳动节前的周日调休上课
+    let week9 = &calendar.weeks[8];
+    assert!(week9.holidays.is_empty());
+    assert_eq!(week9.swap_days.len(), 1);
+    assert_eq!(week9.swap_days[0].date, "2025-04-27");
+    assert_eq!(week9.swap_days[0].kind, CalendarDayKind::Workday);
+
+    // "劳动节放假调休" 含有 "调休"，但是放假
+    let week11 = &calendar.weeks[10];
+    assert!(week11.swap_days.is_empty());
+    assert_eq!(week11.holidays[0].date, "2025-05-05");
+    assert_eq!(week11.holidays[0].note, "劳动节放假调休");
+    assert_eq!(week11.holidays[0].kind, CalendarDayKind::Holiday);
+
+    let holidays = calendar.holiday_dates();
+    assert_eq!(holidays.len(), 6);
+    assert!(holidays.contains(&NaiveDate::from_ymd_opt(2025, 5, 5).unwrap()));
+    assert!(!holidays.contains(&NaiveDate::from_ymd_opt(2025, 4, 27).unwrap()));
+}
+
+#[test]
+fn notes_from_cell_text_and_missing_week_numbers() {
+    // title 中只有日期时取单元格文字作为标注；没有周次列时按行号编号
+    let html = include_str!("fixtures/jxzl_query.html")
+        .replace(
+            "title=\"2025年04月04日 清明节\"",
+            "title=\"2025年04月04日\"",
+        )
+        .replace("<th>周次</th>", "")
+        .replace("<tr><td>6</td>", "<tr>");
+    let calendar = parse_school_calendar(&html).unwrap();
+    let week6 = &calendar.weeks[5];
+    assert_eq!(week6.week, 6);
+    assert_eq!(week6.holidays[0].note, "清明节");
+}
+
+#[test]
+fn swap_day_notes_with_holiday_names() {
+    let html = include_str!("fixtures/jxzl_query.html").replace("调休上课", "劳动节调休上课");
+    let calendar = parse_school_calendar(&html).unwrap();
+    let week9 = &calendar.weeks[8];
+    assert!(week9.holidays.is_empty());
+    assert_eq!(week9.swap_days[0].note, "劳动节调休上课");
+
+    // 只有 "调休" 不能判断是否上课，不标记
+    let html = include_str!("fixtures/jxzl_query.html").replace("调休上课", "调休");
+    let calendar = parse_school_calendar(&html).unwrap();
+    assert!(calendar.weeks[8].holidays.is_empty());
+    assert!(calendar.weeks[8].swap_days.is_empty());
+}
+
+#[test]
+fn notes_without_holiday_keywords_keep_classes() {
+    let html = include_str!("fixtures/jxzl_query.html")
+        .replace("2025年04月04日 清明节", "2025年04月04日 运动会")
+        .replace("2025年05月31日 端午节", "2025年05月31日 开学典礼");
+    let calendar = parse_school_calendar(&html).unwrap();
+    assert!(calendar.weeks[5].holidays.is_empty());
+    assert!(calendar.weeks[5].swap_days.is_empty());
+
+    let holidays = calendar.holiday_dates();
+    assert_eq!(holidays.len(), 4);
+    assert!(!holidays.contains(&NaiveDate::from_ymd_opt(2025, 4, 4).unwrap()));
+    assert!(!holidays.contains(&NaiveDate::from_ymd_opt(2025, 5, 31).unwrap()));
+}
@@ -0,0 +1,59 @@
+use rust_lib_hbfu_alex::api::{
+    error::ApiError,
+    jwxt::classroom::{parse_classroom_filters, parse_free_classrooms},
+};
+
+fn names(sections: &[u32]) -> Vec<String> {
+    parse_free_classrooms(include_str!("fixtures/jsjy_query2.html"), sections)
+        .unwrap()
+        .into_iter()
+        .map(|room| room.name)
+        .collect()
+}
+
+#[test]
+fn rooms_free_in_every_requested_section() {
+    assert_eq!(names(&[1, 2]), ["A102", "A201", "B301", "B302", "E105"]);
+    assert_eq!(names(&[3, 4]), ["A101", "A201", "B301", "E105"]);
+    assert_eq!(names(&[4]), ["A101", "A201", "B301", "B302", "E105"]);
+    assert_eq!(names(&[1, 2, 3, 4, 5, 6]), ["A201", "E105"]);
+    assert_eq!(names(&[11, 12]).len(), 6);
+
+    let rooms = parse_free_classrooms(include_str!("fixtures/jsjy_query2.html"), &[3]).unwrap();
+    let a201 = rooms.iter().find(|room| room.name == "A201").unwrap();
+    assert_eq!(a201.capacity, 200);
+    assert_eq!(a201.room_type, "阶梯教室");
+    assert_eq!(a201.building, "公共教学楼A");
+    let e105 = rooms.iter().find(|room| room.name == "E105").unwrap();
+    assert_eq!(e105.campus, "东校区");
+}
+
+#[test]
+fn list_without_section_columns_is_already_filtered() {
+    // 去掉 12 个节次列，只保留前 6 列
+    let html = regex::Regex::new(r"<th[^>]*>第\d+节</th>")
+        .unwrap()
+        .replace_all(include_str!("fixtures/jsjy_query2.html"), "");
+    let html = regex::Regex::new(r"(<tr>(?:<td>[^<]*</td>){6})(?:<td>[^<]*</td>)+</tr>")
+        .unwrap()
+        .replace_all(&html, "$1</tr>");
+    let rooms = parse_free_classrooms(&html, &[3, 4]).unwrap();
+    assert_eq!(rooms.len(), 6);
+}
+
+#[test]
+fn section_missing_from_table_is_an_error() {
+    let html = include_str!("fixtures/jsjy_query2.html");
+    let err = parse_free_classrooms(html, &[12, 13]).unwrap_err();
+    assert!(matches!(err, ApiError::InvalidArgument { .. }), "{:?}", err);
+}
+
+#[test]
+fn campus_and_building_options() {
+    let filters = parse_classroom_filters(include_str!("fixtures/jsjy_query.html")).unwrap();
+    let campuses: Vec<_> = filters.campuses.iter().map(|o| o.key.as_str()).collect();
+    assert_eq!(campuses, ["本部校区", "东校区"]);
+    assert_eq!(filters.buildings[0].key, "公共教学楼A");
+    assert_eq!(filters.buildings[0].value, "0101");
+    assert_eq!(filters.buildings.len(), 3);
+}
@@ -0,0 +1,583 @@
+//! 本地模拟的 CAS 与 jsxsd 服务，测试时通过 `api_set_endpoints` 指向这里。
+//! 页面取自 tests/fixtures 下录制的真实页面，VPN 与教务系统会话分别用 Cookie 跟踪，
+//! 可以单独让其中一个过期，模拟重定向回登录页的情况
+#![allow(dead_code)]
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use axum::{
+    extract::{Form, Query, State},
+    http::{
+        header::{CONTENT_TYPE, COOKIE, SET_COOKIE},
+        HeaderMap,
+    },
+    response::{Html, IntoResponse, Redirect, Response},
+    routing::{get, post},
+    Router,
+};
+use rust_lib_hbfu_alex::api::{aescbc::aes_cbc_encrypt, conwork::encode_inp};
+use tokio::net::TcpListener;
+
+pub const USERNAME: &str = "20230001";
+pub const PASSWORD: &str = "secret";
+pub const CAPTCHA: &str = "1234";
+
+const VPN_COOKIE: &str = "wengine_vpn_ticket";
+const JWXT_COOKIE: &str = "JSESSIONID";
+
+const CAS_LOGIN: &str = include_str!("../fixtures/cas_login.html");
+const CAS_SUCCESS: &str = include_str!("../fixtures/cas_success.html");
+const JWXT_LOGIN: &str = include_str!("../fixtures/jwxt_login.html");
+const JWXT_MAIN: &str = include_str!("../fixtures/xsMain.html");
+pub const CAPTCHA_IMAGE: &[u8] = include_bytes!("../fixtures/captcha.jpg");
+/// 选课页面中可以进入的选课轮次
+pub const OPEN_ROUND: &str = "6E3F2A91C0D84B7A";
+/// 各类可选课程的列表地址、选课地址与课程列表，与 `SelectionCategory` 一一对应
+const SELECTION_LISTS: [(&str, &str, &str); 3] = [
+    (
+        "xsxkGgxxkxk",
+        "ggxxkxkOper",
+        include_str!("../fixtures/xsxkGgxxkxk.json"),
+    ),
+    (
+        "xsxkXxxk",
+        "xxxkOper",
+        include_str!("../fixtures/xsxkXxxk.json"),
+    ),
+    (
+        "xsxkFawxk",
+        "fawxkOper",
+        include_str!("../fixtures/xsxkFawxk.json"),
+    ),
+];
+/// 学籍卡片照片，内容与验证码图片相同，只用于检查下载结果
+pub const PHOTO_IMAGE: &[u8] = CAPTCHA_IMAGE;
+
+struct MockState {
+    /// 每个教务数据页面的响应延迟，用于观察请求是否串行
+    delay: Duration,
+    /// CAS 是否要求验证码，关闭后可以用空验证码登录
+    captcha_required: AtomicBool,
+    next_token: AtomicU64,
+    vpn_tickets: Mutex<HashSet<String>>,
+    jwxt_sessions: Mutex<HashSet<String>>,
+    /// 是否已进入选课轮次，进入前选课接口只返回提示页面
+    selection_entered: AtomicBool,
+    /// 各教学班的剩余名额，初始值取自 xsxk*.json
+    seats: Mutex<HashMap<String, u32>>,
+    /// 已选的教学班
+    chosen: Mutex<HashSet<String>>,
+}
+
+impl MockState {
+    fn issue(&self, sessions: &Mutex<HashSet<String>>) -> String {
+        let token = format!(
+            "{:016X}",
+            self.next_token.fetch_add(1, Ordering::SeqCst) + 1
+        );
+        sessions.lock().unwrap().insert(token.clone());
+        token
+    }
+
+    fn has_vpn(&self, headers: &HeaderMap) -> bool {
+        cookie(headers, VPN_COOKIE)
+            .is_some_and(|ticket| self.vpn_tickets.lock().unwrap().contains(&ticket))
+    }
+
+    fn has_jwxt(&self, headers: &HeaderMap) -> bool {
+        cookie(headers, JWXT_COOKIE)
+            .is_some_and(|session| self.jwxt_sessions.lock().unwrap().contains(&session))
+    }
+}
+
+pub struct MockServer {
+    pub base_url: String,
+    state: Arc<MockState>,
+}
+
+impl MockServer {
+    pub async fn start(delay: Duration) -> Self {
+        let state = Arc::new(MockState {
+            delay,
+            captcha_required: AtomicBool::new(true),
+            next_token: AtomicU64::new(0),
+            vpn_tickets: Mutex::new(HashSet::new()),
+            jwxt_sessions: Mutex::new(HashSet::new()),
+            selection_entered: AtomicBool::new(false),
+            seats: Mutex::new(initial_seats()),
+            chosen: Mutex::new(HashSet::new()),
+        });
+        let mut app = Router::new()
+            .route("/backstage/cas/login", get(cas_login_page).post(cas_login))
+            .route("/backstage/cas/captcha.jpg", get(captcha))
+            .route("/", get(jwxt_login_page))
+            .route("/jsxsd/", get(jwxt_login_page))
+            .route("/jsxsd/xk/LoginToXk", post(login_to_xk))
+            .route("/jsxsd/framework/xsMain.jsp", get(jwxt_main))
+            .route("/jsxsd/grxx/xsxx", get(student_info))
+            .route("/jsxsd/grxx/xszpLoad", get(student_photo))
+            .route("/jsxsd/xsks/xsksap_query", get(semester))
+            .route("/jsxsd/jxzl/jxzl_query", get(calendar).post(calendar))
+            .route("/jsxsd/kscj/cjcx_list", post(score))
+            .route("/jsxsd/xsks/xsksap_list", post(exam))
+            .route("/jsxsd/xskb/xskb_list.do", post(course))
+            .route("/jsxsd/xkgl/xqxkchList", post(elective))
+            .route("/jsxsd/pyfa/pyfa_query", get(plan))
+            .route("/jsxsd/pyfa/cxxf07List", get(dekt))
+            .route("/jsxsd/pyfa/cxxf07View", get(dekt_detail))
+            .route("/jsxsd/kbxx/jsjy_query", get(classroom_filters))
+            .route("/jsxsd/kbxx/jsjy_query2", post(free_classrooms))
+            .route("/jsxsd/xsxk/xklc_list", get(selection_rounds))
+            .route("/jsxsd/xsxk/xsxk_index", get(enter_selection))
+            .route("/jsxsd/xsxkjg/xstkOper", get(drop_course));
+        for (list, oper, courses) in SELECTION_LISTS {
+            app = app
+                .route(
+                    &format!("/jsxsd/xsxkkc/{}", list),
+                    post(
+                        move |State(state): State<Arc<MockState>>, headers: HeaderMap| {
+                            selection_courses(state, headers, courses)
+                        },
+                    ),
+                )
+                .route(
+                    &format!("/jsxsd/xsxkkc/{}", oper),
+                    get(
+                        move |State(state): State<Arc<MockState>>,
+                              headers: HeaderMap,
+                              Query(query): Query<HashMap<String, String>>| {
+                            select_course(state, headers, query, courses)
+                        },
+                    ),
+                );
+        }
+        let app = app.with_state(state.clone());
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        Self {
+            base_url: format!("http://{}", addr),
+            state,
+        }
+    }
+
+    /// 让所有教务系统会话失效，VPN 仍然有效
+    pub fn expire_jwxt(&self) {
+        self.state.jwxt_sessions.lock().unwrap().clear();
+    }
+
+    /// 让所有 VPN 票据失效，之后的请求都会被重定向到 CAS 登录页
+    pub fn expire_vpn(&self) {
+        self.state.vpn_tickets.lock().unwrap().clear();
+    }
+
+    /// 修改教学班的剩余名额，模拟其他同学退课
+    pub fn set_seats(&self, course_id: &str, seats: u32) {
+        self.state
+            .seats
+            .lock()
+            .unwrap()
+            .insert(course_id.to_string(), seats);
+    }
+
+    pub fn set_captcha_required(&self, required: bool) {
+        self.state
+            .captcha_required
+            .store(required, Ordering::SeqCst);
+    }
+}
+
+fn cookie(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.to_string())
+}
+
+fn set_cookie(name: &str, value: &str) -> [(axum::http::HeaderName, String); 1] {
+    [(SET_COOKIE, format!("{}={}; Path=/; HttpOnly", name, value))]
+}
+
+fn cas_redirect() -> Redirect {
+    Redirect::to("/backstage/cas/login?service=%2Fjsxsd%2F")
+}
+
+fn cas_error(message: &str) -> Html<String> {
+    Html(CAS_LOGIN.replace(
+        "<form id=\"fm1\"",
+        &format!(
+            "<div id=\"msg\" class=\"errors\">{}</div>\n<form id=\"fm1\"",
+            message
+        ),
+    ))
+}
+
+async fn cas_login_page() -> Html<&'static str> {
+    Html(CAS_LOGIN)
+}
+
+async fn cas_login(
+    State(state): State<Arc<MockState>>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Response {
+    let field = |name: &str| form.get(name).map(String::as_str).unwrap_or_default();
+
+    let captcha = field("captcha");
+    let captcha_skipped = captcha.is_empty() && !state.captcha_required.load(Ordering::SeqCst);
+    if captcha != CAPTCHA && !captcha_skipped {
+        return cas_error("验证码错误").into_response();
+    }
+    if field("username") != USERNAME || field("password") != aes_cbc_encrypt(PASSWORD).unwrap() {
+        return cas_error("用户名或密码错误").into_response();
+    }
+
+    let ticket = state.issue(&state.vpn_tickets);
+    (set_cookie(VPN_COOKIE, &ticket), Html(CAS_SUCCESS)).into_response()
+}
+
+async fn captcha() -> impl IntoResponse {
+    ([(CONTENT_TYPE, "image/jpeg")], CAPTCHA_IMAGE)
+}
+
+async fn jwxt_login_page(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    if !state.has_vpn(&headers) {
+        return cas_redirect().into_response();
+    }
+    Html(JWXT_LOGIN).into_response()
+}
+
+async fn login_to_xk(
+    State(state): State<Arc<MockState>>,
+    headers: HeaderMap,
+    Form(form): Form<HashMap<String, String>>,
+) -> Response {
+    if !state.has_vpn(&headers) {
+        return cas_redirect().into_response();
+    }
+    let expected = format!("{}%%%{}", encode_inp(USERNAME), encode_inp(PASSWORD));
+    if form.get("encoded") != Some(&expected) {
+        let page = JWXT_LOGIN.replace(
+            "<span id=\"showMsg\" style=\"color: red;\"></span>",
+            "<span id=\"showMsg\" style=\"color: red;\">该帐号不存在或密码错误,请联系管理员!</span>",
+        );
+        return Html(page).into_response();
+    }
+
+    let session = state.issue(&state.jwxt_sessions);
+    (
+        set_cookie(JWXT_COOKIE, &session),
+        Redirect::to("/jsxsd/framework/xsMain.jsp"),
+    )
+        .into_response()
+}
+
+/// 检查 VPN 与教务会话，失效时按真实系统的行为重定向到对应登录页
+fn authorize(state: &MockState, headers: &HeaderMap) -> Option<Redirect> {
+    if !state.has_vpn(headers) {
+        return Some(cas_redirect());
+    }
+    if !state.has_jwxt(headers) {
+        return Some(Redirect::to("/jsxsd/"));
+    }
+    None
+}
+
+async fn jwxt_main(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    match authorize(&state, &headers) {
+        Some(redirect) => redirect.into_response(),
+        None => Html(JWXT_MAIN).into_response(),
+    }
+}
+
+async fn data_page(state: &MockState, headers: &HeaderMap, page: &'static str) -> Response {
+    if let Some(redirect) = authorize(state, headers) {
+        return redirect.into_response();
+    }
+    tokio::time::sleep(state.delay).await;
+    Html(page).into_response()
+}
+
+async fn student_info(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(&state, &headers, include_str!("../fixtures/xsxx.html")).await
+}
+
+async fn student_photo(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    match authorize(&state, &headers) {
+        Some(redirect) => redirect.into_response(),
+        None => ([(CONTENT_TYPE, "image/jpeg")], PHOTO_IMAGE).into_response(),
+    }
+}
+
+async fn semester(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(
+        &state,
+        &headers,
+        include_str!("../fixtures/xsksap_query.html"),
+    )
+    .await
+}
+
+async fn calendar(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(
+        &state,
+        &headers,
+        include_str!("../fixtures/jxzl_query.html"),
+    )
+    .await
+}
+
+async fn score(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(&state, &headers, include_str!("../fixtures/cjcx_list.html")).await
+}
+
+async fn exam(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(
+        &state,
+        &headers,
+        include_str!("../fixtures/xsksap_list.html"),
+    )
+    .await
+}
+
+async fn course(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(&state, &headers, include_str!("../fixtures/xskb_list.html")).await
+}
+
+async fn elective(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(
+        &state,
+        &headers,
+        include_str!("../fixtures/xqxkchList.html"),
+    )
+    .await
+}
+
+async fn plan(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(
+        &state,
+        &headers,
+        include_str!("../fixtures/pyfa_query.html"),
+    )
+    .await
+}
+
+async fn dekt(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(
+        &state,
+        &headers,
+        include_str!("../fixtures/cxxf07List.html"),
+    )
+    .await
+}
+
+async fn dekt_detail(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(
+        &state,
+        &headers,
+        include_str!("../fixtures/cxxf07View.html"),
+    )
+    .await
+}
+
+async fn classroom_filters(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(
+        &state,
+        &headers,
+        include_str!("../fixtures/jsjy_query.html"),
+    )
+    .await
+}
+
+/// 按表单中的校区（xqbh）与教学楼（jzwbh）筛选教室，编号与 jsjy_query.html 中的选项对应
+async fn free_classrooms(
+    State(state): State<Arc<MockState>>,
+    headers: HeaderMap,
+    Form(form): Form<HashMap<String, String>>,
+) -> Response {
+    if let Some(redirect) = authorize(&state, &headers) {
+        return redirect.into_response();
+    }
+    tokio::time::sleep(state.delay).await;
+    let cell = |key: &str, options: &[(&str, &str)]| {
+        let code = form.get(key)?;
+        options
+            .iter()
+            .find(|(value, _)| value == code)
+            .map(|(_, name)| format!("<td>{}</td>", name))
+    };
+    let filters: Vec<String> = [
+        cell("xqbh", &[("01", "本部校区"), ("02", "东校区")]),
+        cell(
+            "jzwbh",
+            &[
+                ("0101", "公共教学楼A"),
+                ("0102", "公共教学楼B"),
+                ("0201", "东区实验楼"),
+            ],
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    let page: Vec<&str> = include_str!("../fixtures/jsjy_query2.html")
+        .lines()
+        .filter(|line| {
+            !line.contains("<td>") || filters.iter().all(|filter| line.contains(filter.as_str()))
+        })
+        .collect();
+    Html(page.join("\n")).into_response()
+}
+
+fn selection_rows(courses: &str) -> Vec<serde_json::Value> {
+    let value: serde_json::Value = serde_json::from_str(courses).unwrap();
+    value["aaData"].as_array().unwrap().clone()
+}
+
+fn initial_seats() -> HashMap<String, u32> {
+    SELECTION_LISTS
+        .iter()
+        .flat_map(|(_, _, courses)| selection_rows(courses))
+        .map(|row| {
+            let number = |key: &str| match &row[key] {
+                serde_json::Value::String(text) => text.parse::<u32>().unwrap(),
+                value => value.as_u64().unwrap() as u32,
+            };
+            let seats = match row.get("syrs") {
+                Some(_) => number("syrs"),
+                None => number("pkrs") - number("xkrs"),
+            };
+            (row["jx0404id"].as_str().unwrap().to_string(), seats)
+        })
+        .collect()
+}
+
+fn alert_page(message: &str) -> Html<String> {
+    Html(format!(
+        "<html><head><script type=\"text/javascript\">alert('{}');window.history.back();</script></head><body></body></html>",
+        message
+    ))
+}
+
+fn oper_result(success: bool, message: &str) -> Response {
+    axum::Json(serde_json::json!({ "success": success, "message": message })).into_response()
+}
+
+async fn selection_rounds(State(state): State<Arc<MockState>>, headers: HeaderMap) -> Response {
+    data_page(&state, &headers, include_str!("../fixtures/xklc_list.html")).await
+}
+
+async fn enter_selection(
+    State(state): State<Arc<MockState>>,
+    headers: HeaderMap,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    if let Some(redirect) = authorize(&state, &headers) {
+        return redirect.into_response();
+    }
+    if query.get("jx0502zbid").map(String::as_str) != Some(OPEN_ROUND) {
+        return alert_page("选课时间未到").into_response();
+    }
+    state.selection_entered.store(true, Ordering::SeqCst);
+    Html("<html><head><title>选课中心</title></head><body><div id=\"xsxkTab\"></div></body></html>")
+        .into_response()
+}
+
+/// 课程列表的剩余名额与已选人数按当前状态生成
+async fn selection_courses(
+    state: Arc<MockState>,
+    headers: HeaderMap,
+    courses: &'static str,
+) -> Response {
+    if let Some(redirect) = authorize(&state, &headers) {
+        return redirect.into_response();
+    }
+    if !state.selection_entered.load(Ordering::SeqCst) {
+        return alert_page("当前未开放选课").into_response();
+    }
+    tokio::time::sleep(state.delay).await;
+    let seats = state.seats.lock().unwrap().clone();
+    let rows: Vec<_> = selection_rows(courses)
+        .into_iter()
+        .map(|mut row| {
+            let remaining = seats[row["jx0404id"].as_str().unwrap()];
+            row["syrs"] = serde_json::Value::String(remaining.to_string());
+            row
+        })
+        .collect();
+    axum::Json(serde_json::json!({
+        "sEcho": "1",
+        "iTotalRecords": rows.len(),
+        "iTotalDisplayRecords": rows.len(),
+        "aaData": rows,
+    }))
+    .into_response()
+}
+
+/// 只能选本类别课程列表中的教学班
+async fn select_course(
+    state: Arc<MockState>,
+    headers: HeaderMap,
+    query: HashMap<String, String>,
+    courses: &'static str,
+) -> Response {
+    if let Some(redirect) = authorize(&state, &headers) {
+        return redirect.into_response();
+    }
+    if !state.selection_entered.load(Ordering::SeqCst) {
+        return alert_page("不在选课时间范围内，不能选课").into_response();
+    }
+    let id = query.get("jx0404id").cloned().unwrap_or_default();
+    let Some(row) = selection_rows(courses)
+        .into_iter()
+        .find(|row| row["jx0404id"] == id.as_str())
+    else {
+        return oper_result(false, "选课失败：教学班不存在");
+    };
+    if !row["ctsm"].as_str().unwrap_or_default().is_empty() {
+        return oper_result(false, "选课失败：此课堂与已选课程上课时间冲突");
+    }
+    if state.chosen.lock().unwrap().contains(&id) {
+        return oper_result(false, "选课失败：已选择该课程");
+    }
+    let mut seats = state.seats.lock().unwrap();
+    let remaining = seats.get_mut(&id).unwrap();
+    if *remaining == 0 {
+        return oper_result(false, "选课失败：此课堂选课人数已满！");
+    }
+    *remaining -= 1;
+    state.chosen.lock().unwrap().insert(id);
+    oper_result(true, "选课成功")
+}
+
+async fn drop_course(
+    State(state): State<Arc<MockState>>,
+    headers: HeaderMap,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    if let Some(redirect) = authorize(&state, &headers) {
+        return redirect.into_response();
+    }
+    let id = query.get("jx0404id").cloned().unwrap_or_default();
+    if !state.chosen.lock().unwrap().remove(&id) {
+        return oper_result(false, "退课失败：未选择该课程");
+    }
+    if let Some(remaining) = state.seats.lock().unwrap().get_mut(&id) {
+        *remaining += 1;
+    }
+    oper_result(true, "退课成功")
+}
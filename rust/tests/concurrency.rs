@@ -0,0 +1,56 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{MockServer, CAPTCHA, PASSWORD, USERNAME};
+use rust_lib_hbfu_alex::api::simple::{
+    api_course, api_dekt, api_exam, api_get_captcha, api_login, api_score, api_set_endpoints,
+    init_app,
+};
+
+const PAGE_DELAY: Duration = Duration::from_millis(300);
+
+#[tokio::test]
+async fn independent_requests_run_concurrently() {
+    let server = MockServer::start(PAGE_DELAY).await;
+    init_app();
+    api_set_endpoints(server.base_url.clone(), server.base_url.clone()).unwrap();
+    api_get_captcha().await.unwrap();
+    api_login(
+        USERNAME.to_string(),
+        PASSWORD.to_string(),
+        PASSWORD.to_string(),
+        CAPTCHA.to_string(),
+    )
+    .await
+    .unwrap();
+
+    let started = Instant::now();
+    api_score(String::new()).await.unwrap();
+    api_exam(String::new()).await.unwrap();
+    api_course(String::new()).await.unwrap();
+    api_dekt().await.unwrap();
+    let serial = started.elapsed();
+
+    let started = Instant::now();
+    let (score, exam, course, dekt) = tokio::join!(
+        api_score(String::new()),
+        api_exam(String::new()),
+        api_course(String::new()),
+        api_dekt(),
+    );
+    let parallel = started.elapsed();
+    score.unwrap();
+    exam.unwrap();
+    course.unwrap();
+    dekt.unwrap();
+
+    println!(
+        "serial: {:?}, parallel: {:?}, speedup: {:.1}x",
+        serial,
+        parallel,
+        serial.as_secs_f64() / parallel.as_secs_f64()
+    );
+    assert!(serial >= PAGE_DELAY * 4);
+    assert!(parallel < PAGE_DELAY * 2);
+}
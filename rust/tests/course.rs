@@ -0,0 +1,105 @@
+use rust_lib_hbfu_alex::api::jwxt::course::{parse_course_schedule, parse_weeks};
+
+#[test]
+fn week_ranges_and_lists() {
+    assert_eq!(parse_weeks("4-17周"), (4..=17).collect::<Vec<u8>>());
+    assert_eq!(parse_weeks("2,4,6周"), [2, 4, 6]);
+    assert_eq!(parse_weeks("3周"), [3]);
+    assert_eq!(
+        parse_weeks("1-8,10-16周"),
+        (1..=8).chain(10..=16).collect::<Vec<u8>>()
+    );
+    assert_eq!(parse_weeks("1-3，2-4周"), [1, 2, 3, 4]);
+    assert!(parse_weeks("").is_empty());
+    assert!(parse_weeks("未安排").is_empty());
+}
+
+#[test]
+fn odd_and_even_weeks() {
+    assert_eq!(parse_weeks("1-15(单周)"), [1, 3, 5, 7, 9, 11, 13, 15]);
+    assert_eq!(parse_weeks("2-16(双周)"), [2, 4, 6, 8, 10, 12, 14, 16]);
+    assert_eq!(parse_weeks("1-8,10-16(单周)"), [1, 3, 5, 7, 11, 13, 15]);
+    assert_eq!(parse_weeks("1-5单周,6-9周"), [1, 3, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn courses_active_in_week() {
+    let courses = parse_course_schedule(include_str!("fixtures/xskb_list.html")).unwrap();
+    let active = |week: u8| {
+        courses
+            .iter()
+            .filter(|course| course.is_active_in_week(week))
+            .map(|course| course.name.as_str())
+            .collect::<Vec<_>>()
+    };
+
+    assert!(active(9).iter().all(|name| *name != "大学英语Ⅱ"));
+    assert!(active(10).contains(&"大学英语Ⅱ"));
+    assert!(!active(3).contains(&"大学物理"));
+    assert!(active(4).contains(&"大学物理"));
+    assert!(active(17).contains(&"大学物理"));
+    assert!(active(1).contains(&"数据结构"));
+    assert!(!active(2).contains(&"数据结构"));
+}
+
+#[test]
+fn alternating_weeks_in_one_cell() {
+    let courses =
+        parse_course_schedule(include_str!("fixtures/xskb_list_alternating.html")).unwrap();
+
+    let monday: Vec<_> = courses
+        .iter()
+        .filter(|course| course.day_of_week == "星期一" && course.section == 1)
+        .collect();
+    assert_eq!(monday.len(), 2);
+    assert_eq!(monday[0].id, "1-1-大学物理实验");
+    assert_eq!(monday[1].id, "1-1-大学物理实验-2");
+    assert_eq!(
+        (monday[0].teacher.as_str(), monday[0].classroom.as_str()),
+        ("周老师", "实验楼101")
+    );
+    assert_eq!(
+        (monday[1].teacher.as_str(), monday[1].classroom.as_str()),
+        ("吴老师", "实验楼102")
+    );
+    assert_eq!(monday[0].weeks, "1-15(单周)");
+    assert_eq!(monday[1].weeks, "2-16(双周)");
+    assert!(monday[0].is_active_in_week(7) && !monday[1].is_active_in_week(7));
+    assert!(!monday[0].is_active_in_week(8) && monday[1].is_active_in_week(8));
+}
+
+#[test]
+fn rotating_courses_in_one_cell() {
+    let courses =
+        parse_course_schedule(include_str!("fixtures/xskb_list_alternating.html")).unwrap();
+
+    let wednesday: Vec<_> = courses
+        .iter()
+        .filter(|course| course.day_of_week == "星期三")
+        .map(|course| {
+            (
+                course.name.as_str(),
+                course.teacher.as_str(),
+                course.weeks.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        wednesday,
+        [
+            ("体育Ⅱ", "郑老师", "1-8周"),
+            ("形势与政策", "冯老师", "9-12周"),
+            ("军事理论", "陈老师", "13-16周"),
+        ]
+    );
+
+    // 只有 kbcontent1 的单元格仍能解析，缺失的教师与节次标记为未提供
+    let friday = courses
+        .iter()
+        .find(|course| course.day_of_week == "星期五")
+        .unwrap();
+    assert_eq!(friday.name, "创新创业基础");
+    assert_eq!(friday.week_list, [2, 4, 6, 8]);
+    assert_eq!(friday.teacher, "未提供");
+    assert_eq!(friday.duration, "未提供");
+}
@@ -0,0 +1,128 @@
+use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+use rust_lib_hbfu_alex::api::{
+    error::ApiError,
+    ics::{exam_events, write_calendar},
+    jwxt::exam::{exam_countdown, parse_exam, parse_exam_time},
+};
+
+fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2024, 1, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+#[test]
+fn exam_time_formats() {
+    let expected = Some((at(8, 9, 0), at(8, 11, 0)));
+    assert_eq!(parse_exam_time("2024-01-08 09:00~11:00"), expected);
+    assert_eq!(parse_exam_time("2024-01-08 09:00-11:00"), expected);
+    assert_eq!(parse_exam_time("2024-01-08 9:00 ~ 11:00"), expected);
+    assert_eq!(
+        parse_exam_time("2024-01-08 09:00~2024-01-08 11:00"),
+        expected
+    );
+    assert_eq!(parse_exam_time("2024-1-8 09:00~11:00"), expected);
+    assert_eq!(parse_exam_time(""), None);
+    assert_eq!(parse_exam_time("未安排"), None);
+    assert_eq!(parse_exam_time("2024-01-08 11:00~09:00"), None);
+}
+
+#[test]
+fn countdown_sorted_with_conflicts() {
+    let mut exams = parse_exam(include_str!("fixtures/xsksap_list.html")).unwrap();
+    exams[0].exam_time = "待定".to_string();
+    exams.swap(1, 2);
+
+    let countdown = exam_countdown(exams, at(9, 12, 0));
+    let ids: Vec<u32> = countdown.iter().map(|c| c.exam.id).collect();
+    assert_eq!(ids, [2, 3, 1]);
+
+    assert_eq!(countdown[0].remaining_seconds, Some(2 * 3600));
+    assert_eq!(countdown[0].overlaps_with, [1]);
+    assert_eq!(countdown[1].same_day_with, [0]);
+    assert!(!countdown[1].is_finished);
+    assert_eq!(countdown[2].remaining_seconds, None);
+    assert!(countdown[2].overlaps_with.is_empty());
+}
+
+#[test]
+fn conflicts_do_not_depend_on_optional_columns() {
+    let mut exams = parse_exam(include_str!("fixtures/xsksap_list.html")).unwrap();
+    for exam in &mut exams {
+        exam.id = 0;
+        exam.course_code.clear();
+    }
+
+    let countdown = exam_countdown(exams, at(9, 12, 0));
+    assert_eq!(countdown[0].overlaps_with, [1]);
+    assert_eq!(countdown[1].overlaps_with, [0]);
+    assert_eq!(countdown[1].same_day_with, [0]);
+    assert!(countdown[2].overlaps_with.is_empty());
+}
+
+#[test]
+fn finished_exams_sorted_last() {
+    let exams = parse_exam(include_str!("fixtures/xsksap_list.html")).unwrap();
+    let countdown = exam_countdown(exams, at(9, 10, 0));
+
+    let ids: Vec<u32> = countdown.iter().map(|c| c.exam.id).collect();
+    assert_eq!(ids, [2, 3, 1]);
+    assert!(countdown[2].is_finished);
+    assert!(countdown[2].remaining_seconds.unwrap() < 0);
+    assert!(countdown[2].same_day_with.is_empty());
+}
+
+#[test]
+fn exam_calendar_has_alarms() {
+    let exams = parse_exam(include_str!("fixtures/xsksap_list.html")).unwrap();
+    let events = exam_events(&exams, "2023-2024-1", &[1440, 60]);
+    assert_eq!(events.len(), 3);
+
+    let dtstamp = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let ics = write_calendar("考试", &events, dtstamp).replace("\r\n ", "");
+    assert!(ics.contains("SUMMARY:考试：高等数学A(上)"));
+    assert!(ics.contains("DTSTART;TZID=Asia/Shanghai:20240108T090000"));
+    assert!(ics.contains("LOCATION:机房A-3"));
+    assert!(ics.contains("座位号：42"));
+    assert_eq!(ics.matches("BEGIN:VALARM").count(), 6);
+    assert_eq!(ics.matches("TRIGGER:-PT1440M").count(), 3);
+
+    let mut moved = exams.clone();
+    moved[0].exam_time = "2024-01-10 09:00~11:00".to_string();
+    let uids = |exams| {
+        exam_events(exams, "2023-2024-1", &[])
+            .into_iter()
+            .map(|event| event.uid)
+            .collect::<std::collections::HashSet<_>>()
+    };
+    assert_eq!(uids(&exams), uids(&moved));
+}
+
+#[test]
+fn columns_mapped_by_header() {
+    let html = include_str!("fixtures/xsksap_list.html")
+        .replace("<th class=\"Nsb_r_list_thb\">校区</th>", "")
+        .replace("<td>本部校区</td>", "")
+        .replace("<td>东校区</td>", "");
+    let exams = parse_exam(&html).unwrap();
+    assert_eq!(exams.len(), 3);
+    assert_eq!(exams[1].campus, "");
+    assert_eq!(exams[1].course_code, "B0120003");
+    assert_eq!(exams[1].seat_number, "42");
+    assert_eq!(exams[1].exam_method, "闭卷");
+    assert_eq!(exams[1].remark, "携带学生证");
+    assert_eq!(exams[0].remark, "");
+}
+
+#[test]
+fn missing_exam_time_column_is_reported() {
+    let html = include_str!("fixtures/xsksap_list.html").replace("考试时间", "时间");
+    match parse_exam(&html) {
+        Err(ApiError::ParseLayoutChanged { page, selector }) => {
+            assert_eq!(page, "xsksap_list");
+            assert_eq!(selector, "表头「考试时间」");
+        }
+        _ => panic!("expected ParseLayoutChanged"),
+    }
+}
@@ -0,0 +1,139 @@
+use rust_lib_hbfu_alex::api::jwxt::{
+    gpa::{compute_gpa, gpa_report, GpaFilter, GpaScheme},
+    grade::{Grade, GradeScale},
+    score::{parse_score_all, ScoreInfo},
+};
+
+fn approx(actual: Option<f32>, expected: f32) {
+    let actual = actual.expect("没有计算出结果");
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "{} != {}",
+        actual,
+        expected
+    );
+}
+
+fn scores(fixture: &str) -> Vec<ScoreInfo> {
+    parse_score_all(fixture, &GradeScale::default())
+        .unwrap()
+        .info
+}
+
+fn single(raw: &str) -> ScoreInfo {
+    ScoreInfo {
+        id: 1,
+        term: "2023-2024-1".to_string(),
+        course_id: "B0000001".to_string(),
+        course_name: "测试课程".to_string(),
+        score: Grade::parse(raw, &GradeScale::default()),
+        credit: 1.0,
+        hours: 16.0,
+        gpa: 0.0,
+        exam_type: "考试".to_string(),
+        course_attr: "必修".to_string(),
+        course_nature: "公共基础课".to_string(),
+    }
+}
+
+#[test]
+fn scheme_boundaries() {
+    let point = |scheme: GpaScheme, raw: &str| scheme.grade_point(&single(raw)).unwrap();
+
+    assert_eq!(point(GpaScheme::Standard4, "90"), 4.0);
+    assert_eq!(point(GpaScheme::Standard4, "89.5"), 3.0);
+    assert_eq!(point(GpaScheme::Standard4, "60"), 1.0);
+    assert_eq!(point(GpaScheme::Standard4, "59"), 0.0);
+
+    assert_eq!(point(GpaScheme::Improved4, "85"), 4.0);
+    assert_eq!(point(GpaScheme::Improved4, "70"), 3.0);
+    assert_eq!(point(GpaScheme::Improved4, "69"), 2.0);
+
+    assert_eq!(point(GpaScheme::Pku4, "84"), 3.3);
+    assert_eq!(point(GpaScheme::Pku4, "77"), 2.7);
+    assert_eq!(point(GpaScheme::Pku4, "64"), 1.5);
+    assert_eq!(point(GpaScheme::Pku4, "良好"), 3.7);
+
+    assert_eq!(point(GpaScheme::Wes, "75"), 3.0);
+    assert_eq!(point(GpaScheme::Wes, "74"), 2.0);
+    assert_eq!(point(GpaScheme::Wes, "缺考"), 0.0);
+
+    assert_eq!(GpaScheme::Wes.grade_point(&single("免修")), None);
+}
+
+#[test]
+fn overall_gpa_under_every_scheme() {
+    let scores = scores(include_str!("fixtures/cjcx_list.html"));
+    let all = GpaFilter::default();
+
+    let school = compute_gpa(&scores, GpaScheme::School, &all);
+    assert_eq!(school.credits, 17.5);
+    assert_eq!(school.course_count, 6);
+    approx(school.gpa, 55.325 / 17.5);
+    approx(school.average_score, 1445.25 / 17.5);
+
+    approx(
+        compute_gpa(&scores, GpaScheme::Standard4, &all).gpa,
+        48.5 / 17.5,
+    );
+    approx(
+        compute_gpa(&scores, GpaScheme::Improved4, &all).gpa,
+        59.0 / 17.5,
+    );
+    approx(
+        compute_gpa(&scores, GpaScheme::Pku4, &all).gpa,
+        56.75 / 17.5,
+    );
+    approx(compute_gpa(&scores, GpaScheme::Wes, &all).gpa, 59.0 / 17.5);
+}
+
+#[test]
+fn filters_by_course_nature_and_attr() {
+    let scores = scores(include_str!("fixtures/cjcx_list.html"));
+
+    let public = GpaFilter {
+        course_natures: vec!["公共基础课".to_string()],
+        course_attrs: vec![],
+    };
+    let result = compute_gpa(&scores, GpaScheme::Standard4, &public);
+    assert_eq!(result.course_count, 4);
+    approx(result.gpa, 38.0 / 12.0);
+
+    let elective = GpaFilter {
+        course_natures: vec![],
+        course_attrs: vec!["选修".to_string()],
+    };
+    let result = compute_gpa(&scores, GpaScheme::Standard4, &elective);
+    assert_eq!(result.course_count, 0);
+    assert_eq!(result.gpa, None);
+}
+
+#[test]
+fn per_semester_breakdown() {
+    let scores = scores(include_str!("fixtures/cjcx_list.html"));
+    let report = gpa_report(&scores, GpaScheme::Standard4, &GpaFilter::default());
+
+    assert_eq!(report.scheme, GpaScheme::Standard4);
+    approx(report.overall.gpa, 48.5 / 17.5);
+
+    let terms: Vec<_> = report.semesters.iter().map(|s| s.term.as_str()).collect();
+    assert_eq!(terms, ["2023-2024-1", "2023-2024-2"]);
+    approx(report.semesters[0].result.gpa, 35.0 / 13.0);
+    approx(report.semesters[1].result.gpa, 13.5 / 4.5);
+}
+
+#[test]
+fn retakes_count_once_overall_but_in_their_own_semester() {
+    let scores = scores(include_str!("fixtures/cjcx_list_special_grades.html"));
+    let report = gpa_report(&scores, GpaScheme::Standard4, &GpaFilter::default());
+
+    // 高等数学取补考 62 分，程序设计基础取重修 75 分，大学英语免修不计入
+    assert_eq!(report.overall.course_count, 5);
+    assert_eq!(report.overall.credits, 11.0);
+    approx(report.overall.average_score, 70.0);
+
+    // 第一学期的缺考与不及格计入当学期
+    let first = &report.semesters[0].result;
+    assert_eq!(first.course_count, 3);
+    approx(first.average_score, 65.0 / 8.0);
+}
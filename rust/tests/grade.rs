@@ -0,0 +1,117 @@
+use rust_lib_hbfu_alex::api::jwxt::{
+    grade::{Grade, GradeScale},
+    score::{effective_scores, gpa_average, parse_score, parse_score_all, weighted_average},
+};
+
+fn approx(actual: Option<f32>, expected: f32) {
+    let actual = actual.expect("没有计算出结果");
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "{} != {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn levels_use_the_scale() {
+    let scale = GradeScale::default();
+    assert_eq!(Grade::parse("优秀", &scale).value, Some(95.0));
+    assert_eq!(Grade::parse("良好", &scale).value, Some(85.0));
+    assert_eq!(Grade::parse("中等", &scale).value, Some(75.0));
+    assert_eq!(Grade::parse("及格", &scale).value, Some(65.0));
+    assert_eq!(Grade::parse("不及格", &scale).value, Some(0.0));
+    assert_eq!(Grade::parse("合格", &scale).value, Some(85.0));
+    assert_eq!(Grade::parse("不合格", &scale).value, Some(0.0));
+
+    let scale = GradeScale {
+        good: 80.0,
+        ..GradeScale::default()
+    };
+    assert_eq!(Grade::parse("良好", &scale).value, Some(80.0));
+}
+
+#[test]
+fn special_grades_are_flagged() {
+    let scale = GradeScale::default();
+
+    let grade = Grade::parse(" 85.5 ", &scale);
+    assert_eq!(grade.raw, "85.5");
+    assert_eq!(grade.value, Some(85.5));
+    assert!(grade.is_passed());
+
+    let absent = Grade::parse("缺考", &scale);
+    assert!(absent.is_absent);
+    assert_eq!(absent.value, Some(0.0));
+    assert!(absent.is_counted());
+    assert!(!absent.is_passed());
+
+    let exempt = Grade::parse("免修", &scale);
+    assert!(exempt.is_exempt);
+    assert_eq!(exempt.value, None);
+    assert!(!exempt.is_counted());
+    assert!(exempt.is_passed());
+
+    let makeup = Grade::parse("62(补考)", &scale);
+    assert!(makeup.is_makeup);
+    assert_eq!(makeup.value, Some(62.0));
+
+    let retake = Grade::parse("75(重修)", &scale);
+    assert!(retake.is_retake);
+    assert_eq!(retake.value, Some(75.0));
+}
+
+#[test]
+fn exam_nature_column_sets_the_flags() {
+    let html = include_str!("fixtures/cjcx_list_exam_nature.html");
+    let scores = parse_score(html, &GradeScale::default()).unwrap();
+    let flags: Vec<_> = scores
+        .iter()
+        .map(|score| {
+            (
+                score.score.raw.as_str(),
+                score.score.is_makeup,
+                score.score.is_retake,
+            )
+        })
+        .collect();
+    assert_eq!(
+        flags,
+        [
+            ("55", false, false),
+            ("61", true, false),
+            ("78", false, true),
+            // 考试性质列为 "正常考试" 时不再看成绩文字
+            ("62(补考)", false, false),
+        ]
+    );
+
+    let grade = Grade::parse("60", &GradeScale::default());
+    assert!(grade.clone().with_exam_nature("补").is_makeup);
+    assert!(grade.with_exam_nature("重").is_retake);
+}
+
+#[test]
+fn averages_skip_exempt_and_superseded_attempts() {
+    let html = include_str!("fixtures/cjcx_list_special_grades.html");
+    let total = parse_score_all(html, &GradeScale::default()).unwrap();
+
+    let effective: Vec<_> = effective_scores(&total.info)
+        .into_iter()
+        .map(|score| score.score.raw.as_str())
+        .collect();
+    assert_eq!(
+        effective,
+        ["免修", "及格", "62(补考)", "优秀", "中等", "75(重修)"]
+    );
+
+    approx(weighted_average(&total.info), 70.0);
+    approx(total.summary.average_score, 70.0);
+    approx(gpa_average(&total.info), 21.0 / 11.0);
+}
+
+#[test]
+fn averages_of_empty_scores() {
+    assert_eq!(weighted_average(&[]), None);
+    assert_eq!(gpa_average(&[]), None);
+}
@@ -0,0 +1,102 @@
+use rust_lib_hbfu_alex::api::{
+    error::ApiError,
+    jwxt::{
+        dekt::parse_dekt,
+        elective::parse_elective,
+        grade::GradeScale,
+        graduation::{
+            graduation_check, parse_credit, CreditSource, DektRequirement, GraduationReport,
+        },
+        score::parse_score,
+    },
+};
+
+fn check(requirements: &[DektRequirement]) -> GraduationReport {
+    let elective = parse_elective(include_str!("fixtures/xqxkchList.html")).unwrap();
+    let scores = parse_score(
+        include_str!("fixtures/cjcx_list.html"),
+        &GradeScale::default(),
+    )
+    .unwrap();
+    let dekt = parse_dekt(include_str!("fixtures/cxxf07List.html")).unwrap();
+    graduation_check(&elective.credits, &scores, &dekt.total, requirements).unwrap()
+}
+
+#[test]
+fn course_buckets() {
+    let report = check(&[]);
+    let names: Vec<_> = report.buckets.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "必修",
+            "限选",
+            "任选",
+            "公选",
+            "课程总学分",
+            "思想成长",
+            "创新创业",
+            "志愿公益"
+        ]
+    );
+
+    let required = &report.buckets[0];
+    assert_eq!((required.earned, required.required), (66.0, 130.0));
+    assert_eq!(required.missing, 64.0);
+    assert!(!required.passed);
+
+    // 成绩单中已通过课程：5 + 3 + 3 + 1 + 3.5
+    let total = &report.buckets[4];
+    assert_eq!((total.earned, total.required), (15.5, 168.0));
+    assert!(!report.passed);
+}
+
+#[test]
+fn second_classroom_requirements() {
+    let report = check(&[
+        DektRequirement {
+            category: "创新创业".to_string(),
+            credits: 2.0,
+        },
+        DektRequirement {
+            category: "文体活动".to_string(),
+            credits: 1.0,
+        },
+    ]);
+    let dekt: Vec<_> = report
+        .buckets
+        .iter()
+        .filter(|b| b.source == CreditSource::SecondClassroom)
+        .map(|b| (b.name.as_str(), b.earned, b.required, b.passed))
+        .collect();
+    assert_eq!(
+        dekt,
+        [
+            ("创新创业", 2.0, 2.0, true),
+            ("文体活动", 0.0, 1.0, false),
+            ("思想成长", 1.5, 0.0, true),
+            ("志愿公益", 0.5, 0.0, true),
+        ]
+    );
+}
+
+#[test]
+fn missing_requirement_row() {
+    let html = include_str!("fixtures/xqxkchList.html").replace("培养方案要求", "其他");
+    let elective = parse_elective(&html).unwrap();
+    assert!(matches!(
+        graduation_check(&elective.credits, &[], &[], &[]),
+        Err(ApiError::ParseLayoutChanged { .. })
+    ));
+}
+
+#[test]
+fn credit_text() {
+    assert_eq!(parse_credit("1.5"), 1.5);
+    assert_eq!(parse_credit(" 2分"), 2.0);
+    assert_eq!(parse_credit(""), 0.0);
+
+    let html = include_str!("fixtures/xqxkchList.html").replace("<td>66</td>", "<td>66.5</td>");
+    let elective = parse_elective(&html).unwrap();
+    assert_eq!(elective.credits[1].required, 66.5);
+}
@@ -0,0 +1,165 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use rust_lib_hbfu_alex::api::{
+    error::ApiError,
+    ics::{course_events, parse_date, write_calendar, IcsEvent, SectionTime},
+    jwxt::course::parse_course_schedule,
+};
+
+const SEMESTER: &str = "2023-2024-1";
+
+fn first_monday() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2023, 9, 4).unwrap()
+}
+
+fn export(section_times: &[SectionTime]) -> String {
+    let courses = parse_course_schedule(include_str!("fixtures/xskb_list.html")).unwrap();
+    let events = course_events(&courses, SEMESTER, first_monday(), section_times);
+    let dtstamp = Utc.with_ymd_and_hms(2023, 9, 1, 0, 0, 0).unwrap();
+    write_calendar("课表", &events, dtstamp)
+}
+
+/// 取出所有事件，折行已展开
+fn events(ics: &str) -> Vec<Vec<String>> {
+    let unfolded = ics.replace("\r\n ", "");
+    let mut events = Vec::new();
+    let mut current: Option<Vec<String>> = None;
+    for line in unfolded.split("\r\n") {
+        match line {
+            "BEGIN:VEVENT" => current = Some(Vec::new()),
+            "END:VEVENT" => events.extend(current.take()),
+            _ => {
+                if let Some(event) = current.as_mut() {
+                    event.push(line.to_string());
+                }
+            }
+        }
+    }
+    events
+}
+
+fn property<'a>(event: &'a [String], name: &str) -> Option<&'a str> {
+    event
+        .iter()
+        .find_map(|line| line.strip_prefix(name))
+        .map(|rest| rest.trim_start_matches(':'))
+}
+
+#[test]
+fn expands_weeks_into_dated_events() {
+    let ics = export(&[]);
+    let events = events(&ics);
+    let math: Vec<_> = events
+        .iter()
+        .filter(|event| property(event, "SUMMARY") == Some("高等数学A(下)"))
+        .collect();
+    // 星期一、星期三各 16 周
+    assert_eq!(math.len(), 32);
+
+    let first = math[0];
+    assert_eq!(
+        property(first, "DTSTART;TZID=Asia/Shanghai"),
+        Some("20230904T080000")
+    );
+    assert_eq!(
+        property(first, "DTEND;TZID=Asia/Shanghai"),
+        Some("20230904T094000")
+    );
+    assert_eq!(property(first, "LOCATION"), Some("J1-201"));
+    assert!(property(first, "DESCRIPTION").unwrap().contains("王老师"));
+
+    let odd: Vec<_> = events
+        .iter()
+        .filter(|event| property(event, "SUMMARY") == Some("数据结构"))
+        .collect();
+    assert_eq!(odd.len(), 8);
+    // 第 3 周星期二
+    assert_eq!(
+        property(odd[1], "DTSTART;TZID=Asia/Shanghai"),
+        Some("20230919T100000")
+    );
+}
+
+#[test]
+fn section_times_override_time_range() {
+    let ics = export(&[SectionTime {
+        section: 1,
+        start: "08:10".to_string(),
+        end: "09:50".to_string(),
+    }]);
+    let events = events(&ics);
+    let math = events
+        .iter()
+        .find(|event| property(event, "SUMMARY") == Some("高等数学A(下)"))
+        .unwrap();
+    assert_eq!(
+        property(math, "DTSTART;TZID=Asia/Shanghai"),
+        Some("20230904T081000")
+    );
+    assert_eq!(
+        property(math, "DTEND;TZID=Asia/Shanghai"),
+        Some("20230904T095000")
+    );
+}
+
+#[test]
+fn uids_are_stable_and_unique() {
+    let first = export(&[]);
+    let second = export(&[SectionTime {
+        section: 2,
+        start: "10:10".to_string(),
+        end: "11:50".to_string(),
+    }]);
+    let uids = |ics: &str| {
+        let mut uids: Vec<String> = events(ics)
+            .iter()
+            .map(|event| property(event, "UID").unwrap().to_string())
+            .collect();
+        uids.sort();
+        uids
+    };
+
+    let first_uids = uids(&first);
+    assert_eq!(first_uids, uids(&second));
+    let mut unique = first_uids.clone();
+    unique.dedup();
+    assert_eq!(unique.len(), first_uids.len());
+    assert!(first_uids.iter().all(|uid| uid.ends_with("@hbfu-alex")));
+}
+
+#[test]
+fn escapes_text_and_folds_long_lines() {
+    let start = first_monday().and_hms_opt(8, 0, 0).unwrap();
+    let event = IcsEvent {
+        uid: "test@hbfu-alex".to_string(),
+        start,
+        end: start,
+        summary: "课程;名称,含特殊\\字符".to_string(),
+        location: String::new(),
+        description: "第一行\n".to_string() + &"很长的说明".repeat(10),
+        alarms: vec![15],
+    };
+    let ics = write_calendar("课表", &[event], Utc::now());
+
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+    assert!(!ics.contains("LOCATION"));
+    assert!(ics.contains("TRIGGER:-PT15M"));
+
+    let event = &events(&ics)[0];
+    assert_eq!(
+        property(event, "SUMMARY"),
+        Some("课程\\;名称\\,含特殊\\\\字符")
+    );
+    assert!(property(event, "DESCRIPTION")
+        .unwrap()
+        .starts_with("第一行\\n很长的说明"));
+}
+
+#[test]
+fn rejects_malformed_dates() {
+    assert_eq!(parse_date("2023-09-04").unwrap(), first_monday());
+    assert!(matches!(
+        parse_date("2023/9/4"),
+        Err(ApiError::InvalidArgument { .. })
+    ));
+}
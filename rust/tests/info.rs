@@ -0,0 +1,28 @@
+use rust_lib_hbfu_alex::api::{
+    error::ApiError,
+    jwxt::info::{parse_photo_src, StudentPhoto},
+};
+
+#[test]
+fn photo_src_from_student_card() {
+    assert_eq!(
+        parse_photo_src(include_str!("fixtures/xsxx.html")).unwrap(),
+        "/jsxsd/grxx/xszpLoad"
+    );
+    assert!(matches!(
+        parse_photo_src(include_str!("fixtures/xsxx_minimal.html")),
+        Err(ApiError::ParseLayoutChanged { .. })
+    ));
+}
+
+#[test]
+fn photo_mime_type() {
+    let jpeg = include_bytes!("fixtures/captcha.jpg").to_vec();
+    let photo = StudentPhoto::new(jpeg.clone(), Some("image/png;charset=UTF-8")).unwrap();
+    assert_eq!(photo.mime_type, "image/png");
+
+    let photo = StudentPhoto::new(jpeg, Some("application/octet-stream")).unwrap();
+    assert_eq!(photo.mime_type, "image/jpeg");
+
+    assert!(StudentPhoto::new(b"<html></html>".to_vec(), Some("text/html")).is_err());
+}
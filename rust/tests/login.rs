@@ -0,0 +1,82 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{MockServer, CAPTCHA, CAPTCHA_IMAGE, PASSWORD, USERNAME};
+use rust_lib_hbfu_alex::api::{
+    error::ApiError,
+    session::{Endpoints, HttpSession, LoginState},
+};
+
+async fn session() -> (MockServer, HttpSession) {
+    let server = MockServer::start(Duration::ZERO).await;
+    let session = HttpSession::with_endpoints(Endpoints {
+        cas: server.base_url.clone(),
+        jwxt: server.base_url.clone(),
+    });
+    (server, session)
+}
+
+#[tokio::test]
+async fn complete_login_succeeds() {
+    let (_server, session) = session().await;
+    assert_eq!(session.login_state(), LoginState::NoSession);
+
+    assert_eq!(session.get_captcha().await.unwrap(), CAPTCHA_IMAGE);
+    assert_eq!(session.login_state(), LoginState::CaptchaFetched);
+
+    session
+        .complete_login(USERNAME, PASSWORD, PASSWORD, CAPTCHA)
+        .await
+        .unwrap();
+    assert_eq!(session.login_state(), LoginState::JwxtAuthenticated);
+    assert!(session.is_alive().await.unwrap());
+}
+
+#[tokio::test]
+async fn wrong_vpn_password_is_bad_credentials() {
+    let (_server, session) = session().await;
+    let err = session
+        .complete_login(USERNAME, "wrong", PASSWORD, CAPTCHA)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::BadCredentials { .. }), "{:?}", err);
+    assert!(!session.is_alive().await.unwrap());
+}
+
+#[tokio::test]
+async fn wrong_jwxt_password_is_bad_credentials() {
+    let (_server, session) = session().await;
+    let err = session
+        .complete_login(USERNAME, PASSWORD, "wrong", CAPTCHA)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::BadCredentials { .. }), "{:?}", err);
+    assert_eq!(session.login_state(), LoginState::VpnAuthenticated);
+}
+
+#[tokio::test]
+async fn wrong_captcha_is_bad_captcha() {
+    let (_server, session) = session().await;
+    let err = session
+        .complete_login(USERNAME, PASSWORD, PASSWORD, "0000")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::BadCaptcha), "{:?}", err);
+}
+
+#[tokio::test]
+async fn expired_session_is_not_alive() {
+    let (server, session) = session().await;
+    session
+        .complete_login(USERNAME, PASSWORD, PASSWORD, CAPTCHA)
+        .await
+        .unwrap();
+
+    server.expire_jwxt();
+    assert!(!session.is_alive().await.unwrap());
+    assert!(session.access_jwxt().await.unwrap());
+
+    server.expire_vpn();
+    assert!(!session.access_jwxt().await.unwrap());
+}
@@ -0,0 +1,165 @@
+//! 各解析函数的快照测试，fixtures 为匿名化后的真实页面。
+//! 学校更新页面模板后快照会立即失败，用 `cargo insta review` 或
+//! `INSTA_UPDATE=always cargo test` 检查并更新
+
+use insta::assert_json_snapshot;
+use rust_lib_hbfu_alex::api::jwxt::{
+    calendar::parse_school_calendar,
+    classroom::{parse_classroom_filters, parse_free_classrooms},
+    course::parse_course_schedule,
+    dekt::{parse_dekt, parse_dekt_detail},
+    elective::parse_elective,
+    exam::parse_exam,
+    grade::GradeScale,
+    info::parse_student_info,
+    plan::parse_plan,
+    score::parse_score_all,
+    selection::{parse_selection_courses, parse_selection_rounds, SelectionCategory},
+    semester::parse_semester,
+};
+
+macro_rules! fixture {
+    ($name:literal) => {
+        include_str!(concat!("fixtures/", $name))
+    };
+}
+
+#[test]
+fn score() {
+    assert_json_snapshot!(
+        parse_score_all(fixture!("cjcx_list.html"), &GradeScale::default()).unwrap()
+    );
+}
+
+#[test]
+fn score_empty_semester() {
+    assert_json_snapshot!(parse_score_all(
+        fixture!("cjcx_list_empty.html"),
+        &GradeScale::default()
+    )
+    .unwrap());
+}
+
+#[test]
+fn score_missing_columns() {
+    assert_json_snapshot!(parse_score_all(
+        fixture!("cjcx_list_missing_columns.html"),
+        &GradeScale::default()
+    )
+    .unwrap());
+}
+
+#[test]
+fn score_special_grades() {
+    assert_json_snapshot!(parse_score_all(
+        fixture!("cjcx_list_special_grades.html"),
+        &GradeScale::default()
+    )
+    .unwrap());
+}
+
+#[test]
+fn course() {
+    assert_json_snapshot!(parse_course_schedule(fixture!("xskb_list.html")).unwrap());
+}
+
+#[test]
+fn course_alternating_weeks() {
+    assert_json_snapshot!(parse_course_schedule(fixture!("xskb_list_alternating.html")).unwrap());
+}
+
+#[test]
+fn course_empty_semester() {
+    assert_json_snapshot!(parse_course_schedule(fixture!("xskb_list_empty.html")).unwrap());
+}
+
+#[test]
+fn exam() {
+    assert_json_snapshot!(parse_exam(fixture!("xsksap_list.html")).unwrap());
+}
+
+#[test]
+fn exam_empty_semester() {
+    assert_json_snapshot!(parse_exam(fixture!("xsksap_list_empty.html")).unwrap());
+}
+
+#[test]
+fn exam_missing_columns() {
+    assert_json_snapshot!(parse_exam(fixture!("xsksap_list_missing_columns.html")).unwrap());
+}
+
+#[test]
+fn plan() {
+    assert_json_snapshot!(parse_plan(fixture!("pyfa_query.html")).unwrap());
+}
+
+#[test]
+fn elective() {
+    assert_json_snapshot!(parse_elective(fixture!("xqxkchList.html")).unwrap());
+}
+
+#[test]
+fn elective_empty_semester() {
+    assert_json_snapshot!(parse_elective(fixture!("xqxkchList_empty.html")).unwrap());
+}
+
+#[test]
+fn dekt() {
+    assert_json_snapshot!(parse_dekt(fixture!("cxxf07List.html")).unwrap());
+}
+
+#[test]
+fn dekt_empty() {
+    assert_json_snapshot!(parse_dekt(fixture!("cxxf07List_empty.html")).unwrap());
+}
+
+#[test]
+fn dekt_detail() {
+    insta::with_settings!({ sort_maps => true }, {
+        assert_json_snapshot!(parse_dekt_detail(fixture!("cxxf07View.html")).unwrap());
+    });
+}
+
+#[test]
+fn student_info() {
+    assert_json_snapshot!(parse_student_info(fixture!("xsxx.html")).unwrap());
+}
+
+#[test]
+fn student_info_minimal_card() {
+    assert_json_snapshot!(parse_student_info(fixture!("xsxx_minimal.html")).unwrap());
+}
+
+#[test]
+fn semester() {
+    assert_json_snapshot!(parse_semester(fixture!("xsksap_query.html"), true).unwrap());
+}
+
+#[test]
+fn school_calendar() {
+    assert_json_snapshot!(parse_school_calendar(fixture!("jxzl_query.html")).unwrap());
+}
+
+#[test]
+fn selection_rounds() {
+    assert_json_snapshot!(parse_selection_rounds(fixture!("xklc_list.html")).unwrap());
+}
+
+#[test]
+fn selection_courses() {
+    assert_json_snapshot!(parse_selection_courses(
+        fixture!("xsxkGgxxkxk.json"),
+        SelectionCategory::PublicElective
+    )
+    .unwrap());
+}
+
+#[test]
+fn classroom_filters() {
+    assert_json_snapshot!(parse_classroom_filters(fixture!("jsjy_query.html")).unwrap());
+}
+
+#[test]
+fn free_classrooms() {
+    assert_json_snapshot!(parse_free_classrooms(fixture!("jsjy_query2.html"), &[3, 4]).unwrap());
+}
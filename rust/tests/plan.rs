@@ -0,0 +1,113 @@
+use rust_lib_hbfu_alex::api::jwxt::{plan::parse_plan, semester::split_semester};
+
+fn row(id: u32, semester: &str, code: &str, credits: &str, hours: &str) -> String {
+    format!(
+        "<tr><td>{id}</td><td>{semester}</td><td>{code}</td><td>课程{id}</td><td>信息科学与技术学院</td>\
+         <td>{credits}</td><td>{hours}</td><td>考试</td><td>必修</td><td>是</td></tr>"
+    )
+}
+
+#[test]
+fn semester_code_parts() {
+    assert_eq!(
+        split_semester("2023-2024-1"),
+        Some(("2023-2024".to_string(), 1))
+    );
+    assert_eq!(
+        split_semester(" 2024-2025-3 "),
+        Some(("2024-2025".to_string(), 3))
+    );
+    assert_eq!(split_semester("2023-1"), None);
+    assert_eq!(split_semester("23-24-1"), None);
+    assert_eq!(split_semester("2023-2024-秋"), None);
+    assert_eq!(split_semester(""), None);
+}
+
+#[test]
+fn semesters_in_chronological_order() {
+    let plan = parse_plan(include_str!("fixtures/pyfa_query.html")).unwrap();
+    assert_eq!(
+        plan.semesters,
+        [
+            "2023-2024-1",
+            "2023-2024-2",
+            "2024-2025-1",
+            "2024-2025-2",
+            "2025-2026-1",
+            "2025-2026-2",
+            "2026-2027-1",
+            "2026-2027-2",
+        ]
+    );
+    let first = &plan.plans[0];
+    assert_eq!((first.academic_year.as_str(), first.term), ("2023-2024", 1));
+}
+
+#[test]
+fn five_year_programme_with_summer_terms() {
+    // 行顺序打乱，并加入夏季小学期与第五学年
+    let rows = [
+        row(1, "2027-2028-2", "B0390071", "8", "0"),
+        row(2, "2023-2024-3", "B0390081", "1", "16"),
+        row(3, "2023-2024-1", "B0110001", "5", "80"),
+        row(4, "2027-2028-1", "B0390072", "4", "0"),
+        row(5, "2023-2024-2", "B0110002", "5", "80"),
+        row(6, "2024-2025-1", "B0390011", "3", "48"),
+        row(7, "2024-2025-2", "B0390021", "3.5", "64"),
+        row(8, "2024-2025-3", "B0390082", "1", "16"),
+        row(9, "2025-2026-1", "B0390031", "3", "48"),
+        row(10, "2025-2026-2", "B0390041", "3", "48"),
+        row(11, "2026-2027-1", "B0390051", "2", "32"),
+        row(12, "2026-2027-2", "B0390061", "2", "32"),
+        row(13, "2023-2024-1", "B0120003", "3", "48"),
+    ]
+    .concat();
+    let html = include_str!("fixtures/pyfa_query.html");
+    let start = html.find("<tr><td>").unwrap();
+    let end = html.find("</table>").unwrap();
+    let html = format!("{}{}{}", &html[..start], rows, &html[end..]);
+
+    let plan = parse_plan(&html).unwrap();
+    assert_eq!(plan.plans.len(), 13);
+    assert_eq!(
+        plan.semesters,
+        [
+            "2023-2024-1",
+            "2023-2024-2",
+            "2023-2024-3",
+            "2024-2025-1",
+            "2024-2025-2",
+            "2024-2025-3",
+            "2025-2026-1",
+            "2025-2026-2",
+            "2026-2027-1",
+            "2026-2027-2",
+            "2027-2028-1",
+            "2027-2028-2",
+        ]
+    );
+
+    let first = &plan.groups[0];
+    assert_eq!((first.academic_year.as_str(), first.term), ("2023-2024", 1));
+    assert_eq!((first.credits, first.total_hours), (8.0, 128.0));
+    let codes: Vec<_> = first.plans.iter().map(|p| p.course_code.as_str()).collect();
+    assert_eq!(codes, ["B0110001", "B0120003"]);
+
+    let summer = &plan.groups[2];
+    assert_eq!((summer.semester.as_str(), summer.term), ("2023-2024-3", 3));
+    assert_eq!(summer.plans.len(), 1);
+}
+
+#[test]
+fn unrecognised_semesters_sorted_last() {
+    let html = include_str!("fixtures/pyfa_query.html").replace(
+        "<td>2023-2024-1</td><td>B0110001</td>",
+        "<td>待定</td><td>B0110001</td>",
+    );
+    let plan = parse_plan(&html).unwrap();
+    assert_eq!(plan.semesters.len(), 9);
+    assert_eq!(plan.semesters.last().unwrap(), "待定");
+    let last = plan.groups.last().unwrap();
+    assert_eq!((last.academic_year.as_str(), last.term), ("", 0));
+    assert_eq!(last.credits, 5.0);
+}
@@ -0,0 +1,77 @@
+use rust_lib_hbfu_alex::api::jwxt::selection::{
+    parse_enter_result, parse_selection_courses, parse_selection_outcome, parse_selection_rounds,
+    SelectionCategory, SelectionResult,
+};
+
+#[test]
+fn rounds_with_enter_links() {
+    let rounds = parse_selection_rounds(include_str!("fixtures/xklc_list.html")).unwrap();
+    assert_eq!(rounds.len(), 2);
+    assert_eq!(rounds[0].id, "6E3F2A91C0D84B7A");
+    assert!(rounds[0].can_enter);
+    assert_eq!(rounds[0].semester, "2024-2025-2");
+    assert_eq!(rounds[0].start_time, "2025-02-20 10:00");
+    assert!(!rounds[1].can_enter);
+    assert!(rounds[1].id.is_empty());
+}
+
+#[test]
+fn course_capacity_and_remaining_seats() {
+    let courses = parse_selection_courses(
+        include_str!("fixtures/xsxkGgxxkxk.json"),
+        SelectionCategory::PublicElective,
+    )
+    .unwrap();
+    assert_eq!(courses.len(), 3);
+
+    // 数字与字符串两种写法
+    assert_eq!((courses[0].capacity, courses[0].remaining), (120, 25));
+    assert_eq!(courses[1].credits, 1.5);
+    assert_eq!((courses[1].selected, courses[1].remaining), (80, 0));
+    // 没有 syrs 时按容量减已选人数计算
+    assert_eq!(courses[2].remaining, 12);
+    assert_eq!(courses[2].conflict, "与 数据结构 上课时间冲突");
+    assert!(courses
+        .iter()
+        .all(|course| course.category == SelectionCategory::PublicElective));
+}
+
+#[test]
+fn html_instead_of_course_list() {
+    let html = "<script>alert('当前未开放选课');</script>";
+    assert!(parse_selection_courses(html, SelectionCategory::MajorElective).is_err());
+}
+
+#[test]
+fn typed_outcomes() {
+    let outcome = |text: &str| parse_selection_outcome(text).unwrap().result;
+    assert_eq!(
+        outcome(r#"{"success":true,"message":"选课成功"}"#),
+        SelectionResult::Success
+    );
+    assert_eq!(
+        outcome(r#"{"success":false,"message":"选课失败：此课堂与已选课程上课时间冲突"}"#),
+        SelectionResult::TimeConflict
+    );
+    assert_eq!(
+        outcome(r#"{"success":"false","message":"选课失败：此课堂选课人数已满！"}"#),
+        SelectionResult::Full
+    );
+    assert_eq!(
+        outcome(r#"{"success":false,"message":"选课失败：已超出本轮次最多可选学分"}"#),
+        SelectionResult::Failed
+    );
+    assert_eq!(
+        outcome("<script>alert('不在选课时间范围内，不能选课');</script>"),
+        SelectionResult::OutsideWindow
+    );
+    assert!(parse_selection_outcome("<html><body>选课中心</body></html>").is_err());
+
+    assert_eq!(
+        parse_enter_result("<html><body><div id=\"xsxkTab\"></div></body></html>").result,
+        SelectionResult::Success
+    );
+    let closed = parse_enter_result("<script>alert('选课时间未到');</script>");
+    assert_eq!(closed.result, SelectionResult::OutsideWindow);
+    assert_eq!(closed.message, "选课时间未到");
+}
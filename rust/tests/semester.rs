@@ -0,0 +1,74 @@
+use chrono::NaiveDate;
+use rust_lib_hbfu_alex::api::jwxt::semester::{
+    current_week, mark_current, parse_semester, parse_semester_start, teaching_week,
+};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn semesters_sorted_with_current_selected() {
+    // 打乱选项顺序，并去掉 selected
+    let html = include_str!("fixtures/xsksap_query.html")
+        .replace(" selected=\"selected\"", "")
+        .replace(
+            "<option value=\"2025-2026-1\">2025-2026-1</option>",
+            "<option value=\"2022-2023-2\">2022-2023-2</option>\
+             <option value=\"2025-2026-1\">2025-2026-1</option>",
+        );
+    let mut semesters = parse_semester(&html, false).unwrap();
+    let values: Vec<_> = semesters.iter().map(|s| s.value.as_str()).collect();
+    assert_eq!(
+        values,
+        [
+            "2025-2026-1",
+            "2024-2025-2",
+            "2024-2025-1",
+            "2023-2024-2",
+            "2023-2024-1",
+            "2022-2023-2",
+        ]
+    );
+    assert!(semesters.iter().all(|s| !s.is_current));
+
+    let start = parse_semester_start(include_str!("fixtures/jxzl_query.html")).unwrap();
+    mark_current(&mut semesters, &start.semester);
+    let current: Vec<_> = semesters.iter().filter(|s| s.is_current).collect();
+    assert_eq!(current.len(), 1);
+    assert_eq!(
+        (current[0].academic_year.as_str(), current[0].term),
+        ("2024-2025", 2)
+    );
+}
+
+#[test]
+fn calendar_start_date() {
+    let start = parse_semester_start(include_str!("fixtures/jxzl_query.html")).unwrap();
+    assert_eq!(start.semester, "2024-2025-2");
+    assert_eq!(start.start_date, "2025-02-24");
+
+    // 第一周从周三开始时取所在周的周一
+    let html = include_str!("fixtures/jxzl_query.html")
+        .replace("<td title=\"2025年02月24日\">24</td>", "<td></td>")
+        .replace("<td title=\"2025年02月25日\">25</td>", "<td></td>");
+    let start = parse_semester_start(&html).unwrap();
+    assert_eq!(start.start_date, "2025-02-24");
+}
+
+#[test]
+fn week_numbers() {
+    let start = date(2025, 2, 24);
+    assert_eq!(teaching_week(start, date(2025, 2, 24)), 1);
+    assert_eq!(teaching_week(start, date(2025, 3, 2)), 1);
+    assert_eq!(teaching_week(start, date(2025, 3, 3)), 2);
+    assert_eq!(teaching_week(start, date(2025, 7, 6)), 19);
+    assert_eq!(teaching_week(start, date(2025, 2, 23)), 0);
+    assert_eq!(teaching_week(start, date(2025, 2, 16)), -1);
+    // 开学日期不是周一时按所在周计算
+    assert_eq!(teaching_week(date(2025, 2, 26), date(2025, 3, 3)), 2);
+
+    let semester_start = parse_semester_start(include_str!("fixtures/jxzl_query.html")).unwrap();
+    let week = current_week(&semester_start, date(2025, 4, 27), false).unwrap();
+    assert_eq!((week.week, week.weekday), (9, 7));
+}
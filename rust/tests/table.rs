@@ -0,0 +1,81 @@
+use rust_lib_hbfu_alex::api::{
+    error::ApiError,
+    jwxt::{
+        dekt::parse_dekt, elective::parse_elective, grade::GradeScale, plan::parse_plan,
+        score::parse_score,
+    },
+};
+
+fn missing_column<T>(result: Result<T, ApiError>) -> (String, String) {
+    match result {
+        Err(ApiError::ParseLayoutChanged { page, selector }) => (page, selector),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("expected ParseLayoutChanged"),
+    }
+}
+
+fn header(label: &str) -> String {
+    format!("<th class=\"Nsb_r_list_thb\">{}</th>", label)
+}
+
+#[test]
+fn missing_headers_name_page_and_column() {
+    let score = include_str!("fixtures/cjcx_list.html").replace(&header("成绩"), &header("总评"));
+    assert_eq!(
+        missing_column(parse_score(&score, &GradeScale::default())),
+        ("cjcx_list".to_string(), "表头「成绩」".to_string())
+    );
+
+    let plan = include_str!("fixtures/pyfa_query.html").replace(&header("学分"), &header("学时"));
+    assert_eq!(
+        missing_column(parse_plan(&plan)),
+        ("pyfa_query".to_string(), "表头「学分」".to_string())
+    );
+
+    let elective =
+        include_str!("fixtures/xqxkchList.html").replace(&header("课程编号"), &header("编号"));
+    assert_eq!(
+        missing_column(parse_elective(&elective)),
+        ("xqxkchList".to_string(), "表头「课程编号」".to_string())
+    );
+
+    let dekt =
+        include_str!("fixtures/cxxf07List.html").replace(&header("所得学分"), &header("学分"));
+    assert_eq!(
+        missing_column(parse_dekt(&dekt)),
+        ("cxxf07List".to_string(), "表头「所得学分」".to_string())
+    );
+}
+
+#[test]
+fn reordered_score_columns() {
+    let html = include_str!("fixtures/cjcx_list.html");
+    let original = parse_score(html, &GradeScale::default()).unwrap();
+
+    // 把"课程性质"列移到最前
+    let nature = header("课程性质");
+    let reordered = html
+        .replace(&nature, "")
+        .replace(&header("序号"), &format!("{}{}", nature, header("序号")));
+    let reordered = regex::Regex::new(r"<tr>\s*((?:<td[^>]*>.*?</td>\s*){10})(<td[^>]*>.*?</td>)")
+        .unwrap()
+        .replace_all(&reordered, "<tr>$2$1");
+    let scores = parse_score(&reordered, &GradeScale::default()).unwrap();
+
+    assert_eq!(scores.len(), original.len());
+    for (a, b) in scores.iter().zip(&original) {
+        assert_eq!(a.course_id, b.course_id);
+        assert_eq!(a.course_nature, b.course_nature);
+        assert_eq!(a.credit, b.credit);
+    }
+}
+
+#[test]
+fn optional_columns_default_when_missing() {
+    let html = include_str!("fixtures/cxxf07List.html")
+        .replace(&header("操作"), "")
+        .replace("查看</a></td>", "查看</a>");
+    let dekt = parse_dekt(&html).unwrap();
+    assert_eq!(dekt.list.len(), 3);
+    assert_eq!(dekt.list[0].activity_name, "新生入学教育");
+}